@@ -1,7 +1,371 @@
+//! Compiles the theme sources in `themes/` into the built-in catalog.
+//!
+//! Each theme is a TOML file holding a `name` and the 19 color slots as
+//! `"#RRGGBB"` strings:
+//!
+//! ```toml
+//! name = "Tokyo Night"
+//!
+//! background = "#1A1B26"
+//! foreground = "#C0CAF5"
+//! cursor = "#C0CAF5"
+//!
+//! color0 = "#414868"
+//! # ...through color15.
+//! ```
+//!
+//! The sources are read with the `toml` crate, and names are folded with the
+//! runtime's `fold_name`, compiled in here with `#[path]`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use toml::de::{DeTable, DeValue};
+
+#[path = "src/names.rs"]
+#[allow(dead_code)]
+mod names;
+#[path = "src/phf.rs"]
+mod phf;
+
+/// The average number of names per perfect hash bucket.
+const BUCKET_SIZE: usize = 4;
+
+/// The color slots every theme must set, in the order `Theme` declares them.
+const SLOTS: [&str; 19] = [
+    "color0",
+    "color1",
+    "color2",
+    "color3",
+    "color4",
+    "color5",
+    "color6",
+    "color7",
+    "color8",
+    "color9",
+    "color10",
+    "color11",
+    "color12",
+    "color13",
+    "color14",
+    "color15",
+    "background",
+    "foreground",
+    "cursor",
+];
+
+/// A validated theme source.
+struct Source {
+    path: PathBuf,
+    name: String,
+    colors: [[u8; 3]; 19],
+}
+
 fn main() {
-    cc::Build::new()
-        .file("src/term_width.c")
-        .compile("term_width");
+    println!("cargo:rerun-if-changed=themes");
+    println!("cargo:rerun-if-changed=src/names.rs");
+    println!("cargo:rerun-if-changed=src/phf.rs");
+    println!("cargo:rerun-if-changed=src/toml.rs");
+
+    let mut errors = Vec::new();
+    let themes = read_themes(Path::new("themes"), &mut errors);
+
+    if !errors.is_empty() {
+        for error in &errors {
+            eprintln!("error: {error}");
+        }
+
+        eprintln!(
+            "error: found {} problem(s) in the theme sources",
+            errors.len()
+        );
+        process::exit(1);
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set"));
+
+    fs::write(out.join("themes.rs"), generate(&themes))
+        .expect("unable to write the generated themes");
+}
+
+// Reads and validates every theme source, sorted by name.
+fn read_themes(dir: &Path, errors: &mut Vec<String>) -> Vec<Source> {
+    let mut paths = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect::<Vec<PathBuf>>(),
+        Err(e) => {
+            errors.push(format!("{}: {e}", dir.display()));
+            return Vec::new();
+        }
+    };
+
+    paths.sort();
+
+    let mut themes = paths
+        .into_iter()
+        .filter_map(|path| match fs::read_to_string(&path) {
+            Ok(content) => parse_theme(path, &content, errors),
+            Err(e) => {
+                errors.push(format!("{}: {e}", path.display()));
+                None
+            }
+        })
+        .collect::<Vec<Source>>();
+
+    themes.sort_by_key(|theme| theme.name.to_ascii_lowercase());
+
+    // Names must be unique ignoring case, whitespace and punctuation, so that
+    // every name resolves to a single theme.
+    let mut seen = BTreeMap::<String, &Source>::new();
+
+    for theme in &themes {
+        if let Some(other) = seen.insert(names::fold_name(&theme.name), theme) {
+            errors.push(format!(
+                "{}: theme name \"{}\" is a duplicate of \"{}\" in {}",
+                theme.path.display(),
+                theme.name,
+                other.name,
+                other.path.display()
+            ));
+        }
+    }
+
+    if themes.len() > usize::from(u16::MAX) {
+        errors.push(format!("{}: too many themes", dir.display()));
+    }
+
+    if themes.is_empty() && errors.is_empty() {
+        errors.push(format!("{}: no theme sources found", dir.display()));
+    }
+
+    themes
+}
+
+// Parses and validates a single theme source.
+fn parse_theme(path: PathBuf, content: &str, errors: &mut Vec<String>) -> Option<Source> {
+    let line = |offset: usize| content[..offset].matches('\n').count() + 1;
+
+    let doc = match DeTable::parse(content) {
+        Ok(doc) => doc.into_inner(),
+        Err(e) => {
+            let at = e.span().map_or(0, |span| line(span.start));
+            errors.push(format!(
+                "{}: line {at}: {}",
+                path.display(),
+                e.message().trim()
+            ));
+            return None;
+        }
+    };
+
+    let start = errors.len();
+    let mut error = |message: String| errors.push(format!("{}: {message}", path.display()));
+
+    let mut entries = doc
+        .iter()
+        .map(|(key, value)| {
+            (
+                line(key.span().start),
+                key.get_ref().as_ref(),
+                value.get_ref(),
+            )
+        })
+        .collect::<Vec<(usize, &str, &DeValue<'_>)>>();
+
+    entries.sort_by_key(|(line, ..)| *line);
+
+    let mut name = None;
+    let mut colors = [None; 19];
+
+    for (line, key, value) in entries {
+        let Some(value) = value.as_str() else {
+            error(format!(
+                "line {line}: `{key}` must be a string (found {})",
+                value.type_str()
+            ));
+            continue;
+        };
+
+        if key == "name" {
+            if value.trim().is_empty() || value.trim() != value {
+                error(format!("line {line}: invalid theme name \"{value}\""));
+            } else {
+                name = Some(value.to_string());
+            }
+
+            continue;
+        }
+
+        let Some(slot) = SLOTS.iter().position(|&slot| slot == key) else {
+            error(format!("line {line}: unknown key `{key}`"));
+            continue;
+        };
+
+        match parse_color(value) {
+            Some(color) => colors[slot] = Some(color),
+            None => error(format!(
+                "line {line}: `{key}` is \"{value}\", expected a color like \"#1A2B3C\""
+            )),
+        }
+    }
+
+    if doc.get("name").is_none() {
+        error(String::from("missing `name`"));
+    }
+
+    for slot in SLOTS {
+        if doc.get(slot).is_none() {
+            error(format!("missing color slot `{slot}`"));
+        }
+    }
+
+    if errors.len() > start {
+        return None;
+    }
+
+    Some(Source {
+        path,
+        name: name?,
+        colors: colors.map(Option::unwrap),
+    })
+}
+
+// Parses a "#RRGGBB" color.
+fn parse_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.strip_prefix('#')?;
+
+    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |idx: usize| u8::from_str_radix(&hex[idx..idx + 2], 16).ok();
+
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+// Finds a seed for each bucket of names such that `phf::slot` maps every
+// name to a distinct slot, returning the seeds and the theme index at each
+// slot.
+fn perfect_hash(names: &[&str]) -> (Vec<u32>, Vec<usize>) {
+    let bucket_count = names.len().div_ceil(BUCKET_SIZE);
+
+    let mut buckets = vec![Vec::new(); bucket_count];
+
+    for (idx, name) in names.iter().enumerate() {
+        buckets[phf::bucket(name.as_bytes(), bucket_count)].push(idx);
+    }
+
+    // Placing the largest buckets first makes the search much faster.
+    let mut order = (0..bucket_count).collect::<Vec<usize>>();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+    let mut seeds = vec![0; bucket_count];
+    let mut slots = vec![None; names.len()];
+
+    for bucket in order {
+        let members = &buckets[bucket];
+
+        if members.is_empty() {
+            continue;
+        }
+
+        'seeds: for seed in 1.. {
+            seeds[bucket] = seed;
+
+            let mut taken = Vec::with_capacity(members.len());
+
+            for &idx in members {
+                let slot = phf::slot(names[idx].as_bytes(), &seeds, names.len());
+
+                if slots[slot].is_some() || taken.contains(&slot) {
+                    continue 'seeds;
+                }
+
+                taken.push(slot);
+            }
+
+            for (&idx, slot) in members.iter().zip(taken) {
+                slots[slot] = Some(idx);
+            }
+
+            break;
+        }
+    }
+
+    (
+        seeds,
+        slots
+            .into_iter()
+            .map(|slot| slot.expect("every slot is filled"))
+            .collect(),
+    )
+}
+
+// Generates the Rust source for the catalog.
+fn generate(themes: &[Source]) -> String {
+    let mut src = String::from("// @generated by build.rs from the files in themes/.\n\n");
+
+    let _ = writeln!(
+        src,
+        "/// All built-in themes, sorted case-insensitively by name."
+    );
+    let _ = writeln!(src, "pub static ALL_THEMES: [Theme; {}] = [", themes.len());
+
+    for theme in themes {
+        let _ = writeln!(src, "    Theme {{");
+
+        for (slot, [r, g, b]) in SLOTS.iter().zip(theme.colors) {
+            let _ = writeln!(src, "        {slot}: Rgb::new({r}, {g}, {b}),");
+        }
+
+        let _ = writeln!(src, "        name: Cow::Borrowed({:?}),", theme.name);
+        let _ = writeln!(src, "    }},");
+    }
+
+    src.push_str("];\n\n");
+
+    let _ = writeln!(
+        src,
+        "/// Theme names reduced to lowercase alphanumerics, parallel to `ALL_THEMES`."
+    );
+    let _ = writeln!(src, "pub static FOLDED_NAMES: [&str; {}] = [", themes.len());
+
+    for theme in themes {
+        let _ = writeln!(src, "    {:?},", names::fold_name(&theme.name));
+    }
+
+    src.push_str("];\n\n");
+
+    let names = themes
+        .iter()
+        .map(|theme| theme.name.as_str())
+        .collect::<Vec<&str>>();
+    let (seeds, slots) = perfect_hash(&names);
+
+    let _ = writeln!(
+        src,
+        "/// Per-bucket seeds of the perfect hash over theme names."
+    );
+    let _ = writeln!(
+        src,
+        "pub static NAME_SEEDS: [u32; {}] = {seeds:?};\n",
+        seeds.len()
+    );
+    let _ = writeln!(
+        src,
+        "/// The `ALL_THEMES` index stored in each perfect hash slot."
+    );
+    let _ = writeln!(
+        src,
+        "pub static NAME_SLOTS: [u16; {}] = {slots:?};",
+        slots.len()
+    );
 
-    println!("cargo:rerun-if-changed=src/term_width.c");
+    src
 }
@@ -0,0 +1,928 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Result as IoResult, Write};
+
+use crate::util::suggest;
+
+/// A command-line option.
+#[derive(Debug)]
+pub struct Opt {
+    /// Long name, without the leading dashes.
+    pub long: &'static str,
+    /// Optional short name.
+    pub short: Option<char>,
+    /// Placeholder for the option's value, if the option takes one.
+    pub value: Option<&'static str>,
+    /// Allowed values, if restricted.
+    pub choices: &'static [&'static str],
+    /// Help text.
+    pub help: &'static str,
+}
+
+impl Opt {
+    // Returns the option as it appears in help output (e.g. "-c, --color WHEN").
+    fn usage(&self) -> String {
+        let mut usage = self
+            .short
+            .map_or_else(|| String::from("    "), |c| format!("-{c}, "));
+
+        usage.push_str("--");
+        usage.push_str(self.long);
+
+        if let Some(value) = self.value {
+            usage.push(' ');
+            usage.push_str(value);
+        }
+
+        usage
+    }
+}
+
+/// A subcommand.
+#[derive(Debug)]
+pub struct Command {
+    /// Command name.
+    pub name: &'static str,
+    /// Usage string for the positional arguments.
+    pub args: &'static str,
+    /// Minimum number of positional arguments.
+    pub min_args: usize,
+    /// Maximum number of positional arguments, if bounded.
+    pub max_args: Option<usize>,
+    /// One-line description.
+    pub about: &'static str,
+    /// Command-specific options.
+    pub options: &'static [Opt],
+}
+
+/// The `--help` option accepted by every command.
+const HELP: Opt = Opt {
+    long: "help",
+    short: Some('h'),
+    value: None,
+    choices: &[],
+    help: "Print help and exit.",
+};
+
+/// The `--dry-run` option accepted by commands that write files.
+const DRY_RUN: Opt = Opt {
+    long: "dry-run",
+    short: Some('n'),
+    value: None,
+    choices: &[],
+    help: "Show what would change without writing anything.",
+};
+
+/// The `--no-reload` option accepted by commands that write files.
+const NO_RELOAD: Opt = Opt {
+    long: "no-reload",
+    short: None,
+    value: None,
+    choices: &[],
+    help: "Write the settings without reloading Termux.",
+};
+
+/// The `--sort` option accepted by commands that list themes.
+const SORT: Opt = Opt {
+    long: "sort",
+    short: None,
+    value: Some("KEY"),
+    choices: &[
+        "name",
+        "brightness",
+        "contrast",
+        "hue",
+        "saturation",
+        "recent",
+    ],
+    help: "Sort by name, brightness, contrast, hue, saturation or recent.",
+};
+
+/// The `--reverse` option accepted by commands that list themes.
+const REVERSE: Opt = Opt {
+    long: "reverse",
+    short: Some('r'),
+    value: None,
+    choices: &[],
+    help: "Reverse the sort order.",
+};
+
+/// The `--sync-ui` option accepted by commands that apply themes.
+const SYNC_UI: Opt = Opt {
+    long: "sync-ui",
+    short: None,
+    value: None,
+    choices: &[],
+    help: "Also set use-black-ui to match the theme's background.",
+};
+
+/// The `--no-hooks` option accepted by commands that apply themes.
+const NO_HOOKS: Opt = Opt {
+    long: "no-hooks",
+    short: None,
+    value: None,
+    choices: &[],
+    help: "Don't run the hooks in the configuration file.",
+};
+
+/// The `--save` option accepted by commands that create themes.
+const SAVE: Opt = Opt {
+    long: "save",
+    short: None,
+    value: None,
+    choices: &[],
+    help: "Save the theme as a user theme instead of printing it.",
+};
+
+/// The `--preview` option accepted by commands that create themes.
+const PREVIEW: Opt = Opt {
+    long: "preview",
+    short: Some('p'),
+    value: None,
+    choices: &[],
+    help: "Recolor this terminal instead of printing the settings.",
+};
+
+/// Options accepted by commands that list themes.
+const LIST_OPTIONS: &[Opt] = &[
+    SORT,
+    REVERSE,
+    Opt {
+        long: "group-by",
+        short: None,
+        value: Some("KEY"),
+        choices: &["brightness", "family", "first-letter"],
+        help: "Split the list by brightness, family or first-letter.",
+    },
+    Opt {
+        long: "one-per-line",
+        short: Some('1'),
+        value: None,
+        choices: &[],
+        help: "Print one name per line.",
+    },
+    Opt {
+        long: "columns",
+        short: Some('C'),
+        value: None,
+        choices: &[],
+        help: "Print names in columns, even when not writing to a terminal.",
+    },
+    Opt {
+        long: "swatch",
+        short: Some('s'),
+        value: None,
+        choices: &[],
+        help: "Show each theme's background and foreground before its name.",
+    },
+    Opt {
+        long: "sections",
+        short: None,
+        value: None,
+        choices: &[],
+        help: "Same as --group-by first-letter.",
+    },
+];
+
+/// Options accepted before or after any command.
+pub static GLOBAL_OPTIONS: &[Opt] = &[
+    HELP,
+    Opt {
+        long: "version",
+        short: Some('V'),
+        value: None,
+        choices: &[],
+        help: "Print the version and exit.",
+    },
+    Opt {
+        long: "color",
+        short: None,
+        value: Some("WHEN"),
+        choices: &["auto", "always", "never"],
+        help: "Colorize output: auto, always or never [default: auto].",
+    },
+    Opt {
+        long: "termux-dir",
+        short: None,
+        value: Some("DIR"),
+        choices: &[],
+        help: "Use DIR instead of ~/.termux [env: THEMUX_TERMUX_DIR].",
+    },
+    Opt {
+        long: "root",
+        short: None,
+        value: Some("DIR"),
+        choices: &[],
+        help: "Redirect every path themux touches beneath DIR.",
+    },
+    Opt {
+        long: "no-termux-check",
+        short: None,
+        value: None,
+        choices: &[],
+        help: "Run even if the environment doesn't look like Termux.",
+    },
+    Opt {
+        long: "quiet",
+        short: Some('q'),
+        value: None,
+        choices: &[],
+        help: "Suppress informational messages.",
+    },
+    Opt {
+        long: "verbose",
+        short: Some('v'),
+        value: None,
+        choices: &[],
+        help: "Print additional diagnostic messages.",
+    },
+];
+
+/// All available commands.
+pub static COMMANDS: &[Command] = &[
+    Command {
+        name: "all",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Print a list of all available themes.",
+        options: LIST_OPTIONS,
+    },
+    Command {
+        name: "apply",
+        args: "<THEME>",
+        min_args: 1,
+        max_args: None,
+        about: "Apply THEME and reload the Termux settings.",
+        options: &[DRY_RUN, NO_RELOAD, SYNC_UI, NO_HOOKS],
+    },
+    Command {
+        name: "current",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Print the currently set theme.",
+        options: &[],
+    },
+    Command {
+        name: "dark",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Print a list of all dark themes.",
+        options: LIST_OPTIONS,
+    },
+    Command {
+        name: "edit",
+        args: "[THEME]",
+        min_args: 0,
+        max_args: None,
+        about: "Edit the colors of THEME [default: current] and save them as a new theme.",
+        options: &[],
+    },
+    Command {
+        name: "env",
+        args: "[THEME]",
+        min_args: 0,
+        max_args: None,
+        about: "Print shell exports of the colors of THEME [default: current].",
+        options: &[Opt {
+            long: "shell",
+            short: Some('s'),
+            value: Some("SHELL"),
+            choices: &["sh", "fish", "nu"],
+            help: "Print for sh, fish or nu [default: sh].",
+        }],
+    },
+    Command {
+        name: "find",
+        args: "<QUERY>",
+        min_args: 1,
+        max_args: None,
+        about: "List the themes matching a palette QUERY.",
+        options: LIST_OPTIONS,
+    },
+    Command {
+        name: "font",
+        args: "<list|set|current> [FONT]",
+        min_args: 1,
+        max_args: Some(2),
+        about: "List fonts, install FONT as the Termux font, or show it.",
+        options: &[DRY_RUN, NO_RELOAD],
+    },
+    Command {
+        name: "from-image",
+        args: "<IMAGE>",
+        min_args: 1,
+        max_args: Some(1),
+        about: "Extract a theme from the colors of a PNG, JPEG or PPM IMAGE.",
+        options: &[
+            Opt {
+                long: "dark",
+                short: Some('d'),
+                value: None,
+                choices: &[],
+                help: "Use a dark background [default: if the image is mostly dark].",
+            },
+            Opt {
+                long: "light",
+                short: Some('l'),
+                value: None,
+                choices: &[],
+                help: "Use a light background.",
+            },
+            Opt {
+                long: "name",
+                short: None,
+                value: Some("NAME"),
+                choices: &[],
+                help: "Name the theme NAME [default: the image's file name].",
+            },
+            SAVE,
+            PREVIEW,
+        ],
+    },
+    Command {
+        name: "generate",
+        args: "<TARGET> [THEME]",
+        min_args: 1,
+        max_args: None,
+        about: "Print config for TARGET in the colors of THEME [default: current].",
+        options: &[Opt {
+            long: "output",
+            short: Some('o'),
+            value: Some("FILE"),
+            choices: &[],
+            help: "Write to FILE instead of stdout.",
+        }],
+    },
+    Command {
+        name: "generate-theme",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Derive a theme from a base color and print its settings.",
+        options: &[
+            Opt {
+                long: "base",
+                short: Some('b'),
+                value: Some("HEX"),
+                choices: &[],
+                help: "Derive the theme from the color HEX (required).",
+            },
+            Opt {
+                long: "dark",
+                short: Some('d'),
+                value: None,
+                choices: &[],
+                help: "Use a dark background [default: if the base color is dark].",
+            },
+            Opt {
+                long: "light",
+                short: Some('l'),
+                value: None,
+                choices: &[],
+                help: "Use a light background.",
+            },
+            Opt {
+                long: "style",
+                short: Some('s'),
+                value: Some("STYLE"),
+                choices: &["vivid", "muted", "pastel"],
+                help: "Use vivid, muted or pastel colors [default: vivid].",
+            },
+            Opt {
+                long: "name",
+                short: None,
+                value: Some("NAME"),
+                choices: &[],
+                help: "Name the theme NAME [default: Generated HEX].",
+            },
+            SAVE,
+            PREVIEW,
+        ],
+    },
+    Command {
+        name: "get",
+        args: "<SLOT> [THEME]",
+        min_args: 1,
+        max_args: None,
+        about: "Print the SLOT color of THEME [default: current].",
+        options: &[Opt {
+            long: "format",
+            short: Some('f'),
+            value: Some("FORMAT"),
+            choices: &["hex", "rgb", "ansi-fg", "ansi-bg", "decimal"],
+            help: "Print as hex, rgb, ansi-fg, ansi-bg or decimal [default: hex].",
+        }],
+    },
+    Command {
+        name: "help",
+        args: "[COMMAND]",
+        min_args: 0,
+        max_args: Some(1),
+        about: "Print help for themux or for COMMAND.",
+        options: &[],
+    },
+    Command {
+        name: "import",
+        args: "<FILE>",
+        min_args: 1,
+        max_args: Some(1),
+        about: "Apply a colors.properties FILE (or - for stdin).",
+        options: &[DRY_RUN, NO_RELOAD, SYNC_UI, NO_HOOKS],
+    },
+    Command {
+        name: "light",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Print a list of all light themes.",
+        options: LIST_OPTIONS,
+    },
+    Command {
+        name: "profile",
+        args: "<list|show|save|use|delete> [NAME]",
+        min_args: 1,
+        max_args: Some(2),
+        about: "Manage profiles of a theme, font and appearance settings.",
+        options: &[DRY_RUN, NO_RELOAD, NO_HOOKS],
+    },
+    Command {
+        name: "restore",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Restore the theme that was set before the last change.",
+        options: &[DRY_RUN, NO_RELOAD, NO_HOOKS],
+    },
+    Command {
+        name: "set",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Set the theme from an interactive list.",
+        options: &[DRY_RUN, NO_RELOAD, SYNC_UI, NO_HOOKS, SORT, REVERSE],
+    },
+    Command {
+        name: "show",
+        args: "<THEME>",
+        min_args: 1,
+        max_args: None,
+        about: "Print the color value settings for THEME.",
+        options: &[],
+    },
+    Command {
+        name: "sync",
+        args: "",
+        min_args: 0,
+        max_args: Some(0),
+        about: "Apply a desired-state file if the current setup differs.",
+        options: &[
+            Opt {
+                long: "file",
+                short: Some('f'),
+                value: Some("FILE"),
+                choices: &[],
+                help: "Read FILE (or - for stdin) [default: ~/.config/themux/themux.toml].",
+            },
+            DRY_RUN,
+            NO_RELOAD,
+            NO_HOOKS,
+        ],
+    },
+    Command {
+        name: "ui",
+        args: "<get|set> [KEY] [VALUE]",
+        min_args: 1,
+        max_args: Some(3),
+        about: "Get or set appearance KEYs in termux.properties.",
+        options: &[DRY_RUN, NO_RELOAD],
+    },
+    Command {
+        name: "watch",
+        args: "<FILE>",
+        min_args: 1,
+        max_args: Some(1),
+        about: "Apply a colors.properties FILE again whenever it is saved.",
+        options: &[
+            Opt {
+                long: "preview",
+                short: Some('p'),
+                value: None,
+                choices: &[],
+                help: "Recolor this terminal with escape sequences instead.",
+            },
+            NO_RELOAD,
+        ],
+    },
+];
+
+impl Command {
+    /// Returns the `Command` with the given name, if one exists.
+    pub fn find(name: &str) -> Option<&'static Self> {
+        COMMANDS.iter().find(|cmd| cmd.name == name)
+    }
+
+    /// Writes the help message for this command.
+    pub fn write_help<W: Write>(&self, out: &mut W, heading: &str, clear: &str) -> IoResult<()> {
+        write!(
+            out,
+            "{}\n\n{heading}USAGE:{clear} {} [OPTIONS] {}",
+            self.about,
+            env!("CARGO_PKG_NAME"),
+            self.name
+        )?;
+
+        if !self.args.is_empty() {
+            write!(out, " {}", self.args)?;
+        }
+
+        if !self.options.is_empty() {
+            write!(out, "\n\n{heading}OPTIONS:{clear}")?;
+            write_options(out, self.options)?;
+        }
+
+        write!(out, "\n\n{heading}GLOBAL OPTIONS:{clear}")?;
+        write_options(out, GLOBAL_OPTIONS)?;
+
+        writeln!(out)
+    }
+}
+
+/// Writes the top-level help message.
+pub fn write_help<W: Write>(out: &mut W, heading: &str, clear: &str) -> IoResult<()> {
+    write!(
+        out,
+        "{heading}USAGE:{clear} {} [OPTIONS] <COMMAND>\n\n{heading}COMMANDS:{clear}",
+        env!("CARGO_PKG_NAME")
+    )?;
+
+    let usages = COMMANDS
+        .iter()
+        .map(|cmd| format!("{} {}", cmd.name, cmd.args))
+        .collect::<Vec<String>>();
+
+    let width = usages.iter().map(String::len).max().unwrap_or(0);
+
+    for (cmd, usage) in COMMANDS.iter().zip(&usages) {
+        write!(out, "\n    {usage:width$}  {}", cmd.about)?;
+    }
+
+    write!(out, "\n\n{heading}OPTIONS:{clear}")?;
+    write_options(out, GLOBAL_OPTIONS)?;
+
+    writeln!(out)
+}
+
+// Writes an aligned list of options for a help message.
+fn write_options<W: Write>(out: &mut W, options: &[Opt]) -> IoResult<()> {
+    let usages = options.iter().map(Opt::usage).collect::<Vec<String>>();
+    let width = usages.iter().map(String::len).max().unwrap_or(0);
+
+    for (opt, usage) in options.iter().zip(&usages) {
+        write!(out, "\n    {usage:width$}  {}", opt.help)?;
+    }
+
+    Ok(())
+}
+
+/// An error encountered while parsing command-line arguments.
+#[derive(Clone, Debug)]
+pub enum ArgError {
+    /// The command is not recognized.
+    UnknownCommand(String, Option<&'static str>),
+    /// The option is not recognized (with the long name of a similar one).
+    UnknownOption(String, Option<&'static str>),
+    /// The option requires a value but none was given.
+    MissingValue(&'static Opt),
+    /// The option does not take a value but one was given.
+    UnexpectedValue(&'static Opt),
+    /// The option's value is not one of the allowed choices.
+    InvalidValue(&'static Opt, String),
+    /// Too few positional arguments were given.
+    MissingArgument(&'static Command),
+    /// Too many positional arguments were given.
+    UnexpectedArgument(&'static Command, String),
+    /// A positional argument is not one of the allowed choices (with what
+    /// kind of argument it is).
+    InvalidChoice(&'static Command, &'static str, String, Vec<&'static str>),
+    /// A positional argument is invalid for the given reason.
+    InvalidArgument(&'static Command, String),
+}
+
+impl Display for ArgError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::UnknownCommand(name, suggestion) => {
+                write!(f, "\"{name}\" is not a valid command")?;
+                did_you_mean(f, *suggestion)
+            }
+            Self::UnknownOption(name, suggestion) => {
+                write!(f, "\"{name}\" is not a valid option")?;
+                did_you_mean(f, suggestion.map(|s| format!("--{s}")).as_deref())
+            }
+            Self::MissingValue(opt) => write!(f, "Option \"--{}\" requires a value", opt.long),
+            Self::UnexpectedValue(opt) => {
+                write!(f, "Option \"--{}\" does not take a value", opt.long)
+            }
+            Self::InvalidValue(opt, value) => write!(
+                f,
+                "\"{value}\" is not a valid value for \"--{}\" (expected {})",
+                opt.long,
+                opt.choices.join(", ")
+            ),
+            Self::MissingArgument(cmd) => write!(
+                f,
+                "Missing required argument for '{}': {}",
+                cmd.name, cmd.args
+            ),
+            Self::UnexpectedArgument(cmd, arg) => {
+                write!(f, "Unexpected argument \"{arg}\" for '{}'", cmd.name)
+            }
+            Self::InvalidChoice(cmd, kind, value, choices) => {
+                write!(
+                    f,
+                    "\"{value}\" is not a valid {kind} for '{}' (expected {})",
+                    cmd.name,
+                    choices.join(", ")
+                )?;
+                did_you_mean(f, suggest(value, choices.iter().copied()))
+            }
+            Self::InvalidArgument(_, msg) => f.write_str(msg),
+        }
+    }
+}
+
+// Writes a "did you mean" hint if there is a suggestion.
+fn did_you_mean(f: &mut Formatter<'_>, suggestion: Option<&str>) -> FmtResult {
+    suggestion.map_or(Ok(()), |s| write!(f, "; did you mean \"{s}\"?"))
+}
+
+/// Parsed command-line arguments.
+#[derive(Debug, Default)]
+pub struct Args {
+    /// The selected command, if any.
+    pub command: Option<&'static Command>,
+    /// Options that were given, along with their values.
+    pub options: Vec<(&'static Opt, Option<String>)>,
+    /// Positional arguments following the command.
+    pub positionals: Vec<String>,
+}
+
+impl Args {
+    /// Parses the given arguments (excluding the program name).
+    pub fn parse<I>(args: I) -> Result<Self, ArgError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        let mut options_done = false;
+
+        while let Some(arg) = args.next() {
+            if options_done || arg == "-" || !arg.starts_with('-') {
+                parsed.push_positional(arg)?;
+            } else if arg == "--" {
+                options_done = true;
+            } else if let Some(long) = arg.strip_prefix("--") {
+                let (name, value) = match long.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (long, None),
+                };
+
+                let opt = parsed.find_long(name)?;
+                parsed.push_option(opt, value, &mut args)?;
+            } else {
+                // One or more short options, e.g. "-q" or "-qv".
+                let shorts = &arg[1..];
+
+                for (idx, c) in shorts.char_indices() {
+                    let opt = parsed.find_short(c)?;
+
+                    if opt.value.is_some() {
+                        // The rest of the argument is the value, if present.
+                        let rest = &shorts[(idx + c.len_utf8())..];
+                        let value = (!rest.is_empty()).then(|| rest.to_string());
+                        parsed.push_option(opt, value, &mut args)?;
+                        break;
+                    }
+
+                    parsed.push_option(opt, None, &mut args)?;
+                }
+            }
+        }
+
+        // Skip arity checks when help was requested.
+        if let Some(cmd) = parsed.command.filter(|_| !parsed.flag("help")) {
+            if parsed.positionals.len() < cmd.min_args {
+                return Err(ArgError::MissingArgument(cmd));
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Returns true if the option with the given long name was given.
+    pub fn flag(&self, long: &str) -> bool {
+        self.options.iter().any(|(opt, _)| opt.long == long)
+    }
+
+    /// Returns the last value given for the option with the given long name.
+    pub fn value(&self, long: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(opt, _)| opt.long == long)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    // Returns the options valid at the current position.
+    fn valid_options(&self) -> impl Iterator<Item = &'static Opt> {
+        let local = self.command.map_or(&[][..], |cmd| cmd.options);
+        GLOBAL_OPTIONS.iter().chain(local)
+    }
+
+    // Finds an option by its long name.
+    fn find_long(&self, name: &str) -> Result<&'static Opt, ArgError> {
+        self.valid_options()
+            .find(|opt| opt.long == name)
+            .ok_or_else(|| {
+                let longs = self.valid_options().map(|opt| opt.long);
+                ArgError::UnknownOption(format!("--{name}"), suggest(name, longs))
+            })
+    }
+
+    // Finds an option by its short name.
+    fn find_short(&self, c: char) -> Result<&'static Opt, ArgError> {
+        self.valid_options()
+            .find(|opt| opt.short == Some(c))
+            .ok_or_else(|| ArgError::UnknownOption(format!("-{c}"), None))
+    }
+
+    // Records an option, taking its value from `args` if required.
+    fn push_option<I>(
+        &mut self,
+        opt: &'static Opt,
+        value: Option<String>,
+        args: &mut I,
+    ) -> Result<(), ArgError>
+    where
+        I: Iterator<Item = String>,
+    {
+        let value = match (opt.value, value) {
+            (Some(_), Some(value)) => Some(value),
+            (Some(_), None) => Some(args.next().ok_or(ArgError::MissingValue(opt))?),
+            (None, Some(_)) => return Err(ArgError::UnexpectedValue(opt)),
+            (None, None) => None,
+        };
+
+        if let Some(value) = value.as_deref() {
+            if !opt.choices.is_empty() && !opt.choices.contains(&value) {
+                return Err(ArgError::InvalidValue(opt, value.to_string()));
+            }
+        }
+
+        self.options.push((opt, value));
+
+        Ok(())
+    }
+
+    // Records a positional argument, treating the first as the command.
+    fn push_positional(&mut self, arg: String) -> Result<(), ArgError> {
+        let Some(cmd) = self.command else {
+            return Command::find(&arg).map_or_else(
+                || {
+                    let names = COMMANDS.iter().map(|cmd| cmd.name);
+                    let suggestion = suggest(&arg, names);
+                    Err(ArgError::UnknownCommand(arg.clone(), suggestion))
+                },
+                |cmd| {
+                    self.command = Some(cmd);
+                    Ok(())
+                },
+            );
+        };
+
+        if cmd
+            .max_args
+            .is_some_and(|max| self.positionals.len() >= max)
+        {
+            return Err(ArgError::UnexpectedArgument(cmd, arg));
+        }
+
+        self.positionals.push(arg);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgError> {
+        Args::parse(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn short_options_cluster_with_an_attached_value() {
+        let args = parse(&["sync", "-nqfdesired.toml"]).unwrap();
+
+        assert!(args.flag("dry-run"));
+        assert!(args.flag("quiet"));
+        assert_eq!(args.value("file"), Some("desired.toml"));
+
+        // Without an attached value, the next argument is taken.
+        let args = parse(&["sync", "-f", "-"]).unwrap();
+        assert_eq!(args.value("file"), Some("-"));
+    }
+
+    #[test]
+    fn long_options_take_a_value_after_an_equals_sign() {
+        let args = parse(&["sync", "--file=a=b.toml"]).unwrap();
+        assert_eq!(args.value("file"), Some("a=b.toml"));
+
+        let args = parse(&["--color=never", "all"]).unwrap();
+        assert_eq!(args.value("color"), Some("never"));
+
+        assert!(matches!(
+            parse(&["sync", "--dry-run=yes"]),
+            Err(ArgError::UnexpectedValue(opt)) if opt.long == "dry-run"
+        ));
+        assert!(matches!(
+            parse(&["--color=sometimes", "all"]),
+            Err(ArgError::InvalidValue(_, value)) if value == "sometimes"
+        ));
+    }
+
+    #[test]
+    fn double_dash_ends_options() {
+        let args = parse(&["apply", "--", "-q", "--dry-run"]).unwrap();
+
+        assert!(!args.flag("quiet"));
+        assert!(!args.flag("dry-run"));
+        assert_eq!(args.positionals, ["-q", "--dry-run"]);
+    }
+
+    #[test]
+    fn lone_dash_is_a_positional() {
+        let args = parse(&["import", "-"]).unwrap();
+
+        assert_eq!(args.command.map(|cmd| cmd.name), Some("import"));
+        assert_eq!(args.positionals, ["-"]);
+    }
+
+    #[test]
+    fn unknown_options_and_commands_are_suggested() {
+        let err = parse(&["sync", "--dry-rn"]).unwrap_err();
+        assert!(matches!(err, ArgError::UnknownOption(_, Some("dry-run"))));
+        assert_eq!(
+            err.to_string(),
+            "\"--dry-rn\" is not a valid option; did you mean \"--dry-run\"?"
+        );
+
+        // Options belong to their command.
+        assert!(matches!(
+            parse(&["--file", "x", "sync"]),
+            Err(ArgError::UnknownOption(name, _)) if name == "--file"
+        ));
+        assert!(matches!(
+            parse(&["sync", "-x"]),
+            Err(ArgError::UnknownOption(name, None)) if name == "-x"
+        ));
+
+        assert!(matches!(
+            parse(&["aply", "nord"]),
+            Err(ArgError::UnknownCommand(name, Some("apply"))) if name == "aply"
+        ));
+        assert!(matches!(
+            parse(&["qqqqqqqq"]),
+            Err(ArgError::UnknownCommand(_, None))
+        ));
+    }
+
+    #[test]
+    fn arguments_are_counted() {
+        assert!(matches!(
+            parse(&["apply"]),
+            Err(ArgError::MissingArgument(cmd)) if cmd.name == "apply"
+        ));
+        assert!(matches!(
+            parse(&["import", "a", "b"]),
+            Err(ArgError::UnexpectedArgument(_, arg)) if arg == "b"
+        ));
+        assert!(matches!(
+            parse(&["sync", "-f"]),
+            Err(ArgError::MissingValue(opt)) if opt.long == "file"
+        ));
+    }
+
+    #[test]
+    fn help_skips_the_argument_count() {
+        let args = parse(&["apply", "--help"]).unwrap();
+
+        assert!(args.flag("help"));
+        assert!(args.positionals.is_empty());
+        assert!(parse(&["import", "-h"]).is_ok());
+    }
+}
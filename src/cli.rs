@@ -1,12 +1,40 @@
 use std::env;
+use std::fmt::Write as _;
 use std::fs;
 use std::io::{self, IsTerminal, Result as IoResult, Write};
-use std::process::ExitCode;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::str;
 
-use crate::fail;
-use crate::style::{Themes, BLUE, CLR, CYAN, GRN};
+use crate::args::{self, ArgError, Args, Command};
+use themux::generate::Target;
+use themux::palette::{self, Style};
+use themux::query::Query;
+use themux::template::Template;
+use themux::{properties, Error, Rgb, Theme, Themes, Unresolved};
+
+use crate::config::Config;
+use crate::diff::unified_diff;
+use crate::editor::Editor;
+use crate::font;
+use crate::history;
+use crate::hooks::{self, Hook};
+use crate::image;
+use crate::list::{self, print_list, GroupBy, Layout, Mode, SortKey};
+use crate::profile::{self, Profile};
+use crate::style::ColorChoice;
+use crate::style::{print_values, set_color_choice, use_color};
+use crate::style::{BLUE, CLR, CYAN, GRN, RED};
+use crate::templates;
+use crate::termux::{get_settings_file_path, is_termux_env, read_properties};
+use crate::termux::{read_theme, Reload};
+use crate::termux::{write_all, Change, Paths};
 use crate::tui::Tui;
-use crate::util::{get_settings_file_path};
+use crate::ui::{self, Key};
+use crate::user_themes;
+use crate::util::{self, Verbosity};
+use crate::watch::{self, Watcher};
+use crate::{fail, info, verbose};
 
 /// A type containing methods used for handling CLI options.
 pub struct Cli;
@@ -14,76 +42,152 @@ pub struct Cli;
 impl Cli {
     /// Handle the CLI arguments.
     pub fn handle_args() -> ExitCode {
-        let mut args = env::args().skip(1);
+        let args = match Args::parse(env::args().skip(1)) {
+            Ok(args) => args,
+            Err(e) => return Self::usage_error(&e),
+        };
+
+        Self::apply_global_options(&args);
+
+        if args.flag("version") {
+            return Self::print_version();
+        }
+
+        let Some(cmd) = args.command else {
+            // No command is the same as `--help`.
+            return Self::print_help(None);
+        };
+
+        if args.flag("help") {
+            return Self::print_help(Some(cmd));
+        }
+
+        if cmd.name == "help" {
+            return Self::print_command_help(args.positionals.first());
+        }
+
+        // Exit if not a Termux environment.
+        if !args.flag("no-termux-check") && !is_termux_env() {
+            fail!("Not a Termux environment. Exiting.");
+        }
+
+        match Paths::new(args.value("termux-dir"), args.value("root")) {
+            Ok(paths) => paths.init(),
+            Err(e) => fail!("{e}"),
+        }
+
+        verbose!("running command '{}'", cmd.name);
 
-        match args.next().as_deref() {
+        match cmd.name {
             // Start the theme selector TUI.
-            Some("set") => Tui::get_selection(),
+            "set" => Tui::get_selection(&args),
             // Print a list of all dark themes to stdout.
-            Some("dark") => Self::print_themes(false, true),
+            "dark" => Self::print_themes(&args, false, true),
             // Print a list of all light themes to stdout.
-            Some("light") => Self::print_themes(true, false),
+            "light" => Self::print_themes(&args, true, false),
             // Print a list of all available themes to stdout.
-            Some("all") => Self::print_themes(true, true),
+            "all" => Self::print_themes(&args, true, true),
+            // Print a list of the themes matching a query.
+            "find" => Self::find_themes(&args),
+            // Apply a theme by name.
+            "apply" => Self::apply_named_theme(&args.positionals.join(" "), &args),
+            // Apply a color settings file.
+            "import" => Self::import_file(&args.positionals[0], &args),
             // Print the current theme.
-            Some("current") => Self::print_current_theme(),
-            // Print the version.
-            Some("-v" | "--version") => Self::print_version(),
-            // Print the help message.
-            Some("-h" | "--help") | None => Self::print_help(),
+            "current" => Self::print_current_theme(),
+            // Edit a theme's colors in a full-screen editor.
+            "edit" => Self::edit(&args),
+            // Print shell exports of a theme's colors.
+            "env" => Self::print_env(&args),
+            // Print or write the config for another program.
+            "generate" => Self::generate(cmd, &args),
+            // Extract a theme from an image.
+            "from-image" => Self::theme_from_image(cmd, &args),
+            // Derive a theme from a base color.
+            "generate-theme" => Self::generate_theme(cmd, &args),
+            // Print a single color of a theme.
+            "get" => Self::print_slot(cmd, &args),
+            // Save, apply and manage profiles.
+            "profile" => Self::handle_profile(cmd, &args),
+            // Apply a desired-state file.
+            "sync" => Self::sync(&args),
+            // Get or set appearance keys in termux.properties.
+            "ui" => Self::handle_ui(cmd, &args),
+            // List, install or identify fonts.
+            "font" => Self::handle_font(cmd, &args),
+            // Restore the previous theme.
+            "restore" => Self::restore_theme(&args),
+            // Apply a color settings file whenever it is saved.
+            "watch" => Self::watch(&args),
             // Print the color value settings for a given theme.
-            Some("show") => {
-                // Remaining args should be the theme name.
-                let arg = args.reduce(|mut acc, s| {
-                    acc.push(' ');
-                    acc.push_str(s.trim());
-                    acc
-                });
-
-                arg.as_ref().map_or_else(
-                    || fail!("Missing required argument for 'show'"),
-                    |name| Self::print_theme_settings(name.trim())
-                )
-            }
-            Some(opt) => {
-                if opt.trim_start().starts_with('-') {
-                    // Invalid option.
-                    fail!("\"{opt}\" is not a valid option");
-                } else {
-                    // Invalid command.
-                    fail!("\"{opt}\" is not a valid command");
-                }
-            }
+            "show" => Self::print_theme_settings(&args.positionals.join(" ")),
+            _ => unreachable!("unhandled command '{}'", cmd.name),
         }
     }
 
+    // Applies the options that affect every command.
+    fn apply_global_options(args: &Args) {
+        if let Some(choice) = args.value("color").and_then(ColorChoice::parse) {
+            set_color_choice(choice);
+        }
+
+        if args.flag("quiet") {
+            util::set_verbosity(Verbosity::Quiet);
+        } else if args.flag("verbose") {
+            util::set_verbosity(Verbosity::Verbose);
+        }
+    }
+
+    // Prints a command-line usage error and returns the usage exit status.
+    fn usage_error(e: &ArgError) -> ExitCode {
+        util::print_error(&e.to_string());
+
+        let hint = match e {
+            ArgError::MissingArgument(cmd)
+            | ArgError::UnexpectedArgument(cmd, _)
+            | ArgError::InvalidChoice(cmd, ..)
+            | ArgError::InvalidArgument(cmd, _) => {
+                format!("{} {} --help", env!("CARGO_PKG_NAME"), cmd.name)
+            }
+            _ => format!("{} --help", env!("CARGO_PKG_NAME")),
+        };
+
+        eprintln!("Run '{hint}' for more information.");
+
+        ExitCode::from(util::EXIT_USAGE)
+    }
+
+    // Prints the help message for `themux help [COMMAND]`.
+    fn print_command_help(name: Option<&String>) -> ExitCode {
+        let Some(name) = name else {
+            return Self::print_help(None);
+        };
+
+        Command::find(name).map_or_else(
+            || {
+                let names = args::COMMANDS.iter().map(|cmd| cmd.name);
+                let suggestion = util::suggest(name, names);
+                Self::usage_error(&ArgError::UnknownCommand(name.clone(), suggestion))
+            },
+            |cmd| Self::print_help(Some(cmd)),
+        )
+    }
+
     // Print the help message to stdout.
-    fn print_help() -> ExitCode {
+    fn print_help(cmd: Option<&Command>) -> ExitCode {
         let mut out = io::stdout().lock();
 
-        let is_term = out.is_terminal();
+        let is_color = use_color(out.is_terminal());
+        let heading = if is_color { GRN } else { "" };
+        let clear = if is_color { CLR } else { "" };
 
-        writeln!(
-            &mut out,
-            "\
-            {0}USAGE:{1} {2} [OPTION] <COMMAND>\n\n\
-            {0}COMMANDS:{1}\n    \
-                all            Print a list of all available themes.\n    \
-                current        Print the currently set theme.\n    \
-                dark           Print a list of all dark themes.\n    \
-                light          Print a list of all light themes.\n    \
-                set            Set the theme from an interactive list.\n    \
-                show <THEME>   Print the color value settings for THEME.\n\n\
-            {0}OPTIONS:{1}\n    \
-                -h, --help     Print this help message and exit.\n    \
-                -v, --version  Print the version.",
-            if is_term { GRN } else { "" },
-            if is_term { CLR } else { "" },
-            env!("CARGO_PKG_NAME")
-        )
-        .expect("Unable to write to stdout");
+        let res = match cmd {
+            Some(cmd) => cmd.write_help(&mut out, heading, clear),
+            None => args::write_help(&mut out, heading, clear),
+        };
 
-        out.flush().expect("Unable to write to stdout");
+        res.and_then(|()| out.flush())
+            .expect("Unable to write to stdout");
 
         ExitCode::SUCCESS
     }
@@ -98,11 +202,13 @@ impl Cli {
     fn print_current_theme() -> ExitCode {
         match get_settings_file_path() {
             Ok(path) if matches!(path.try_exists(), Ok(true)) => {
+                verbose!("reading {}", path.display());
+
                 match fs::read_to_string(path) {
                     Ok(theme) => Self::find_and_print_name(&theme),
                     Err(e) => fail!("{e}"),
                 }
-            },
+            }
             Ok(_) => fail!("Settings file not found"),
             Err(e) => fail!("{e}"),
         }
@@ -110,29 +216,26 @@ impl Cli {
 
     // Parses and prints the theme name from the content of a settings file.
     fn find_and_print_name(theme: &str) -> ExitCode {
-        for line in theme.lines() {
-            if let Some(name) = line.trim().strip_prefix("# Color Theme: ") {
-                return Self::print_name(name)
-                    .map_or_else(|e| fail!("{e}"), |_| ExitCode::SUCCESS);
-            }
-        }
+        let Some(name) = properties::theme_name(theme) else {
+            fail!("Unable to determine the current theme");
+        };
 
-        fail!("Unable to determine the current theme");
+        Self::print_name("theme", name).map_or_else(|e| fail!("{e}"), |()| ExitCode::SUCCESS)
     }
 
-    // Prints the theme name as colored if writing to the terminal or as plain
-    // text if not.
-    fn print_name(name: &str) -> IoResult<()> {
+    // Prints the current theme or font name as colored if writing to the
+    // terminal or as plain text if not.
+    fn print_name(what: &str, name: &str) -> IoResult<()> {
         let mut out = io::stdout().lock();
-        let is_term = out.is_terminal();
+        let is_color = use_color(out.is_terminal());
 
         writeln!(
             &mut out,
-            "{}Current theme{}: {}{name}{}",
-            if is_term { BLUE } else { "" },
-            if is_term { CLR } else { "" },
-            if is_term { CYAN } else { "" },
-            if is_term { CLR } else { "" }
+            "{}Current {what}{}: {}{name}{}",
+            if is_color { BLUE } else { "" },
+            if is_color { CLR } else { "" },
+            if is_color { CYAN } else { "" },
+            if is_color { CLR } else { "" }
         )?;
 
         out.flush()?;
@@ -141,26 +244,1229 @@ impl Cli {
     }
 
     // Prints a list of all available themes to stdout.
-    fn print_themes(do_light: bool, do_dark: bool) -> ExitCode {
-        Themes::init()
-            .print(do_light, do_dark)
-            .map_or_else(|e| fail!("{e}"), |code| code)
+    fn print_themes(args: &Args, do_light: bool, do_dark: bool) -> ExitCode {
+        let themes = Themes::init()
+            .iter()
+            .filter(|t| {
+                if t.background.is_dark() {
+                    do_dark
+                } else {
+                    do_light
+                }
+            })
+            .collect::<Vec<&Theme>>();
+
+        Self::print_theme_list(args, themes)
     }
 
-    // Prints the color value settings for a given theme.
-    fn print_theme_settings(name: &str) -> ExitCode {
+    // Prints the themes matching a query, or fails if there are none.
+    fn find_themes(args: &Args) -> ExitCode {
+        let text = args.positionals.join(" ");
+
+        let query = match Query::parse(&text) {
+            Ok(query) => query,
+            Err(e) => {
+                util::print_error(&format!("Invalid query: {e}"));
+
+                // Point at the problem beneath the query.
+                if let Error::Query { column, width, .. } = e {
+                    eprintln!("    {text}");
+                    eprintln!("    {}{}", " ".repeat(column - 1), "^".repeat(width));
+                }
+
+                return ExitCode::from(util::EXIT_USAGE);
+            }
+        };
+
+        let themes = Themes::init().find(&query).collect::<Vec<&Theme>>();
+
+        if themes.is_empty() {
+            info!("No themes match the query");
+            return ExitCode::from(util::EXIT_FAILURE);
+        }
+
+        Self::print_theme_list(args, themes)
+    }
+
+    // Prints themes using the list options.
+    fn print_theme_list(args: &Args, themes: Vec<&'static Theme>) -> ExitCode {
+        // The last of -1 and -C wins, as with `ls`.
+        let mode = args
+            .options
+            .iter()
+            .rev()
+            .find_map(|(opt, _)| match opt.long {
+                "one-per-line" => Some(Mode::OnePerLine),
+                "columns" => Some(Mode::Columns),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let group_by = args
+            .value("group-by")
+            .and_then(GroupBy::parse)
+            .or_else(|| args.flag("sections").then_some(GroupBy::FirstLetter));
+
+        let layout = Layout {
+            mode,
+            swatch: args.flag("swatch"),
+            group_by,
+        };
+
+        let themes = Self::sorted_themes(args, themes);
+
+        match print_list(themes, layout) {
+            // Stopping early because of e.g. `| head` isn't an error.
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+            Err(e) => fail!("{e}"),
+        }
+    }
+
+    /// Sorts themes as requested by the `--sort` and `--reverse` options.
+    pub fn sorted_themes(args: &Args, mut themes: Vec<&'static Theme>) -> Vec<&'static Theme> {
+        let key = args
+            .value("sort")
+            .and_then(SortKey::parse)
+            .unwrap_or_default();
+
+        let recent = if key == SortKey::Recent {
+            history::read()
+        } else {
+            Vec::new()
+        };
+
+        list::sort(&mut themes, key, &recent);
+
+        if args.flag("reverse") {
+            themes.reverse();
+        }
+
+        themes
+    }
+
+    /// Writes a theme to the color settings file and reloads the Termux
+    /// settings, or only shows what would change with `--dry-run`.
+    ///
+    /// With `--sync-ui`, `use-black-ui` is also set to match the theme.
+    pub fn apply_theme(theme: &Theme, args: &Args) -> ExitCode {
+        let changes = Self::color_changes(theme.to_settings_string(), theme, args);
+        let done = format!("Applied theme: {}", theme.name);
+
+        Self::apply_changes(
+            &changes,
+            Some(theme),
+            [&theme.name, &done],
+            args,
+            ExitCode::SUCCESS,
+        )
+    }
+
+    // Applies the theme with the given name.
+    fn apply_named_theme(name: &str, args: &Args) -> ExitCode {
+        Self::apply_theme(Self::resolve_theme(name.trim()), args)
+    }
+
+    // Validates and applies a color settings file.
+    fn import_file(file: &str, args: &Args) -> ExitCode {
+        let content = if file == "-" {
+            io::read_to_string(io::stdin())
+        } else {
+            fs::read_to_string(file)
+        };
+
+        let content = content.unwrap_or_else(|e| fail!("Unable to read {file}: {e}"));
+
+        let theme = properties::parse(&content).unwrap_or_else(|e| {
+            fail!("{file} is not a valid color settings file: {e}");
+        });
+
+        let changes = Self::color_changes(content, &theme, args);
+        let source = if file == "-" { "stdin" } else { file };
+        let done = format!("Imported {file}");
+
+        Self::apply_changes(
+            &changes,
+            Some(&theme),
+            [source, &done],
+            args,
+            ExitCode::SUCCESS,
+        )
+    }
+
+    // Returns the changes that apply new color settings, including the
+    // matching `use-black-ui` setting with `--sync-ui`.
+    fn color_changes(content: String, theme: &Theme, args: &Args) -> Vec<Change> {
+        let mut changes = vec![Change::settings(content).unwrap_or_else(|e| fail!("{e}"))];
+
+        if args.flag("sync-ui") {
+            let current = read_properties().unwrap_or_else(|e| fail!("{e}"));
+            let value = if theme.background.is_dark() {
+                "true"
+            } else {
+                "false"
+            };
+
+            if properties::get(&current, ui::BLACK_UI) != Some(value) {
+                let content = properties::set(&current, ui::BLACK_UI, value);
+                changes.push(Change::properties(content).unwrap_or_else(|e| fail!("{e}")));
+            }
+        }
+
+        changes
+    }
+
+    // Returns how to reload the Termux settings, or `None` with
+    // `--no-reload`.
+    fn reload(args: &Args) -> Option<Reload> {
+        if args.flag("no-reload") {
+            return None;
+        }
+
+        Some(Config::load().unwrap_or_else(|e| fail!("{e}")).reload)
+    }
+
+    // Writes every change, then reloads the Termux settings once. If the
+    // reload fails, the changes are undone so that the files keep matching
+    // what Termux shows.
+    fn write_and_reload(changes: &[Change], args: &Args) {
+        if let Err(e) = Self::try_write_and_reload(changes, Self::reload(args).as_ref()) {
+            fail!("{e}");
+        }
+    }
+
+    // Does the work of `write_and_reload`, returning any error.
+    fn try_write_and_reload(changes: &[Change], reload: Option<&Reload>) -> Result<(), String> {
+        let undo = write_all(changes)?;
+
+        let Some(reload) = reload else {
+            verbose!("not reloading the Termux settings");
+            return Ok(());
+        };
+
+        reload.run().map_err(|e| match undo.run() {
+            Ok(()) => format!("{e}; the previous settings were restored"),
+            Err(undo_err) => format!("{e}\n{undo_err}"),
+        })
+    }
+
+    // Makes the changes for a new theme, or with `--dry-run`, only lists
+    // them. `labels` are where the changes come from, for `--dry-run`, and
+    // the message reported once they are made.
+    //
+    // The pre-apply hooks run first, then the changes are written and Termux
+    // reloaded, the theme is recorded in the history and the generated files
+    // are rewritten, and the post-apply hooks run last. Without a theme, as
+    // for a profile that only sets a font, only the changes are made.
+    //
+    // Returns `status`, or `EXIT_FAILURE` if a generated file couldn't be
+    // written or a post-apply hook failed. The changes are kept either way.
+    fn apply_changes(
+        changes: &[Change],
+        theme: Option<&Theme>,
+        [source, done]: [&str; 2],
+        args: &Args,
+        status: ExitCode,
+    ) -> ExitCode {
+        // Rendering first means a bad configuration or template stops the
+        // change before anything runs.
+        let generated = theme.map(Self::generated_files).unwrap_or_default();
+        let run_hooks = |hook| theme.is_none_or(|theme| Self::run_hooks(hook, theme, args));
+
+        if args.flag("dry-run") {
+            run_hooks(Hook::PreApply);
+            Self::print_dry_run(changes, source, args);
+            Self::print_generated(&generated);
+            run_hooks(Hook::PostApply);
+
+            return status;
+        }
+
+        run_hooks(Hook::PreApply);
+        Self::write_and_reload(changes, args);
+
+        // The history only affects `--sort recent`, so failing to update it
+        // isn't worth failing the command.
+        if let Some(Err(e)) = theme.map(|theme| history::record(&theme.name)) {
+            verbose!("{e}");
+        }
+
+        let written = Self::write_generated(&generated).inspect_err(|e| util::print_error(e));
+
+        info!("{done}");
+
+        // The theme was changed either way, so the post-apply hooks still
+        // run.
+        let hooks_ok = run_hooks(Hook::PostApply);
+
+        if written.is_ok() && hooks_ok {
+            status
+        } else {
+            ExitCode::from(util::EXIT_FAILURE)
+        }
+    }
+
+    // Returns the paths and content of the generated files and templates
+    // listed in the configuration, rendered for a new theme. This is done
+    // before anything is written, so that a bad configuration or template
+    // stops the change.
+    fn generated_files(theme: &Theme) -> Vec<(PathBuf, String)> {
+        let config = Config::load().unwrap_or_else(|e| fail!("{e}"));
+        let dir = Paths::get().generated_dir();
+
+        let generated = config
+            .generate
+            .into_iter()
+            .map(|target| (dir.join(target.file_name()), target.render(theme)));
+
+        let rendered = config.templates.into_iter().map(|(name, path)| {
+            let template = templates::load(&name).unwrap_or_else(|e| fail!("{e}"));
+            (path, template.render(theme))
+        });
+
+        generated.chain(rendered).collect()
+    }
+
+    // Lists the generated files that a change would rewrite.
+    fn print_generated(files: &[(PathBuf, String)]) {
+        let is_color = use_color(io::stdout().is_terminal());
+
+        for (path, content) in files {
+            if fs::read_to_string(path).ok().as_ref() != Some(content) {
+                println!(
+                    "{}Would regenerate{} {}",
+                    if is_color { BLUE } else { "" },
+                    if is_color { CLR } else { "" },
+                    path.display()
+                );
+            }
+        }
+    }
+
+    // Writes generated files. A file that can't be written doesn't stop the
+    // others, and the errors for all of them are returned together.
+    fn write_generated(files: &[(PathBuf, String)]) -> Result<(), String> {
+        let errors = files
+            .iter()
+            .filter_map(|(path, content)| {
+                verbose!("writing {}", path.display());
+
+                path.parent()
+                    .map_or(Ok(()), fs::create_dir_all)
+                    .and_then(|()| fs::write(path, content))
+                    .err()
+                    .map(|e| format!("Unable to write {}: {e}", path.display()))
+            })
+            .collect::<Vec<String>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+
+    // Runs the hooks in the configuration file for a theme change, or with
+    // `--dry-run`, only lists them. Nothing runs with `--no-hooks`.
+    //
+    // A failed pre-apply hook stops the command before anything is written.
+    // A failed post-apply hook is reported and makes this return false, but
+    // the new theme is kept.
+    fn run_hooks(hook: Hook, theme: &Theme, args: &Args) -> bool {
+        if args.flag("no-hooks") {
+            return true;
+        }
+
+        let hooks = Config::load().unwrap_or_else(|e| fail!("{e}")).hooks;
+        let is_color = use_color(io::stdout().is_terminal());
+        let mut is_ok = true;
+
+        for cmd in hooks.commands(hook) {
+            if args.flag("dry-run") {
+                println!(
+                    "{}Would run {} hook{}: {cmd}",
+                    if is_color { BLUE } else { "" },
+                    hook.name(),
+                    if is_color { CLR } else { "" }
+                );
+
+                continue;
+            }
+
+            if let Err(e) = hooks::run(cmd, hook, theme, hooks.timeout) {
+                if hook == Hook::PreApply {
+                    fail!("{e}; the theme was not changed");
+                }
+
+                util::print_error(&e);
+                is_ok = false;
+            }
+        }
+
+        is_ok
+    }
+
+    // Loads a user template for `generate`, or returns a usage error if
+    // there is no template with that name.
+    fn user_template(cmd: &'static Command, name: &str) -> Result<Template, ExitCode> {
+        let names = templates::names().unwrap_or_else(|e| fail!("{e}"));
+
+        if names.iter().any(|n| n == name) {
+            return Ok(templates::load(name).unwrap_or_else(|e| fail!("{e}")));
+        }
+
+        let mut choices = Target::names().collect::<Vec<&str>>();
+        choices.extend(names.iter().map(String::as_str));
+
+        let mut msg = format!(
+            "\"{name}\" is not a target or a template (expected {})",
+            choices.join(", ")
+        );
+
+        if let Some(similar) = util::suggest(name, choices) {
+            let _ = write!(msg, "; did you mean \"{similar}\"?");
+        }
+
+        Err(Self::usage_error(&ArgError::InvalidArgument(cmd, msg)))
+    }
+
+    // Returns the theme named by the positional arguments, or the colors in
+    // the color settings file if there are none.
+    fn named_or_current_theme(name: &[String]) -> Theme {
         if name.is_empty() {
-            fail!("Missing required argument for 'show'");
+            read_theme().unwrap_or_else(|e| fail!("{e}"))
+        } else {
+            Self::resolve_theme(name.join(" ").trim()).clone()
+        }
+    }
+
+    // Opens the palette editor on a theme, or on the current colors.
+    fn edit(args: &Args) -> ExitCode {
+        let theme = Self::named_or_current_theme(&args.positionals);
+
+        Editor::run(theme).map_or_else(|e| fail!("{e}"), |()| ExitCode::SUCCESS)
+    }
+
+    // Prints a theme's colors as variable assignments for a shell.
+    fn print_env(args: &Args) -> ExitCode {
+        let theme = Self::named_or_current_theme(&args.positionals);
+        let shell = args.value("shell").unwrap_or("sh");
+
+        let mut out = io::stdout().lock();
+
+        match Self::write_env(&mut out, &theme, shell).and_then(|()| out.flush()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+            Err(e) => fail!("{e}"),
         }
+    }
+
+    // Writes the output of `print_env`, quoting each value for the shell.
+    fn write_env<W: Write>(out: &mut W, theme: &Theme, shell: &str) -> IoResult<()> {
+        for (key, value) in hooks::theme_env(theme) {
+            match shell {
+                "fish" => {
+                    let value = value.replace('\\', "\\\\").replace('\'', "\\'");
+                    writeln!(out, "set -gx {key} '{value}'")?;
+                }
+                "nu" => {
+                    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+                    writeln!(out, "$env.{key} = \"{value}\"")?;
+                }
+                _ => writeln!(out, "export {key}='{}'", value.replace('\'', "'\\''"))?,
+            }
+        }
+
+        Ok(())
+    }
+
+    // Prints one color of a theme in the requested format.
+    fn print_slot(cmd: &'static Command, args: &Args) -> ExitCode {
+        let (slot, rest) = args.positionals.split_first().expect("get requires a slot");
+
+        if !properties::COLOR_KEYS.contains(&slot.as_str()) {
+            let mut msg = format!(
+                "\"{slot}\" is not a color slot (expected color0 to color15, background, \
+                foreground or cursor)"
+            );
 
-        if let Some(theme) = Themes::init().get(name) {
-            if let Err(e) = theme.print_values() {
-                fail!("{e}");
+            if let Some(similar) = util::suggest(slot, properties::COLOR_KEYS) {
+                let _ = write!(msg, "; did you mean \"{similar}\"?");
             }
 
-            ExitCode::SUCCESS
+            return Self::usage_error(&ArgError::InvalidArgument(cmd, msg));
+        }
+
+        let theme = Self::named_or_current_theme(rest);
+        let Rgb { r, g, b } = theme.slot(slot).expect("slot is a color key");
+
+        match args.value("format").unwrap_or("hex") {
+            "rgb" => println!("{r},{g},{b}"),
+            "ansi-fg" => println!("\x1b[38;2;{r};{g};{b}m"),
+            "ansi-bg" => println!("\x1b[48;2;{r};{g};{b}m"),
+            "decimal" => println!("{}", u32::from_be_bytes([0, r, g, b])),
+            _ => println!("{}", Rgb::new(r, g, b)),
+        }
+
+        ExitCode::SUCCESS
+    }
+
+    // Prints or writes the config for a target or a user template, in the
+    // colors of the given theme or of the current settings.
+    fn generate(cmd: &'static Command, args: &Args) -> ExitCode {
+        let (name, rest) = args
+            .positionals
+            .split_first()
+            .expect("generate requires a target");
+
+        let render: Box<dyn Fn(&Theme) -> String> = if let Some(target) = Target::from_name(name) {
+            Box::new(move |theme| target.render(theme))
         } else {
-            fail!("\"{name}\" is not a valid theme");
+            match Self::user_template(cmd, name) {
+                Ok(template) => Box::new(move |theme| template.render(theme)),
+                Err(code) => return code,
+            }
+        };
+
+        let content = render(&Self::named_or_current_theme(rest));
+
+        match args.value("output").filter(|path| *path != "-") {
+            Some(path) => {
+                if let Err(e) = fs::write(path, content) {
+                    fail!("Unable to write {path}: {e}");
+                }
+
+                info!("Wrote {path}");
+            }
+            None => print!("{content}"),
         }
+
+        ExitCode::SUCCESS
+    }
+
+    // Derives a theme from a base color, then prints, previews or saves it.
+    fn generate_theme(cmd: &'static Command, args: &Args) -> ExitCode {
+        let Some(base) = args.value("base") else {
+            let msg = String::from("a base color is required (--base HEX)");
+            return Self::usage_error(&ArgError::InvalidArgument(cmd, msg));
+        };
+
+        // A leading '#' starts a comment in the shell, so it is optional.
+        let hex = if base.starts_with('#') {
+            base.to_string()
+        } else {
+            format!("#{base}")
+        };
+
+        let base = match hex.parse::<Rgb>() {
+            Ok(color) => color,
+            Err(e) => return Self::usage_error(&ArgError::InvalidArgument(cmd, e.to_string())),
+        };
+
+        let dark = match Self::background_choice(cmd, args) {
+            Ok(dark) => dark.unwrap_or_else(|| base.is_dark()),
+            Err(code) => return code,
+        };
+
+        let style = args
+            .value("style")
+            .and_then(Style::from_name)
+            .unwrap_or_default();
+
+        Self::output_theme(palette::generate(base, dark, style), args)
+    }
+
+    // Extracts a theme from the colors of an image, then prints, previews or
+    // saves it.
+    fn theme_from_image(cmd: &'static Command, args: &Args) -> ExitCode {
+        let dark = match Self::background_choice(cmd, args) {
+            Ok(dark) => dark,
+            Err(code) => return code,
+        };
+
+        let path = Path::new(&args.positionals[0]);
+        let pixels = image::read(path).unwrap_or_else(|e| fail!("{e}"));
+
+        verbose!("clustering the colors of {} pixels", pixels.len());
+
+        let Some(mut theme) = palette::extract(&pixels, dark) else {
+            fail!("{} has no opaque pixels", path.display());
+        };
+
+        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+            theme.name = stem.to_string().into();
+        }
+
+        Self::output_theme(theme, args)
+    }
+
+    // Returns whether `--dark` or `--light` asked for a dark background, if
+    // either did.
+    fn background_choice(cmd: &'static Command, args: &Args) -> Result<Option<bool>, ExitCode> {
+        match (args.flag("dark"), args.flag("light")) {
+            (true, true) => {
+                let msg = String::from("--dark and --light can't be used together");
+                Err(Self::usage_error(&ArgError::InvalidArgument(cmd, msg)))
+            }
+            (true, false) => Ok(Some(true)),
+            (false, true) => Ok(Some(false)),
+            (false, false) => Ok(None),
+        }
+    }
+
+    // Names a new theme with `--name`, then saves it with `--save`, sends it
+    // to the terminal with `--preview`, or prints its settings.
+    fn output_theme(mut theme: Theme, args: &Args) -> ExitCode {
+        if let Some(name) = args.value("name") {
+            theme.name = name.trim().to_string().into();
+        }
+
+        for (name, color) in theme.slots() {
+            verbose!(
+                "{name} {color} has a contrast of {:.1}",
+                color.contrast(theme.background)
+            );
+        }
+
+        if args.flag("save") {
+            let path = user_themes::save(&theme).unwrap_or_else(|e| fail!("{e}"));
+            info!(
+                "Saved {}; apply it with: themux apply {}",
+                path.display(),
+                util::shell_quote(&theme.name)
+            );
+        }
+
+        let output = if args.flag("preview") {
+            watch::preview_sequences(&theme)
+        } else if args.flag("save") {
+            return ExitCode::SUCCESS;
+        } else {
+            user_themes::file_content(&theme)
+        };
+
+        let mut out = io::stdout().lock();
+
+        match write!(out, "{output}").and_then(|()| out.flush()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+            Err(e) => fail!("{e}"),
+        }
+    }
+
+    // Dispatches the `profile` command's actions.
+    fn handle_profile(cmd: &'static Command, args: &Args) -> ExitCode {
+        const ACTIONS: &[&str] = &["list", "show", "save", "use", "delete"];
+
+        let (action, rest) = args
+            .positionals
+            .split_first()
+            .expect("profile requires an action");
+
+        let res = match (action.as_str(), rest) {
+            ("list", []) => Self::list_profiles(),
+            ("show", [name]) => profile::read(name).map(|content| {
+                print!("{content}");
+                ExitCode::SUCCESS
+            }),
+            ("save", [name]) => Profile::capture()
+                .and_then(|profile| profile.save(name))
+                .map(|()| {
+                    info!("Saved profile: {name}");
+                    ExitCode::SUCCESS
+                }),
+            ("use", [name]) => Self::use_profile(name, args),
+            ("delete", [name]) => profile::delete(name).map(|()| {
+                info!("Deleted profile: {name}");
+                ExitCode::SUCCESS
+            }),
+            ("show" | "save" | "use" | "delete", []) => {
+                return Self::usage_error(&ArgError::MissingArgument(cmd));
+            }
+            ("list", [extra, ..]) | (_, [_, extra, ..]) if ACTIONS.contains(&action.as_str()) => {
+                return Self::usage_error(&ArgError::UnexpectedArgument(cmd, extra.clone()));
+            }
+            (action, _) => {
+                return Self::usage_error(&ArgError::InvalidChoice(
+                    cmd,
+                    "action",
+                    action.to_string(),
+                    ACTIONS.to_vec(),
+                ));
+            }
+        };
+
+        res.unwrap_or_else(|e| fail!("{e}"))
+    }
+
+    // Prints the names of the saved profiles.
+    fn list_profiles() -> Result<ExitCode, String> {
+        let names = profile::names()?;
+
+        if names.is_empty() {
+            info!(
+                "No profiles saved in {}",
+                Paths::get().profiles_dir().display()
+            );
+        }
+
+        for name in names {
+            println!("{name}");
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    // Applies every part of a profile, with a single reload.
+    fn use_profile(name: &str, args: &Args) -> Result<ExitCode, String> {
+        let profile = Profile::load(name)?;
+        let changes = profile.changes()?;
+
+        if changes.is_empty() && !args.flag("dry-run") {
+            info!("Profile {name} is already in use");
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let done = format!("Switched to profile: {name}");
+
+        Ok(Self::apply_changes(
+            &changes,
+            profile.theme.as_ref(),
+            [name, &done],
+            args,
+            ExitCode::SUCCESS,
+        ))
+    }
+
+    // Applies a desired-state file, exiting with `EXIT_CHANGED` if anything
+    // was (or with `--dry-run`, would be) changed.
+    fn sync(args: &Args) -> ExitCode {
+        let path = args
+            .value("file")
+            .map_or_else(|| Paths::get().sync_file(), PathBuf::from);
+
+        let label = path.display().to_string();
+
+        let content = if label == "-" {
+            io::read_to_string(io::stdin())
+        } else {
+            fs::read_to_string(&path)
+        };
+
+        let content = content.unwrap_or_else(|e| fail!("Unable to read {label}: {e}"));
+
+        let profile = Profile::parse(&content).unwrap_or_else(|e| fail!("{label}: {e}"));
+        let changes = profile.changes().unwrap_or_else(|e| fail!("{e}"));
+
+        if changes.is_empty() {
+            verbose!("everything matches {label}");
+            return ExitCode::SUCCESS;
+        }
+
+        let done = changes
+            .iter()
+            .map(|change| format!("Updated {}", change.path.display()))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Self::apply_changes(
+            &changes,
+            profile.theme.as_ref(),
+            [&label, &done],
+            args,
+            ExitCode::from(util::EXIT_CHANGED),
+        )
+    }
+
+    // Applies a color settings file, or previews it with `--preview`, then
+    // again each time it is saved, until interrupted.
+    fn watch(args: &Args) -> ExitCode {
+        let file = &args.positionals[0];
+        let path = PathBuf::from(file);
+        let preview = args.flag("preview");
+        let reload = if preview { None } else { Self::reload(args) };
+
+        let watcher = Watcher::new(&path).unwrap_or_else(|e| fail!("Unable to watch {file}: {e}"));
+
+        info!("Watching {file}, press Ctrl-C to stop");
+
+        let mut last = None;
+        let mut is_backed_up = false;
+
+        loop {
+            Self::watch_step(
+                &path,
+                preview,
+                reload.as_ref(),
+                &mut last,
+                &mut is_backed_up,
+            );
+
+            if let Err(e) = watcher.wait() {
+                fail!("Unable to watch {file}: {e}");
+            }
+        }
+    }
+
+    // Applies or previews the watched file if its content differs from the
+    // last time. Errors are reported without stopping the watch.
+    //
+    // Content that fails to parse is remembered, since it would fail again,
+    // but content that fails to be written or reloaded isn't, so that the
+    // next save retries it even if nothing changed.
+    //
+    // Only the first write of a session backs up the color settings file,
+    // so that `restore` returns to the theme from before the watch.
+    fn watch_step(
+        path: &Path,
+        preview: bool,
+        reload: Option<&Reload>,
+        last: &mut Option<String>,
+        is_backed_up: &mut bool,
+    ) {
+        let label = path.display();
+
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => return util::print_error(&format!("Unable to read {label}: {e}")),
+        };
+
+        if last.as_ref() == Some(&content) {
+            verbose!("{label} is unchanged");
+            return;
+        }
+
+        let theme = match properties::parse(&content) {
+            Ok(theme) => theme,
+            Err(e) => {
+                util::print_error(&format!("{label} is not a valid color settings file: {e}"));
+                *last = Some(content);
+                return;
+            }
+        };
+
+        let res = if preview {
+            let mut out = io::stdout().lock();
+
+            write!(out, "{}", watch::preview_sequences(&theme))
+                .and_then(|()| out.flush())
+                .map_err(|e| e.to_string())
+                .map(|()| "Previewed")
+        } else {
+            Change::settings(content.clone())
+                .map(|change| {
+                    if *is_backed_up {
+                        change.without_backup()
+                    } else {
+                        change
+                    }
+                })
+                .and_then(|change| Self::try_write_and_reload(&[change], reload))
+                .map(|()| {
+                    *is_backed_up = true;
+                    "Applied"
+                })
+        };
+
+        match res {
+            Ok(done) => {
+                info!("{done} {label}");
+                *last = Some(content);
+            }
+            Err(e) => util::print_error(&e),
+        }
+    }
+
+    // Dispatches the `ui` command's actions.
+    fn handle_ui(cmd: &'static Command, args: &Args) -> ExitCode {
+        const ACTIONS: &[&str] = &["get", "set"];
+
+        let (action, rest) = args
+            .positionals
+            .split_first()
+            .expect("ui requires an action");
+
+        let find_key = |name: &str| {
+            Key::find(name).ok_or_else(|| {
+                let names = Key::names().collect();
+                ArgError::InvalidChoice(cmd, "key", name.to_string(), names)
+            })
+        };
+
+        let res = match (action.as_str(), rest) {
+            ("get", []) => return Self::print_ui_keys(),
+            ("get", [key]) => find_key(key).map(Self::print_ui_key),
+            ("set", [key, value]) => find_key(key).and_then(|key| {
+                key.validate(value)
+                    .map_err(|e| ArgError::InvalidArgument(cmd, e))
+                    .map(|()| Self::set_ui_key(key, value, args))
+            }),
+            ("set", [] | [_]) => Err(ArgError::MissingArgument(cmd)),
+            ("get", [_, extra, ..]) | ("set", [_, _, extra, ..]) => {
+                Err(ArgError::UnexpectedArgument(cmd, extra.clone()))
+            }
+            (action, _) => Err(ArgError::InvalidChoice(
+                cmd,
+                "action",
+                action.to_string(),
+                ACTIONS.to_vec(),
+            )),
+        };
+
+        res.unwrap_or_else(|e| Self::usage_error(&e))
+    }
+
+    // Prints every managed key that termux.properties sets.
+    fn print_ui_keys() -> ExitCode {
+        let content = read_properties().unwrap_or_else(|e| fail!("{e}"));
+
+        let mut out = io::stdout().lock();
+
+        let res = Key::names()
+            .filter_map(|key| properties::get(&content, key).map(|value| (key, value)))
+            .try_for_each(|(key, value)| writeln!(out, "{key} = {value}"));
+
+        res.and_then(|()| out.flush())
+            .map_or_else(|e| fail!("{e}"), |()| ExitCode::SUCCESS)
+    }
+
+    // Prints the value of a key, failing quietly if it isn't set, as
+    // `git config` does.
+    fn print_ui_key(key: &Key) -> ExitCode {
+        let content = read_properties().unwrap_or_else(|e| fail!("{e}"));
+
+        properties::get(&content, key.name).map_or_else(
+            || {
+                verbose!("{} is not set", key.name);
+                ExitCode::from(util::EXIT_FAILURE)
+            },
+            |value| {
+                println!("{value}");
+                ExitCode::SUCCESS
+            },
+        )
+    }
+
+    // Sets a key in termux.properties and reloads the Termux settings.
+    fn set_ui_key(key: &Key, value: &str, args: &Args) -> ExitCode {
+        let current = read_properties().unwrap_or_else(|e| fail!("{e}"));
+
+        let content = properties::set(&current, key.name, value);
+        let change = Change::properties(content).unwrap_or_else(|e| fail!("{e}"));
+
+        if args.flag("dry-run") {
+            return Self::print_dry_run(&[change], &format!("{} = {value}", key.name), args);
+        }
+
+        if properties::get(&current, key.name) == Some(value) {
+            info!("{} is already {value}", key.name);
+            return ExitCode::SUCCESS;
+        }
+
+        Self::write_and_reload(&[change], args);
+
+        info!("Set {} to {value}", key.name);
+
+        ExitCode::SUCCESS
+    }
+
+    // Dispatches the `font` command's actions.
+    fn handle_font(cmd: &'static Command, args: &Args) -> ExitCode {
+        const ACTIONS: &[&str] = &["list", "set", "current"];
+
+        let (action, rest) = args
+            .positionals
+            .split_first()
+            .expect("font requires an action");
+
+        match (action.as_str(), rest) {
+            ("list", []) => Self::list_fonts(),
+            ("set", [font]) => Self::set_font(font, args),
+            ("current", []) => Self::print_current_font(),
+            ("set", []) => Self::usage_error(&ArgError::MissingArgument(cmd)),
+            ("set", [_, extra, ..]) | ("list" | "current", [extra, ..]) => {
+                Self::usage_error(&ArgError::UnexpectedArgument(cmd, extra.clone()))
+            }
+            (action, _) => Self::usage_error(&ArgError::InvalidChoice(
+                cmd,
+                "action",
+                action.to_string(),
+                ACTIONS.to_vec(),
+            )),
+        }
+    }
+
+    // Prints the fonts in the font directory, marking the installed one.
+    fn list_fonts() -> ExitCode {
+        let fonts = font::available().unwrap_or_else(|e| fail!("{e}"));
+
+        if fonts.is_empty() {
+            info!("No fonts found in {}", Paths::get().fonts_dir().display());
+            return ExitCode::SUCCESS;
+        }
+
+        let installed = font::installed_hash().unwrap_or_else(|e| fail!("{e}"));
+
+        let mut out = io::stdout().lock();
+        let is_color = use_color(out.is_terminal());
+
+        let res = fonts.iter().try_for_each(|path| {
+            let is_installed = installed.is_some()
+                && fs::read(path).ok().map(|data| font::hash(&data)) == installed;
+
+            if is_installed {
+                writeln!(
+                    out,
+                    "* {}{}{}",
+                    if is_color { GRN } else { "" },
+                    font::name(path),
+                    if is_color { CLR } else { "" }
+                )
+            } else {
+                writeln!(out, "  {}", font::name(path))
+            }
+        });
+
+        match res.and_then(|()| out.flush()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+            Err(e) => fail!("{e}"),
+        }
+    }
+
+    // Validates and installs a font, then reloads the Termux settings.
+    fn set_font(name: &str, args: &Args) -> ExitCode {
+        let path = font::find(name).unwrap_or_else(|e| fail!("{e}"));
+        let data = font::read(&path).unwrap_or_else(|e| fail!("{e}"));
+
+        verbose!("found font {}", path.display());
+
+        let change = Change::font(data).unwrap_or_else(|e| fail!("{e}"));
+
+        if args.flag("dry-run") {
+            return Self::print_dry_run(&[change], &path.display().to_string(), args);
+        }
+
+        Self::write_and_reload(&[change], args);
+
+        info!("Installed font: {}", font::name(&path));
+
+        ExitCode::SUCCESS
+    }
+
+    // Prints the name of the installed font, identified by its content.
+    fn print_current_font() -> ExitCode {
+        let name = match font::installed_hash() {
+            Ok(None) => String::from("Termux default"),
+            Ok(Some(hash)) => {
+                let fonts = font::available().unwrap_or_else(|e| fail!("{e}"));
+
+                fonts
+                    .iter()
+                    .find(|path| fs::read(path).is_ok_and(|data| font::hash(&data) == hash))
+                    .map_or_else(
+                        || format!("unknown (hash {hash:016x})"),
+                        |path| font::name(path),
+                    )
+            }
+            Err(e) => fail!("{e}"),
+        };
+
+        Self::print_name("font", &name).map_or_else(|e| fail!("{e}"), |()| ExitCode::SUCCESS)
+    }
+
+    // Restores the backed up color settings file. Writing it backs up the
+    // current file in its place, so restoring again undoes it.
+    fn restore_theme(args: &Args) -> ExitCode {
+        let backup = Paths::get().backup_file();
+
+        let content = fs::read_to_string(&backup).unwrap_or_else(|e| {
+            fail!("Unable to read backup {}: {e}", backup.display());
+        });
+
+        // Generated files and hooks follow the restored colors, if they can
+        // be parsed.
+        let theme = properties::parse(&content).ok();
+        let change = Change::settings(content).unwrap_or_else(|e| fail!("{e}"));
+        let source = backup.display().to_string();
+
+        Self::apply_changes(
+            &[change],
+            theme.as_ref(),
+            [&source, "Restored the previous theme"],
+            args,
+            ExitCode::SUCCESS,
+        )
+    }
+
+    // Prints the paths of and diffs for changes that are not performed, and
+    // the reload command that would follow them.
+    fn print_dry_run(changes: &[Change], source: &str, args: &Args) -> ExitCode {
+        let reload = Self::reload(args).map(|reload| reload.command());
+
+        let mut out = io::stdout().lock();
+        let is_color = use_color(out.is_terminal());
+
+        match Self::write_dry_run(&mut out, changes, source, reload.as_deref(), is_color)
+            .and_then(|()| out.flush())
+        {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+            Err(e) => fail!("{e}"),
+        }
+    }
+
+    // Writes the output of `print_dry_run`.
+    fn write_dry_run<W: Write>(
+        out: &mut W,
+        changes: &[Change],
+        source: &str,
+        reload: Option<&str>,
+        is_color: bool,
+    ) -> IoResult<()> {
+        let color = |c| if is_color { c } else { "" };
+        let mut is_changed = false;
+
+        for change in changes {
+            let current = fs::read(&change.path).unwrap_or_default();
+            let label = change.path.display().to_string();
+
+            let backup = match &change.backup {
+                Some(backup) if !current.is_empty() => {
+                    format!(" (backing up to {})", backup.display())
+                }
+                _ => String::new(),
+            };
+
+            let (Ok(old), Ok(new)) = (str::from_utf8(&current), str::from_utf8(&change.content))
+            else {
+                // Binary files such as fonts are only compared.
+                if current == change.content {
+                    writeln!(out, "No changes to {label}")?;
+                } else {
+                    writeln!(
+                        out,
+                        "{}Would write{} {label} from {source}{backup}",
+                        color(BLUE),
+                        color(CLR)
+                    )?;
+                    is_changed = true;
+                }
+
+                continue;
+            };
+
+            let diff = unified_diff(
+                old,
+                new,
+                &label,
+                &format!("{label} ({source})"),
+                [color(RED), color(GRN), color(CYAN)],
+                color(CLR),
+            );
+
+            if diff.is_empty() {
+                writeln!(out, "No changes to {label}")?;
+                continue;
+            }
+
+            write!(
+                out,
+                "{}Would write{} {label}{backup}\n{diff}",
+                color(BLUE),
+                color(CLR)
+            )?;
+            is_changed = true;
+        }
+
+        if let Some(cmd) = reload.filter(|_| is_changed) {
+            writeln!(out, "{}Would run{}: {cmd}", color(BLUE), color(CLR))?;
+        }
+
+        Ok(())
+    }
+
+    // Resolves a theme name to a user theme with that exact name or else a
+    // built-in theme, exiting with a list of candidates on failure.
+    fn resolve_theme(name: &str) -> &'static Theme {
+        let unresolved = match user_themes::resolve(name) {
+            Ok(theme) => return theme,
+            Err(unresolved) => unresolved,
+        };
+
+        let msg = match unresolved {
+            Unresolved::Ambiguous(_) => {
+                format!("\"{name}\" matches more than one theme")
+            }
+            Unresolved::NotFound(_) => format!("\"{name}\" is not a valid theme"),
+        };
+
+        util::print_error(&msg);
+
+        let candidates = unresolved.candidates();
+
+        if !candidates.is_empty() {
+            eprintln!(
+                "{}",
+                match unresolved {
+                    Unresolved::Ambiguous(_) => "Matching themes:",
+                    Unresolved::NotFound(_) => "Did you mean one of these?",
+                }
+            );
+
+            for theme in candidates {
+                eprintln!("    {}", theme.name);
+            }
+        }
+
+        process::exit(i32::from(util::EXIT_FAILURE));
+    }
+
+    // Prints the color value settings for a given theme.
+    fn print_theme_settings(name: &str) -> ExitCode {
+        let name = name.trim();
+
+        let theme = Self::resolve_theme(name);
+
+        if let Err(e) = print_values(theme) {
+            fail!("{e}");
+        }
+
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the line `write_env` gives for the theme's name.
+    fn env_name(name: &str, shell: &str) -> String {
+        let mut theme = Themes::init().get("Nord").unwrap().clone();
+        theme.name = name.to_string().into();
+
+        let mut out = Vec::new();
+        Cli::write_env(&mut out, &theme, shell).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        out.lines().next().unwrap().to_string()
+    }
+
+    #[test]
+    fn write_env_quotes_for_each_shell() {
+        let name = r#"It's "x" \ $HOME"#;
+
+        assert_eq!(
+            env_name(name, "sh"),
+            r#"export THEMUX_NAME='It'\''s "x" \ $HOME'"#
+        );
+        assert_eq!(
+            env_name(name, "fish"),
+            r#"set -gx THEMUX_NAME 'It\'s "x" \\ $HOME'"#
+        );
+        assert_eq!(
+            env_name(name, "nu"),
+            r#"$env.THEMUX_NAME = "It's \"x\" \\ $HOME""#
+        );
     }
 }
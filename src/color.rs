@@ -0,0 +1,334 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// A color in RGB format.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rgb {
+    /// Red.
+    pub r: u8,
+    /// Green.
+    pub g: u8,
+    /// Blue.
+    pub b: u8,
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = Error;
+
+    /// Parses a color in the `#RRGGBB` or `#RGB` hex format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(hex) = s.strip_prefix('#') else {
+            return Err(Error::InvalidColor(format!(
+                "\"{s}\" does not start with '#'"
+            )));
+        };
+
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::InvalidColor(format!("\"{s}\" is not a hex color")));
+        }
+
+        let channel =
+            |digits: &str| u8::from_str_radix(digits, 16).expect("hex digits were validated");
+
+        match hex.len() {
+            6 => Ok(Self {
+                r: channel(&hex[0..2]),
+                g: channel(&hex[2..4]),
+                b: channel(&hex[4..6]),
+            }),
+            // Each digit is repeated, so "#F80" is "#FF8800".
+            3 => Ok(Self {
+                r: channel(&hex[0..1]) * 17,
+                g: channel(&hex[1..2]) * 17,
+                b: channel(&hex[2..3]) * 17,
+            }),
+            _ => Err(Error::InvalidColor(format!(
+                "\"{s}\" must have 3 or 6 hex digits"
+            ))),
+        }
+    }
+}
+
+impl From<[u8; 3]> for Rgb {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl Rgb {
+    /// Creates a color from its channels.
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Calculates the perceived brightness from the RGB value and returns
+    /// true if it is considered dark.
+    //
+    // See: https://www.nbdtech.com/Blog/archive/2008/04/27/Calculating-the-
+    // Perceived-Brightness-of-a-Color.aspx
+    #[must_use]
+    pub fn is_dark(self) -> bool {
+        let r = f32::from(self.r) * f32::from(self.r) * 0.241_f32;
+        let g = f32::from(self.g) * f32::from(self.g) * 0.691_f32;
+        let b = f32::from(self.b) * f32::from(self.b) * 0.068_f32;
+
+        (r + g + b).sqrt().floor() < 130.0
+    }
+
+    /// Returns the relative luminance, from 0 for black to 1 for white.
+    //
+    // See: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    #[must_use]
+    pub fn luminance(self) -> f64 {
+        let [r, g, b] = [self.r, self.g, self.b].map(linearize);
+
+        0.0722_f64.mul_add(b, 0.2126_f64.mul_add(r, 0.7152 * g))
+    }
+
+    /// Returns the contrast ratio between two colors, from 1 for identical
+    /// colors to 21 for black and white.
+    //
+    // See: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    #[must_use]
+    pub fn contrast(self, other: Self) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns the hue in degrees, and the saturation and lightness from 0 to
+    /// 1. Grays have a hue and saturation of 0.
+    #[must_use]
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+
+        let [r, g, b] = [self.r, self.g, self.b].map(|c| f64::from(c) / 255.0);
+        let (hi, lo) = (f64::from(max) / 255.0, f64::from(min) / 255.0);
+
+        let lightness = f64::midpoint(hi, lo);
+
+        if max == min {
+            return (0.0, 0.0, lightness);
+        }
+
+        let delta = hi - lo;
+        let saturation = delta / (1.0 - 2.0_f64.mul_add(lightness, -1.0).abs());
+
+        let sector = if max == self.r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        (sector * 60.0, saturation, lightness)
+    }
+
+    /// Creates a color from a hue in degrees, and a saturation and lightness
+    /// from 0 to 1.
+    //
+    // See: https://www.w3.org/TR/css-color-4/#hsl-to-rgb
+    #[must_use]
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let amount = saturation * lightness.min(1.0 - lightness);
+
+        let channel = |n: f64| {
+            let k = (n + hue.rem_euclid(360.0) / 30.0) % 12.0;
+            let value = amount.mul_add(-(k - 3.0).min(9.0 - k).clamp(-1.0, 1.0), lightness);
+
+            to_channel(value * 255.0)
+        };
+
+        Self::new(channel(0.0), channel(8.0), channel(4.0))
+    }
+
+    /// Returns a color `amount` of the way from this color to `other`, where
+    /// 0 is this color and 1 is `other`.
+    #[must_use]
+    pub fn mix(self, other: Self, amount: f64) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| {
+            to_channel((f64::from(to) - f64::from(from)).mul_add(amount, f64::from(from)))
+        };
+
+        Self::new(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+        )
+    }
+
+    /// Returns the color in the Oklab color space: the perceived lightness
+    /// from 0 to 1, then the green-red and blue-yellow axes.
+    //
+    // See: https://bottosson.github.io/posts/oklab/
+    #[must_use]
+    pub fn to_oklab(self) -> (f64, f64, f64) {
+        let rgb = [self.r, self.g, self.b].map(linearize);
+        let lms = RGB_TO_LMS.map(|row| dot(row, rgb).cbrt());
+
+        LMS_TO_OKLAB.map(|row| dot(row, lms)).into()
+    }
+
+    /// Creates a color from its Oklab coordinates, clamping each channel to
+    /// the sRGB gamut.
+    #[must_use]
+    pub fn from_oklab(lightness: f64, a: f64, b: f64) -> Self {
+        let [r, g, b] = oklab_to_linear(lightness, a, b).map(|c| to_channel(gamma(c) * 255.0));
+
+        Self::new(r, g, b)
+    }
+
+    /// Creates a color from its Oklch coordinates. Colors outside the sRGB
+    /// gamut keep their lightness and hue and lose just enough chroma to fit.
+    #[must_use]
+    pub fn from_oklch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        let lightness = lightness.clamp(0.0, 1.0);
+        let (sin, cos) = hue.to_radians().sin_cos();
+        let lab = |chroma: f64| (lightness, chroma * cos, chroma * sin);
+
+        let in_gamut = |chroma: f64| {
+            let (l, a, b) = lab(chroma);
+            oklab_to_linear(l, a, b)
+                .iter()
+                .all(|c| (-1e-6..=1.0 + 1e-6).contains(c))
+        };
+
+        // The gamut boundary is found by bisecting the chroma.
+        let mut chroma = chroma.max(0.0);
+
+        if !in_gamut(chroma) {
+            let (mut lo, mut hi) = (0.0, chroma);
+
+            for _ in 0..24 {
+                let mid = f64::midpoint(lo, hi);
+                if in_gamut(mid) {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+
+            chroma = lo;
+        }
+
+        let (l, a, b) = lab(chroma);
+
+        Self::from_oklab(l, a, b)
+    }
+
+    /// Returns the color in the Oklch color space: the perceived lightness
+    /// from 0 to 1, the chroma from 0 to about 0.32 for sRGB colors, and the
+    /// hue in degrees. Grays have a hue of 0.
+    #[must_use]
+    pub fn to_oklch(self) -> (f64, f64, f64) {
+        let (lightness, a, b) = self.to_oklab();
+        let chroma = a.hypot(b);
+
+        // Rounding leaves grays with a tiny chroma and an arbitrary hue.
+        if chroma < 1e-4 {
+            return (lightness, 0.0, 0.0);
+        }
+
+        (lightness, chroma, b.atan2(a).to_degrees().rem_euclid(360.0))
+    }
+}
+
+/// Converts linear sRGB to cone responses, as used by Oklab.
+const RGB_TO_LMS: [[f64; 3]; 3] = [
+    [0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9],
+    [0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6],
+    [0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5],
+];
+
+/// Converts cube roots of cone responses to Oklab.
+const LMS_TO_OKLAB: [[f64; 3]; 3] = [
+    [0.210_454_255_3, 0.793_617_785_0, -0.004_072_046_8],
+    [1.977_998_495_1, -2.428_592_205_0, 0.450_593_709_9],
+    [0.025_904_037_1, 0.782_771_766_2, -0.808_675_766_0],
+];
+
+/// Converts Oklab to cube roots of cone responses.
+const OKLAB_TO_LMS: [[f64; 3]; 3] = [
+    [1.0, 0.396_337_777_4, 0.215_803_757_3],
+    [1.0, -0.105_561_345_8, -0.063_854_172_8],
+    [1.0, -0.089_484_177_5, -1.291_485_548_0],
+];
+
+/// Converts cone responses to linear sRGB.
+const LMS_TO_RGB: [[f64; 3]; 3] = [
+    [4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2],
+    [-1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5],
+    [-0.004_196_086_3, -0.703_418_614_7, 1.707_614_701_0],
+];
+
+// Converts Oklab to linear sRGB channels, which are outside 0 to 1 for
+// colors outside the sRGB gamut.
+fn oklab_to_linear(lightness: f64, a: f64, b: f64) -> [f64; 3] {
+    let lms = OKLAB_TO_LMS.map(|row| dot(row, [lightness, a, b]).powi(3));
+
+    LMS_TO_RGB.map(|row| dot(row, lms))
+}
+
+// Multiplies a matrix row by a vector.
+fn dot(row: [f64; 3], v: [f64; 3]) -> f64 {
+    row[0].mul_add(v[0], row[1].mul_add(v[1], row[2] * v[2]))
+}
+
+// Converts a gamma-encoded sRGB channel to linear light.
+fn linearize(channel: u8) -> f64 {
+    let c = f64::from(channel) / 255.0;
+
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Converts a linear light channel to gamma-encoded sRGB, from 0 to 1.
+fn gamma(c: f64) -> f64 {
+    let c = c.clamp(0.0, 1.0);
+
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055_f64.mul_add(c.powf(1.0 / 2.4), -0.055)
+    }
+}
+
+// Rounds a channel value to the nearest `u8`.
+// The value is clamped first, so the cast can't truncate or lose the sign.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn to_channel(value: f64) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+// Colors are (de)serialized as "#RRGGBB" strings, matching colors.properties.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rgb {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rgb {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use themux::generate::Target;
+use toml::Value;
+
+use crate::document::{Document, Entry};
+use crate::hooks::Hooks;
+use crate::termux::{Paths, Reload};
+use crate::util::suggest;
+
+/// Settings read from `config.toml` in the config directory:
+///
+/// ```toml
+/// [generate]
+/// targets = ["fzf", "tmux"]   # regenerated whenever the theme changes
+///
+/// [templates]                 # templates and where they are rendered to
+/// "kitty.conf" = "~/.config/kitty/colors.conf"
+///
+/// [hooks]                     # commands run when the theme changes
+/// pre-apply = "..."           # a command, or an array of them
+/// post-apply = ["tmux source-file ~/.config/themux/generated/tmux.conf"]
+/// timeout = 10                # seconds before a hook is stopped
+///
+/// [reload]                    # how the Termux settings are reloaded
+/// command = "termux-reload-settings"
+/// timeout = 10                # seconds before reloading is given up
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    /// The targets regenerated whenever the theme changes.
+    pub generate: Vec<Target>,
+    /// The names of templates in the template directory, and the paths they
+    /// are rendered to whenever the theme changes.
+    pub templates: Vec<(String, PathBuf)>,
+    /// The commands run before and after the theme changes.
+    pub hooks: Hooks,
+    /// How the Termux settings are reloaded.
+    pub reload: Reload,
+}
+
+impl Config {
+    /// Parses a configuration file.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let doc = Document::parse(content)?;
+
+        doc.check_tables(&["generate", "templates", "hooks", "reload"])?;
+        doc.root().check_keys(&[])?;
+
+        let mut config = Self::default();
+
+        if let Some(table) = doc.table("generate") {
+            table.check_keys(&["targets"])?;
+
+            if let Some(entry) = table.get("targets") {
+                config.generate = entry
+                    .as_strings()?
+                    .into_iter()
+                    .map(|name| target(entry, name))
+                    .collect::<Result<_, _>>()?;
+            }
+        }
+
+        if let Some(table) = doc.table("templates") {
+            config.templates = table
+                .entries
+                .iter()
+                .map(|entry| Ok((entry.key.clone(), Paths::get().expand(entry.as_str()?))))
+                .collect::<Result<_, String>>()?;
+        }
+
+        if let Some(table) = doc.table("hooks") {
+            table.check_keys(&["pre-apply", "post-apply", "timeout"])?;
+
+            if let Some(entry) = table.get("pre-apply") {
+                config.hooks.pre_apply = commands(entry)?;
+            }
+
+            if let Some(entry) = table.get("post-apply") {
+                config.hooks.post_apply = commands(entry)?;
+            }
+
+            if let Some(entry) = table.get("timeout") {
+                config.hooks.timeout = timeout(entry)?;
+            }
+        }
+
+        if let Some(table) = doc.table("reload") {
+            table.check_keys(&["command", "timeout"])?;
+
+            if let Some(entry) = table.get("command") {
+                config.reload.command = Some(entry.as_str()?.to_string());
+            }
+
+            if let Some(entry) = table.get("timeout") {
+                config.reload.timeout = timeout(entry)?;
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the configuration file. A missing file is the same as an empty
+    /// one.
+    pub fn load() -> Result<Self, String> {
+        let path = Paths::get().config_file();
+
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+        }
+    }
+}
+
+// Looks up a target named in a configuration entry.
+fn target(entry: &Entry, name: &str) -> Result<Target, String> {
+    Target::from_name(name).ok_or_else(|| {
+        let mut msg = format!("unknown target \"{name}\"");
+
+        if let Some(similar) = suggest(name, Target::names()) {
+            let _ = write!(msg, "; did you mean \"{similar}\"?");
+        }
+
+        entry.error(&msg)
+    })
+}
+
+// Reads a hook entry, which is a command or an array of them.
+fn commands(entry: &Entry) -> Result<Vec<String>, String> {
+    match &entry.value {
+        Value::String(cmd) => Ok(vec![cmd.clone()]),
+        _ => Ok(entry.as_strings()?.into_iter().map(String::from).collect()),
+    }
+}
+
+// Reads a timeout, which is a positive number of seconds.
+fn timeout(entry: &Entry) -> Result<Duration, String> {
+    u64::try_from(entry.as_integer()?)
+        .ok()
+        .filter(|&secs| secs > 0)
+        .map(Duration::from_secs)
+        .ok_or_else(|| {
+            entry.error(&format!(
+                "`{}` must be a positive number of seconds",
+                entry.key
+            ))
+        })
+}
@@ -1,2130 +1,8 @@
-/// All built-in themes.
-pub static ALL_THEMES: [(&str, [u8; 57]); 247] = [
-    (
-        "3024 Day",
-        [
-            74, 69, 67, 74, 69, 67, 247, 247, 247, 9, 3, 0, 219, 45, 32, 1, 162, 82, 253, 237, 2,
-            1, 160, 228, 161, 106, 148, 181, 228, 244, 165, 162, 162, 92, 88, 85, 232, 187, 208,
-            58, 52, 50, 74, 69, 67, 128, 125, 124, 214, 213, 212, 205, 171, 83, 247, 247, 247,
-        ],
-    ),
-    (
-        "3024 Night",
-        [
-            165, 162, 162, 165, 162, 162, 9, 3, 0, 9, 3, 0, 219, 45, 32, 1, 162, 82, 253, 237, 2,
-            1, 160, 228, 161, 106, 148, 181, 228, 244, 165, 162, 162, 92, 88, 85, 232, 187, 208,
-            58, 52, 50, 74, 69, 67, 128, 125, 124, 214, 213, 212, 205, 171, 83, 247, 247, 247,
-        ],
-    ),
-    (
-        "Aci",
-        [
-            180, 225, 253, 180, 225, 253, 13, 25, 38, 54, 54, 54, 255, 8, 131, 131, 255, 8, 255,
-            131, 8, 8, 131, 255, 131, 8, 255, 8, 255, 131, 182, 182, 182, 66, 66, 66, 255, 30, 142,
-            142, 255, 30, 255, 142, 30, 30, 142, 255, 142, 30, 255, 30, 255, 142, 194, 194, 194,
-        ],
-    ),
-    (
-        "Aco",
-        [
-            180, 225, 253, 180, 225, 253, 31, 19, 5, 63, 63, 63, 255, 8, 131, 131, 255, 8, 255,
-            131, 8, 8, 131, 255, 131, 8, 255, 8, 255, 131, 190, 190, 190, 71, 71, 71, 255, 30, 142,
-            142, 255, 30, 255, 142, 30, 30, 142, 255, 142, 30, 255, 30, 255, 142, 196, 196, 196,
-        ],
-    ),
-    (
-        "Adventure Time",
-        [
-            248, 220, 192, 248, 220, 192, 31, 29, 69, 5, 4, 4, 189, 0, 19, 74, 177, 24, 231, 116,
-            30, 15, 74, 198, 102, 89, 147, 112, 165, 152, 248, 220, 192, 78, 124, 191, 252, 95, 90,
-            158, 255, 110, 239, 193, 26, 25, 151, 198, 155, 89, 83, 200, 250, 244, 246, 245, 251,
-        ],
-    ),
-    (
-        "Afterglow",
-        [
-            208, 208, 208, 208, 208, 208, 34, 34, 34, 21, 21, 21, 165, 60, 35, 123, 146, 70, 211,
-            160, 77, 108, 153, 187, 159, 78, 133, 125, 214, 207, 208, 208, 208, 80, 80, 80, 165,
-            60, 35, 123, 146, 70, 211, 160, 77, 84, 124, 153, 159, 78, 133, 125, 214, 207, 245,
-            245, 245,
-        ],
-    ),
-    (
-        "Alien Blood",
-        [
-            99, 125, 117, 99, 125, 117, 15, 22, 16, 17, 38, 22, 127, 43, 39, 47, 126, 37, 113, 127,
-            36, 47, 106, 127, 71, 88, 127, 50, 127, 119, 100, 125, 117, 60, 72, 18, 224, 128, 9,
-            24, 224, 0, 189, 224, 0, 0, 170, 224, 0, 88, 224, 0, 224, 196, 115, 250, 145,
-        ],
-    ),
-    (
-        "Apprentice",
-        [
-            188, 188, 188, 188, 188, 188, 38, 38, 38, 28, 28, 28, 175, 95, 95, 95, 135, 95, 135,
-            135, 95, 95, 135, 175, 95, 95, 135, 95, 135, 135, 108, 108, 108, 68, 68, 68, 255, 135,
-            0, 135, 175, 135, 255, 255, 175, 143, 175, 215, 135, 135, 175, 95, 175, 175, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Argonaut",
-        [
-            255, 250, 244, 255, 250, 244, 14, 16, 25, 35, 35, 35, 255, 0, 15, 140, 225, 11, 255,
-            185, 0, 0, 141, 248, 109, 67, 166, 0, 216, 235, 255, 255, 255, 68, 68, 68, 255, 39, 64,
-            171, 225, 91, 255, 210, 66, 0, 146, 255, 154, 95, 235, 103, 255, 240, 255, 255, 255,
-        ],
-    ),
-    (
-        "Arthur",
-        [
-            221, 238, 221, 221, 238, 221, 28, 28, 28, 61, 53, 42, 205, 92, 92, 134, 175, 128, 232,
-            174, 91, 100, 149, 237, 222, 184, 135, 176, 196, 222, 187, 170, 153, 85, 68, 68, 204,
-            85, 51, 136, 170, 34, 255, 167, 93, 135, 206, 235, 153, 102, 0, 176, 196, 222, 221,
-            204, 187,
-        ],
-    ),
-    (
-        "Atom",
-        [
-            197, 200, 198, 197, 200, 198, 22, 23, 25, 0, 0, 0, 253, 95, 241, 135, 195, 138, 255,
-            215, 177, 133, 190, 253, 185, 182, 252, 133, 190, 253, 224, 224, 224, 0, 0, 0, 253, 95,
-            241, 148, 250, 54, 245, 255, 168, 150, 203, 254, 185, 182, 252, 133, 190, 253, 224,
-            224, 224,
-        ],
-    ),
-    (
-        "Aura",
-        [
-            237, 236, 238, 237, 236, 238, 21, 20, 27, 17, 15, 24, 255, 103, 103, 97, 255, 202, 255,
-            202, 133, 162, 119, 255, 162, 119, 255, 97, 255, 202, 237, 236, 238, 109, 109, 109,
-            255, 202, 133, 162, 119, 255, 255, 202, 133, 162, 119, 255, 162, 119, 255, 97, 255,
-            202, 237, 236, 238,
-        ],
-    ),
-    (
-        "Ayu Dark",
-        [
-            230, 180, 80, 179, 177, 173, 10, 14, 20, 10, 14, 20, 255, 51, 51, 194, 217, 76, 255,
-            143, 64, 89, 194, 255, 255, 238, 153, 149, 230, 203, 179, 177, 173, 77, 85, 102, 255,
-            51, 51, 194, 217, 76, 255, 143, 64, 89, 194, 255, 255, 238, 153, 149, 230, 203, 179,
-            177, 173,
-        ],
-    ),
-    (
-        "Ayu Light",
-        [
-            255, 153, 64, 87, 95, 102, 250, 250, 250, 87, 95, 102, 245, 24, 24, 134, 179, 0, 242,
-            174, 73, 57, 158, 230, 163, 122, 204, 76, 191, 153, 250, 250, 250, 138, 145, 153, 245,
-            24, 24, 134, 179, 0, 242, 174, 73, 57, 158, 230, 163, 122, 204, 76, 191, 153, 250, 250,
-            250,
-        ],
-    ),
-    (
-        "Ayu Mirage",
-        [
-            255, 204, 102, 203, 204, 198, 31, 36, 48, 31, 36, 48, 255, 51, 51, 186, 230, 126, 255,
-            167, 89, 115, 208, 255, 212, 191, 255, 149, 230, 203, 203, 204, 198, 112, 122, 140,
-            255, 51, 51, 186, 230, 126, 255, 167, 89, 115, 208, 255, 212, 191, 255, 149, 230, 203,
-            203, 204, 198,
-        ],
-    ),
-    (
-        "Azu",
-        [
-            217, 230, 242, 217, 230, 242, 9, 17, 26, 0, 0, 0, 172, 109, 116, 116, 172, 109, 172,
-            164, 109, 109, 116, 172, 164, 109, 172, 109, 172, 164, 230, 230, 230, 38, 38, 38, 214,
-            184, 188, 188, 214, 184, 214, 211, 184, 184, 188, 214, 211, 184, 214, 184, 214, 211,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Belafonte Day",
-        [
-            69, 55, 60, 69, 55, 60, 213, 204, 186, 32, 17, 27, 190, 16, 14, 133, 129, 98, 234, 165,
-            73, 66, 106, 121, 151, 82, 44, 152, 154, 156, 150, 140, 131, 94, 82, 82, 190, 16, 14,
-            133, 129, 98, 234, 165, 73, 66, 106, 121, 151, 82, 44, 152, 154, 156, 213, 204, 186,
-        ],
-    ),
-    (
-        "Belafonte Night",
-        [
-            150, 140, 131, 150, 140, 131, 32, 17, 27, 32, 17, 27, 190, 16, 14, 133, 129, 98, 234,
-            165, 73, 66, 106, 121, 151, 82, 44, 152, 154, 156, 150, 140, 131, 94, 82, 82, 190, 16,
-            14, 133, 129, 98, 234, 165, 73, 66, 106, 121, 151, 82, 44, 152, 154, 156, 213, 204,
-            186,
-        ],
-    ),
-    (
-        "Bim",
-        [
-            169, 190, 216, 169, 190, 216, 1, 40, 73, 44, 36, 35, 245, 87, 160, 169, 238, 85, 245,
-            162, 85, 94, 162, 236, 169, 87, 236, 94, 238, 160, 145, 137, 136, 145, 137, 136, 245,
-            121, 178, 187, 238, 120, 245, 179, 120, 129, 179, 236, 187, 121, 236, 129, 238, 178,
-            245, 238, 236,
-        ],
-    ),
-    (
-        "Birds Of Paradise",
-        [
-            224, 219, 183, 224, 219, 183, 42, 31, 29, 87, 61, 38, 190, 45, 38, 107, 161, 138, 233,
-            157, 42, 90, 134, 173, 172, 128, 166, 116, 166, 173, 224, 219, 183, 155, 108, 74, 232,
-            70, 39, 149, 216, 186, 208, 209, 80, 184, 211, 237, 209, 158, 203, 147, 207, 215, 255,
-            249, 213,
-        ],
-    ),
-    (
-        "Blazer",
-        [
-            217, 230, 242, 217, 230, 242, 13, 25, 38, 0, 0, 0, 184, 122, 122, 122, 184, 122, 184,
-            184, 122, 122, 122, 184, 184, 122, 184, 122, 184, 184, 217, 217, 217, 38, 38, 38, 219,
-            189, 189, 189, 219, 189, 219, 219, 189, 189, 189, 219, 219, 189, 219, 189, 219, 219,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Bluloco Light",
-        [
-            56, 58, 66, 56, 58, 66, 249, 249, 249, 213, 214, 221, 213, 39, 83, 35, 151, 74, 223,
-            99, 28, 39, 95, 228, 130, 63, 241, 39, 97, 141, 0, 0, 0, 228, 229, 237, 255, 100, 128,
-            60, 188, 102, 197, 163, 50, 0, 153, 225, 206, 51, 192, 109, 147, 187, 38, 39, 45,
-        ],
-    ),
-    (
-        "Bluloco Zsh Light",
-        [
-            56, 58, 66, 56, 58, 66, 249, 249, 249, 228, 229, 241, 213, 39, 83, 35, 151, 74, 223,
-            99, 28, 39, 95, 228, 130, 63, 241, 39, 97, 141, 0, 0, 0, 87, 148, 222, 255, 100, 128,
-            60, 188, 102, 197, 163, 50, 0, 153, 225, 206, 51, 192, 109, 147, 187, 38, 39, 45,
-        ],
-    ),
-    (
-        "Borland",
-        [
-            255, 255, 78, 255, 255, 78, 0, 0, 164, 79, 79, 79, 255, 108, 96, 168, 255, 96, 255,
-            255, 182, 150, 203, 254, 255, 115, 253, 198, 197, 254, 238, 238, 238, 124, 124, 124,
-            255, 182, 176, 206, 255, 172, 255, 255, 204, 181, 220, 255, 255, 156, 254, 223, 223,
-            254, 255, 255, 255,
-        ],
-    ),
-    (
-        "Breath",
-        [
-            23, 168, 139, 23, 168, 139, 30, 34, 41, 30, 34, 41, 237, 21, 21, 68, 133, 58, 246, 116,
-            0, 29, 153, 243, 155, 89, 182, 26, 188, 156, 252, 252, 252, 127, 140, 141, 192, 57, 43,
-            85, 166, 73, 253, 188, 75, 61, 174, 233, 142, 68, 173, 22, 160, 133, 255, 255, 255,
-        ],
-    ),
-    (
-        "Breath Darker",
-        [
-            23, 168, 139, 23, 168, 139, 8, 13, 20, 30, 34, 41, 237, 21, 21, 68, 133, 58, 246, 116,
-            0, 29, 153, 243, 155, 89, 182, 26, 188, 156, 252, 252, 252, 127, 140, 141, 192, 57, 43,
-            85, 166, 73, 253, 188, 75, 61, 174, 233, 142, 68, 173, 22, 160, 133, 255, 255, 255,
-        ],
-    ),
-    (
-        "Breath Light",
-        [
-            41, 47, 52, 41, 47, 52, 232, 232, 232, 232, 232, 232, 237, 21, 21, 192, 57, 43, 246,
-            116, 0, 29, 153, 243, 155, 89, 182, 26, 188, 156, 252, 252, 252, 127, 140, 141, 192,
-            57, 43, 85, 166, 73, 253, 188, 75, 61, 174, 233, 142, 68, 173, 22, 160, 133, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Breath Silverfox",
-        [
-            187, 187, 187, 187, 187, 187, 30, 34, 41, 30, 34, 41, 237, 21, 21, 68, 133, 58, 246,
-            116, 0, 29, 153, 243, 155, 89, 182, 26, 188, 156, 252, 252, 252, 127, 140, 141, 192,
-            57, 43, 85, 166, 73, 253, 188, 75, 61, 174, 233, 142, 68, 173, 22, 160, 133, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Breeze",
-        [
-            252, 252, 252, 252, 252, 252, 35, 38, 39, 35, 38, 39, 237, 21, 21, 17, 209, 22, 246,
-            116, 0, 29, 153, 243, 155, 89, 182, 26, 188, 156, 252, 252, 252, 127, 140, 141, 192,
-            57, 43, 28, 220, 154, 253, 188, 75, 61, 174, 233, 142, 68, 173, 22, 160, 133, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Broadcast",
-        [
-            230, 225, 220, 230, 225, 220, 43, 43, 43, 0, 0, 0, 218, 73, 57, 81, 159, 80, 255, 210,
-            74, 109, 156, 190, 208, 208, 255, 110, 156, 190, 255, 255, 255, 50, 50, 50, 255, 123,
-            107, 131, 209, 130, 255, 255, 124, 159, 206, 240, 255, 255, 255, 160, 206, 240, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Brogrammer",
-        [
-            214, 219, 229, 214, 219, 229, 19, 19, 19, 31, 31, 31, 248, 17, 24, 45, 197, 94, 236,
-            186, 15, 42, 132, 210, 78, 90, 183, 16, 129, 214, 214, 219, 229, 214, 219, 229, 222,
-            53, 46, 29, 211, 97, 243, 189, 9, 16, 129, 214, 83, 80, 185, 15, 125, 219, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "C64",
-        [
-            120, 105, 196, 120, 105, 196, 64, 49, 141, 9, 3, 0, 136, 57, 50, 85, 160, 73, 191, 206,
-            114, 64, 49, 141, 139, 63, 150, 103, 182, 189, 255, 255, 255, 0, 0, 0, 136, 57, 50, 85,
-            160, 73, 191, 206, 114, 64, 49, 141, 139, 63, 150, 103, 182, 189, 247, 247, 247,
-        ],
-    ),
-    (
-        "Cai",
-        [
-            217, 230, 242, 217, 230, 242, 9, 17, 26, 0, 0, 0, 202, 39, 77, 77, 202, 39, 202, 164,
-            39, 39, 77, 202, 164, 39, 202, 39, 202, 164, 128, 128, 128, 128, 128, 128, 233, 141,
-            163, 163, 233, 141, 233, 212, 141, 141, 163, 233, 212, 141, 233, 141, 233, 212, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Catppuccin Frappe",
-        [
-            198, 208, 245, 198, 208, 245, 48, 52, 70, 81, 87, 109, 231, 130, 132, 166, 209, 137,
-            229, 200, 144, 140, 170, 238, 244, 184, 228, 129, 200, 190, 181, 191, 226, 98, 104,
-            128, 231, 130, 132, 166, 209, 137, 229, 200, 144, 140, 170, 238, 244, 184, 228, 129,
-            200, 190, 165, 173, 206,
-        ],
-    ),
-    (
-        "Catppuccin Latte",
-        [
-            76, 79, 105, 76, 79, 105, 239, 241, 245, 92, 95, 119, 210, 15, 57, 64, 160, 43, 223,
-            142, 29, 30, 102, 245, 234, 118, 203, 23, 146, 153, 172, 176, 190, 108, 111, 133, 210,
-            15, 57, 64, 160, 43, 223, 142, 29, 30, 102, 245, 234, 118, 203, 23, 146, 153, 188, 192,
-            204,
-        ],
-    ),
-    (
-        "Catppuccin Macchiato",
-        [
-            202, 211, 245, 202, 211, 245, 36, 39, 58, 73, 77, 100, 237, 135, 150, 166, 218, 149,
-            238, 212, 159, 138, 173, 244, 245, 189, 230, 139, 213, 202, 184, 192, 224, 91, 96, 120,
-            237, 135, 150, 166, 218, 149, 238, 212, 159, 138, 173, 244, 245, 189, 230, 139, 213,
-            202, 165, 173, 203,
-        ],
-    ),
-    (
-        "Catppuccin Mocha",
-        [
-            205, 214, 244, 205, 214, 244, 30, 30, 46, 69, 71, 90, 243, 139, 168, 166, 227, 161,
-            249, 226, 175, 137, 180, 250, 245, 194, 231, 148, 226, 213, 186, 194, 222, 88, 91, 112,
-            243, 139, 168, 166, 227, 161, 249, 226, 175, 137, 180, 250, 245, 194, 231, 148, 226,
-            213, 166, 173, 200,
-        ],
-    ),
-    (
-        "Chalk",
-        [
-            212, 212, 212, 212, 212, 212, 45, 45, 45, 100, 100, 100, 245, 142, 142, 169, 211, 171,
-            254, 211, 126, 122, 171, 212, 214, 173, 213, 121, 212, 213, 212, 212, 212, 100, 100,
-            100, 245, 142, 142, 169, 211, 171, 254, 211, 126, 122, 171, 212, 214, 173, 213, 121,
-            212, 213, 212, 212, 212,
-        ],
-    ),
-    (
-        "Chalkboard",
-        [
-            217, 230, 242, 217, 230, 242, 41, 38, 47, 0, 0, 0, 195, 115, 114, 114, 195, 115, 194,
-            195, 114, 115, 114, 195, 195, 114, 194, 114, 194, 195, 217, 217, 217, 50, 50, 50, 219,
-            170, 170, 170, 219, 170, 218, 219, 170, 170, 170, 219, 219, 170, 218, 170, 218, 219,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Chameleon",
-        [
-            222, 222, 222, 222, 222, 222, 44, 44, 44, 44, 44, 44, 204, 35, 28, 104, 157, 105, 215,
-            153, 34, 54, 107, 113, 78, 81, 101, 69, 133, 135, 200, 187, 151, 119, 119, 119, 204,
-            35, 28, 104, 157, 105, 215, 153, 34, 54, 107, 113, 78, 81, 101, 69, 133, 135, 200, 187,
-            151,
-        ],
-    ),
-    (
-        "Ciapre",
-        [
-            174, 164, 122, 174, 164, 122, 25, 28, 39, 24, 24, 24, 129, 0, 9, 72, 81, 59, 204, 139,
-            63, 87, 109, 140, 114, 77, 124, 92, 79, 75, 174, 164, 127, 85, 85, 85, 172, 56, 53,
-            166, 167, 93, 220, 223, 124, 48, 151, 198, 211, 48, 97, 243, 219, 178, 244, 244, 244,
-        ],
-    ),
-    (
-        "Clone Of Ubuntu",
-        [
-            255, 255, 255, 255, 255, 255, 48, 10, 36, 46, 52, 54, 204, 0, 0, 78, 154, 6, 196, 160,
-            0, 52, 101, 164, 117, 80, 123, 6, 152, 154, 211, 215, 207, 85, 87, 83, 239, 41, 41,
-            138, 226, 52, 252, 233, 79, 114, 159, 207, 173, 127, 168, 52, 226, 226, 238, 238, 236,
-        ],
-    ),
-    (
-        "Clrs",
-        [
-            38, 38, 38, 38, 38, 38, 255, 255, 255, 0, 0, 0, 248, 40, 42, 50, 138, 93, 250, 112, 29,
-            19, 92, 208, 159, 0, 189, 51, 195, 193, 179, 179, 179, 85, 87, 83, 251, 4, 22, 44, 198,
-            49, 253, 215, 39, 22, 112, 255, 233, 0, 176, 58, 213, 206, 238, 238, 236,
-        ],
-    ),
-    (
-        "Cobalt 2",
-        [
-            255, 255, 255, 255, 255, 255, 19, 39, 56, 0, 0, 0, 255, 0, 0, 56, 222, 33, 255, 229,
-            10, 20, 96, 210, 255, 0, 93, 0, 187, 187, 187, 187, 187, 85, 85, 85, 244, 14, 23, 59,
-            208, 29, 237, 200, 9, 85, 85, 255, 255, 85, 255, 106, 227, 250, 255, 255, 255,
-        ],
-    ),
-    (
-        "Cobalt Neon",
-        [
-            143, 245, 134, 143, 245, 134, 20, 40, 56, 20, 38, 49, 255, 35, 32, 59, 165, 255, 233,
-            231, 92, 143, 245, 134, 120, 26, 160, 143, 245, 134, 186, 70, 178, 255, 246, 136, 212,
-            49, 46, 143, 245, 134, 233, 240, 109, 60, 125, 210, 130, 48, 167, 108, 188, 103, 143,
-            245, 134,
-        ],
-    ),
-    (
-        "Colorcli",
-        [
-            0, 95, 135, 0, 95, 135, 255, 255, 255, 0, 0, 0, 215, 0, 0, 95, 175, 0, 95, 175, 0, 0,
-            95, 135, 215, 0, 0, 95, 95, 95, 228, 228, 228, 95, 95, 95, 215, 0, 0, 95, 95, 95, 255,
-            255, 0, 0, 135, 175, 0, 135, 175, 0, 135, 175, 255, 255, 255,
-        ],
-    ),
-    (
-        "Crayon Pony Fish",
-        [
-            104, 82, 90, 104, 82, 90, 21, 7, 7, 43, 27, 29, 145, 0, 43, 87, 149, 36, 171, 49, 27,
-            140, 135, 176, 105, 47, 80, 232, 168, 102, 104, 82, 90, 61, 43, 46, 197, 37, 93, 141,
-            255, 87, 200, 56, 29, 207, 201, 255, 252, 108, 186, 255, 206, 175, 176, 148, 157,
-        ],
-    ),
-    (
-        "Dark Pastel",
-        [
-            255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 85, 85, 85, 255, 85, 255, 255, 85,
-            85, 85, 255, 255, 85, 255, 85, 255, 255, 187, 187, 187, 85, 85, 85, 255, 85, 85, 85,
-            255, 85, 255, 255, 85, 85, 85, 255, 255, 85, 255, 85, 255, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Darkside",
-        [
-            186, 186, 186, 186, 186, 186, 34, 35, 36, 0, 0, 0, 232, 52, 28, 104, 194, 86, 242, 212,
-            44, 28, 152, 232, 142, 105, 201, 28, 152, 232, 186, 186, 186, 0, 0, 0, 224, 90, 79,
-            119, 184, 105, 239, 214, 75, 56, 124, 211, 149, 123, 190, 61, 151, 226, 186, 186, 186,
-        ],
-    ),
-    (
-        "Dehydration",
-        [
-            204, 204, 204, 204, 204, 204, 51, 51, 51, 51, 51, 51, 255, 85, 85, 95, 211, 141, 255,
-            153, 85, 55, 113, 200, 188, 95, 211, 95, 211, 188, 153, 153, 153, 102, 102, 102, 255,
-            128, 128, 135, 222, 170, 255, 179, 128, 95, 141, 211, 205, 135, 222, 135, 222, 205,
-            204, 204, 204,
-        ],
-    ),
-    (
-        "Desert",
-        [
-            255, 255, 255, 255, 255, 255, 51, 51, 51, 77, 77, 77, 255, 43, 43, 152, 251, 152, 240,
-            230, 140, 205, 133, 63, 255, 222, 173, 255, 160, 160, 245, 222, 179, 85, 85, 85, 255,
-            85, 85, 85, 255, 85, 255, 255, 85, 135, 206, 255, 255, 85, 255, 255, 215, 0, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Dimmed Monokai",
-        [
-            185, 188, 186, 185, 188, 186, 31, 31, 31, 58, 61, 67, 190, 63, 72, 135, 154, 59, 197,
-            166, 53, 79, 118, 161, 133, 92, 141, 87, 143, 164, 185, 188, 186, 136, 137, 135, 251,
-            0, 31, 15, 114, 47, 196, 112, 51, 24, 109, 227, 251, 0, 103, 46, 112, 109, 253, 255,
-            185,
-        ],
-    ),
-    (
-        "Dissonance",
-        [
-            220, 50, 47, 255, 255, 255, 0, 0, 0, 0, 0, 0, 220, 50, 47, 86, 219, 58, 255, 132, 0, 0,
-            132, 212, 183, 41, 217, 204, 204, 255, 255, 255, 255, 214, 219, 229, 220, 50, 47, 86,
-            219, 58, 255, 132, 0, 0, 132, 212, 183, 41, 217, 204, 204, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Dracula",
-        [
-            248, 248, 242, 248, 248, 242, 40, 42, 54, 68, 71, 90, 255, 85, 85, 80, 250, 123, 255,
-            184, 108, 139, 233, 253, 189, 147, 249, 255, 121, 198, 248, 248, 242, 0, 0, 0, 255, 85,
-            85, 80, 250, 123, 255, 184, 108, 139, 233, 253, 189, 147, 249, 255, 121, 198, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Earthsong",
-        [
-            229, 199, 169, 229, 199, 169, 41, 37, 32, 18, 20, 24, 201, 66, 52, 133, 197, 76, 245,
-            174, 46, 19, 152, 185, 208, 99, 61, 80, 149, 82, 229, 198, 170, 103, 95, 84, 255, 100,
-            90, 152, 224, 54, 224, 213, 97, 95, 218, 255, 255, 146, 105, 132, 240, 136, 246, 247,
-            236,
-        ],
-    ),
-    (
-        "Elemental",
-        [
-            128, 122, 116, 128, 122, 116, 34, 33, 29, 60, 60, 48, 152, 41, 15, 71, 154, 67, 127,
-            113, 17, 73, 127, 125, 127, 78, 47, 56, 127, 88, 128, 121, 116, 85, 84, 69, 224, 80,
-            42, 97, 224, 112, 214, 153, 39, 121, 217, 217, 205, 124, 84, 89, 213, 153, 255, 241,
-            233,
-        ],
-    ),
-    (
-        "Elementary",
-        [
-            242, 242, 242, 242, 242, 242, 16, 16, 16, 48, 48, 48, 225, 50, 26, 106, 176, 23, 255,
-            192, 5, 0, 79, 158, 236, 0, 72, 42, 167, 231, 242, 242, 242, 93, 93, 93, 255, 54, 30,
-            123, 201, 31, 255, 208, 10, 0, 113, 255, 255, 29, 98, 75, 184, 253, 160, 32, 240,
-        ],
-    ),
-    (
-        "Elic",
-        [
-            242, 242, 242, 242, 242, 242, 74, 69, 62, 48, 48, 48, 225, 50, 26, 106, 176, 23, 255,
-            192, 5, 114, 159, 207, 236, 0, 72, 242, 242, 242, 42, 167, 231, 93, 93, 93, 255, 54,
-            30, 123, 201, 31, 255, 208, 10, 0, 113, 255, 255, 29, 98, 75, 184, 253, 160, 32, 240,
-        ],
-    ),
-    (
-        "Elio",
-        [
-            242, 242, 242, 242, 242, 242, 4, 26, 59, 48, 48, 48, 225, 50, 26, 106, 176, 23, 255,
-            192, 5, 114, 159, 207, 236, 0, 72, 42, 167, 231, 242, 242, 242, 93, 93, 93, 255, 54,
-            30, 123, 201, 31, 255, 208, 10, 0, 113, 255, 255, 29, 98, 75, 184, 253, 160, 32, 240,
-        ],
-    ),
-    (
-        "Espresso",
-        [
-            255, 255, 255, 255, 255, 255, 50, 50, 50, 53, 53, 53, 210, 82, 82, 165, 194, 97, 255,
-            198, 109, 108, 153, 187, 209, 151, 217, 190, 214, 255, 238, 238, 236, 83, 83, 83, 240,
-            12, 12, 194, 224, 117, 225, 228, 139, 138, 183, 217, 239, 181, 247, 220, 244, 255, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Espresso Libre",
-        [
-            184, 168, 152, 184, 168, 152, 42, 33, 28, 0, 0, 0, 204, 0, 0, 26, 146, 28, 240, 229,
-            58, 0, 102, 255, 197, 101, 107, 6, 152, 154, 211, 215, 207, 85, 87, 83, 239, 41, 41,
-            154, 255, 135, 255, 251, 92, 67, 168, 237, 255, 129, 138, 52, 226, 226, 238, 238, 236,
-        ],
-    ),
-    (
-        "Everblush",
-        [
-            218, 218, 218, 218, 218, 218, 20, 27, 30, 35, 42, 45, 229, 116, 116, 140, 207, 126,
-            229, 199, 107, 103, 176, 232, 196, 127, 213, 108, 191, 191, 179, 185, 184, 45, 52, 55,
-            239, 126, 126, 150, 217, 136, 244, 214, 122, 113, 186, 242, 206, 137, 223, 103, 203,
-            231, 189, 195, 194,
-        ],
-    ),
-    (
-        "Everforest Dark",
-        [
-            211, 198, 170, 211, 198, 170, 45, 53, 59, 75, 86, 92, 230, 126, 128, 167, 192, 128,
-            219, 188, 127, 127, 187, 179, 214, 153, 182, 131, 192, 146, 211, 198, 170, 92, 106,
-            114, 248, 85, 82, 141, 161, 1, 223, 160, 0, 58, 148, 197, 223, 105, 186, 53, 167, 124,
-            223, 221, 200,
-        ],
-    ),
-    (
-        "Everforest Light",
-        [
-            92, 106, 114, 92, 106, 114, 253, 246, 227, 92, 106, 114, 248, 85, 82, 141, 161, 1, 223,
-            160, 0, 58, 148, 197, 223, 105, 186, 53, 167, 124, 223, 221, 200, 75, 86, 92, 230, 126,
-            128, 167, 192, 128, 219, 188, 127, 127, 187, 179, 214, 153, 182, 131, 192, 146, 211,
-            198, 170,
-        ],
-    ),
-    (
-        "Fairy Floss",
-        [
-            255, 184, 209, 194, 255, 223, 90, 84, 117, 66, 57, 93, 168, 117, 123, 255, 133, 127,
-            230, 192, 0, 174, 129, 255, 113, 103, 153, 194, 255, 223, 248, 248, 242, 117, 80, 123,
-            255, 184, 209, 241, 86, 142, 213, 164, 37, 197, 163, 255, 128, 119, 168, 194, 255, 255,
-            248, 248, 240,
-        ],
-    ),
-    (
-        "Fairy Floss Dark",
-        [
-            255, 184, 209, 194, 255, 223, 66, 57, 93, 66, 57, 93, 168, 117, 123, 255, 133, 127,
-            230, 192, 0, 174, 129, 255, 113, 103, 153, 194, 255, 223, 248, 248, 242, 117, 80, 123,
-            255, 184, 209, 241, 86, 142, 213, 164, 37, 197, 163, 255, 128, 119, 168, 194, 255, 255,
-            248, 248, 240,
-        ],
-    ),
-    (
-        "Fishtank",
-        [
-            236, 240, 254, 236, 240, 254, 35, 37, 55, 3, 7, 60, 198, 0, 74, 172, 241, 87, 254, 205,
-            94, 82, 95, 184, 152, 111, 130, 150, 135, 99, 236, 240, 252, 108, 91, 48, 218, 75, 138,
-            219, 255, 169, 254, 230, 169, 178, 190, 250, 253, 165, 205, 165, 189, 134, 246, 255,
-            236,
-        ],
-    ),
-    (
-        "Flat",
-        [
-            26, 188, 156, 26, 188, 156, 31, 45, 58, 44, 62, 80, 192, 57, 43, 39, 174, 96, 243, 156,
-            18, 41, 128, 185, 142, 68, 173, 22, 160, 133, 189, 195, 199, 52, 73, 94, 231, 76, 60,
-            46, 204, 113, 241, 196, 15, 52, 152, 219, 155, 89, 182, 42, 161, 152, 236, 240, 241,
-        ],
-    ),
-    (
-        "Flat Remix",
-        [
-            255, 255, 255, 255, 255, 255, 39, 42, 52, 31, 34, 41, 212, 25, 25, 94, 189, 171, 254,
-            164, 76, 54, 123, 240, 191, 46, 93, 73, 174, 230, 230, 230, 230, 140, 66, 171, 236, 1,
-            1, 71, 212, 185, 255, 138, 24, 39, 127, 255, 215, 22, 85, 5, 161, 247, 255, 255, 255,
-        ],
-    ),
-    (
-        "Flatland",
-        [
-            184, 219, 239, 184, 219, 239, 29, 31, 33, 29, 29, 25, 241, 131, 57, 159, 211, 100, 244,
-            239, 109, 80, 150, 190, 105, 90, 188, 214, 56, 101, 255, 255, 255, 29, 29, 25, 210, 42,
-            36, 167, 212, 44, 255, 137, 73, 97, 185, 208, 105, 90, 188, 214, 56, 101, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Foxnightly",
-        [
-            215, 215, 219, 215, 215, 219, 42, 42, 46, 42, 42, 46, 185, 142, 255, 255, 125, 233,
-            114, 159, 207, 102, 160, 91, 117, 80, 123, 172, 172, 174, 255, 255, 255, 164, 0, 0,
-            191, 64, 64, 102, 160, 91, 255, 184, 108, 114, 159, 207, 143, 89, 2, 196, 160, 0, 92,
-            53, 102,
-        ],
-    ),
-    (
-        "Freya",
-        [
-            131, 148, 150, 148, 163, 165, 37, 46, 50, 7, 54, 66, 220, 50, 47, 133, 153, 0, 181,
-            137, 0, 38, 139, 210, 236, 0, 72, 42, 161, 152, 148, 163, 165, 88, 110, 117, 203, 75,
-            22, 133, 153, 0, 181, 137, 0, 38, 139, 210, 211, 54, 130, 42, 161, 152, 108, 113, 196,
-        ],
-    ),
-    (
-        "Frontend Delight",
-        [
-            173, 173, 173, 173, 173, 173, 27, 28, 29, 36, 37, 38, 248, 81, 27, 86, 87, 71, 250,
-            119, 29, 44, 112, 183, 240, 46, 79, 60, 161, 166, 173, 173, 173, 95, 172, 109, 247, 67,
-            25, 116, 236, 76, 253, 195, 37, 51, 147, 202, 231, 94, 79, 79, 188, 230, 140, 115, 91,
-        ],
-    ),
-    (
-        "Frontend Fun Forrest",
-        [
-            222, 193, 101, 222, 193, 101, 37, 18, 0, 0, 0, 0, 214, 38, 43, 145, 156, 0, 190, 138,
-            19, 70, 153, 163, 141, 67, 49, 218, 130, 19, 221, 194, 101, 127, 106, 85, 229, 90, 28,
-            191, 198, 90, 255, 203, 27, 124, 201, 207, 210, 99, 73, 230, 169, 107, 255, 234, 163,
-        ],
-    ),
-    (
-        "Frontend Galaxy",
-        [
-            255, 255, 255, 255, 255, 255, 29, 40, 55, 0, 0, 0, 249, 85, 95, 33, 176, 137, 254, 240,
-            42, 88, 157, 246, 148, 77, 149, 31, 158, 231, 187, 187, 187, 85, 85, 85, 250, 140, 143,
-            53, 187, 154, 255, 255, 85, 88, 157, 246, 231, 86, 153, 57, 121, 188, 255, 255, 255,
-        ],
-    ),
-    (
-        "Geohot",
-        [
-            255, 255, 255, 255, 255, 255, 31, 30, 31, 249, 245, 245, 204, 0, 0, 31, 30, 31, 173,
-            161, 16, 255, 0, 78, 117, 80, 123, 6, 145, 154, 255, 255, 255, 85, 87, 83, 239, 41, 41,
-            255, 0, 0, 173, 161, 16, 95, 74, 166, 183, 68, 56, 64, 143, 12, 255, 255, 255,
-        ],
-    ),
-    (
-        "Github",
-        [
-            62, 62, 62, 62, 62, 62, 244, 244, 244, 62, 62, 62, 151, 11, 22, 7, 150, 42, 248, 238,
-            199, 0, 62, 138, 233, 70, 145, 137, 209, 236, 255, 255, 255, 102, 102, 102, 222, 0, 0,
-            135, 213, 162, 241, 208, 7, 46, 108, 186, 255, 162, 159, 28, 250, 254, 255, 255, 255,
-        ],
-    ),
-    (
-        "Gogh",
-        [
-            191, 199, 213, 191, 199, 213, 41, 45, 62, 41, 45, 62, 240, 113, 120, 98, 222, 132, 255,
-            203, 107, 117, 161, 255, 245, 128, 255, 96, 186, 236, 171, 178, 191, 149, 157, 203,
-            240, 113, 120, 195, 232, 141, 255, 85, 114, 130, 170, 255, 255, 203, 107, 103, 110,
-            149, 255, 254, 254,
-        ],
-    ),
-    (
-        "Gooey",
-        [
-            235, 238, 249, 235, 238, 249, 13, 16, 27, 0, 0, 9, 187, 79, 108, 114, 204, 174, 198,
-            94, 61, 88, 182, 202, 100, 136, 196, 141, 132, 198, 133, 136, 147, 31, 34, 45, 238,
-            130, 159, 165, 255, 225, 249, 145, 112, 139, 233, 253, 151, 187, 247, 192, 183, 249,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Google Dark",
-        [
-            232, 234, 237, 232, 234, 237, 32, 33, 36, 32, 33, 36, 234, 67, 53, 52, 168, 83, 251,
-            188, 4, 66, 133, 244, 161, 66, 244, 36, 193, 224, 232, 234, 237, 95, 99, 104, 234, 67,
-            53, 52, 168, 83, 251, 188, 5, 66, 133, 244, 161, 66, 244, 36, 193, 224, 255, 255, 255,
-        ],
-    ),
-    (
-        "Google Light",
-        [
-            95, 99, 104, 95, 99, 104, 255, 255, 255, 32, 33, 36, 234, 67, 53, 52, 168, 83, 251,
-            188, 4, 66, 133, 244, 161, 66, 244, 36, 193, 224, 232, 234, 237, 95, 99, 104, 234, 67,
-            53, 52, 168, 83, 251, 188, 5, 66, 133, 244, 161, 66, 244, 36, 193, 224, 255, 255, 255,
-        ],
-    ),
-    (
-        "Gotham",
-        [
-            152, 209, 206, 152, 209, 206, 10, 15, 20, 10, 15, 20, 195, 48, 39, 38, 169, 139, 237,
-            181, 75, 25, 84, 101, 78, 81, 101, 51, 133, 157, 152, 209, 206, 16, 21, 27, 210, 105,
-            57, 8, 31, 45, 36, 83, 97, 9, 55, 72, 136, 139, 165, 89, 156, 170, 211, 235, 233,
-        ],
-    ),
-    (
-        "Grape",
-        [
-            159, 159, 161, 159, 159, 161, 23, 20, 35, 45, 40, 63, 237, 34, 97, 31, 169, 27, 141,
-            220, 32, 72, 125, 244, 141, 53, 201, 59, 222, 237, 158, 158, 160, 89, 81, 106, 240,
-            114, 154, 83, 170, 94, 178, 220, 135, 169, 188, 236, 173, 129, 194, 157, 227, 235, 162,
-            136, 247,
-        ],
-    ),
-    (
-        "Grass",
-        [
-            255, 240, 165, 255, 240, 165, 19, 119, 61, 0, 0, 0, 187, 0, 0, 0, 187, 0, 231, 176, 0,
-            0, 0, 163, 149, 0, 98, 0, 187, 187, 187, 187, 187, 85, 85, 85, 187, 0, 0, 0, 187, 0,
-            231, 176, 0, 0, 0, 187, 255, 85, 255, 85, 255, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Gruvbox",
-        [
-            60, 56, 54, 60, 56, 54, 251, 241, 199, 251, 241, 199, 204, 36, 29, 152, 151, 26, 215,
-            153, 33, 69, 133, 136, 177, 98, 134, 104, 157, 106, 124, 111, 100, 146, 131, 116, 157,
-            0, 6, 121, 116, 14, 181, 118, 20, 7, 102, 120, 143, 63, 113, 66, 123, 88, 60, 56, 54,
-        ],
-    ),
-    (
-        "Gruvbox Dark",
-        [
-            235, 219, 178, 235, 219, 178, 40, 40, 40, 40, 40, 40, 204, 36, 29, 152, 151, 26, 215,
-            153, 33, 69, 133, 136, 177, 98, 134, 104, 157, 106, 168, 153, 132, 146, 131, 116, 251,
-            73, 52, 184, 187, 38, 250, 189, 47, 131, 165, 152, 211, 134, 155, 142, 192, 124, 235,
-            219, 178,
-        ],
-    ),
-    (
-        "Gruvbox Material",
-        [
-            212, 190, 152, 212, 190, 152, 40, 40, 40, 60, 56, 54, 234, 105, 98, 169, 182, 101, 216,
-            166, 87, 125, 174, 163, 211, 134, 155, 137, 180, 130, 212, 190, 152, 60, 56, 54, 234,
-            105, 98, 169, 182, 101, 216, 166, 87, 125, 174, 163, 211, 134, 155, 137, 180, 130, 212,
-            190, 152,
-        ],
-    ),
-    (
-        "Hardcore",
-        [
-            160, 160, 160, 160, 160, 160, 18, 18, 18, 27, 29, 30, 249, 38, 114, 166, 226, 46, 253,
-            151, 31, 102, 217, 239, 158, 111, 254, 94, 113, 117, 204, 204, 198, 80, 83, 84, 255,
-            102, 157, 190, 237, 95, 230, 219, 116, 102, 217, 239, 158, 111, 254, 163, 186, 191,
-            248, 248, 242,
-        ],
-    ),
-    (
-        "Harper",
-        [
-            168, 164, 157, 168, 164, 157, 1, 1, 1, 1, 1, 1, 248, 182, 63, 127, 181, 225, 214, 218,
-            37, 72, 158, 72, 178, 150, 198, 245, 191, 215, 168, 164, 157, 114, 110, 106, 248, 182,
-            63, 127, 181, 225, 214, 218, 37, 72, 158, 72, 178, 150, 198, 245, 191, 215, 254, 251,
-            234,
-        ],
-    ),
-    (
-        "Hemisu Dark",
-        [
-            186, 255, 170, 255, 255, 255, 0, 0, 0, 68, 68, 68, 255, 0, 84, 177, 214, 48, 157, 137,
-            94, 103, 190, 227, 181, 118, 188, 86, 154, 159, 237, 237, 237, 119, 119, 119, 214, 94,
-            117, 186, 255, 170, 236, 225, 200, 159, 211, 229, 222, 179, 223, 182, 224, 229, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Hemisu Light",
-        [
-            255, 0, 84, 68, 68, 68, 239, 239, 239, 119, 119, 119, 255, 0, 85, 115, 145, 0, 80, 61,
-            21, 83, 128, 145, 91, 52, 94, 83, 128, 145, 153, 153, 153, 153, 153, 153, 214, 94, 118,
-            156, 199, 0, 148, 117, 85, 157, 179, 205, 161, 132, 164, 133, 178, 170, 186, 186, 186,
-        ],
-    ),
-    (
-        "Highway",
-        [
-            237, 237, 237, 237, 237, 237, 34, 34, 37, 0, 0, 0, 208, 14, 24, 19, 128, 52, 255, 203,
-            62, 0, 107, 179, 107, 39, 117, 56, 69, 100, 237, 237, 237, 93, 80, 74, 240, 126, 24,
-            177, 209, 48, 255, 241, 32, 79, 194, 253, 222, 0, 113, 93, 80, 74, 255, 255, 255,
-        ],
-    ),
-    (
-        "Hipster Green",
-        [
-            132, 193, 56, 132, 193, 56, 16, 11, 5, 0, 0, 0, 182, 33, 74, 0, 166, 0, 191, 191, 0,
-            36, 110, 178, 178, 0, 178, 0, 166, 178, 191, 191, 191, 102, 102, 102, 229, 0, 0, 134,
-            169, 62, 229, 229, 0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Homebrew",
-        [
-            0, 255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 166, 0, 153, 153, 0, 0, 0, 178,
-            178, 0, 178, 0, 166, 178, 191, 191, 191, 102, 102, 102, 229, 0, 0, 0, 217, 0, 229, 229,
-            0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Homebrew Light",
-        [
-            0, 0, 0, 0, 0, 0, 255, 255, 255, 0, 0, 0, 153, 0, 0, 0, 166, 0, 153, 153, 0, 0, 0, 178,
-            178, 0, 178, 0, 166, 178, 191, 191, 191, 102, 102, 102, 229, 0, 0, 0, 217, 0, 229, 229,
-            0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Homebrew Ocean",
-        [
-            255, 255, 255, 255, 255, 255, 34, 79, 188, 0, 0, 0, 153, 0, 0, 0, 166, 0, 153, 153, 0,
-            0, 0, 178, 178, 0, 178, 0, 166, 178, 191, 191, 191, 102, 102, 102, 229, 0, 0, 0, 217,
-            0, 229, 229, 0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Horizon Bright",
-        [
-            28, 30, 38, 28, 30, 38, 253, 240, 237, 22, 22, 28, 218, 16, 63, 30, 185, 128, 246, 102,
-            30, 38, 187, 217, 238, 100, 174, 29, 137, 145, 250, 218, 209, 26, 28, 35, 244, 62, 92,
-            7, 218, 140, 247, 125, 38, 63, 198, 222, 240, 117, 183, 30, 174, 174, 253, 240, 237,
-        ],
-    ),
-    (
-        "Horizon Dark",
-        [
-            253, 240, 237, 253, 240, 237, 28, 30, 38, 22, 22, 28, 233, 86, 120, 41, 211, 152, 250,
-            183, 149, 38, 187, 217, 238, 100, 174, 89, 227, 227, 250, 218, 209, 35, 37, 48, 236,
-            106, 136, 63, 218, 164, 251, 195, 167, 63, 198, 222, 240, 117, 183, 107, 230, 230, 253,
-            240, 237,
-        ],
-    ),
-    (
-        "Hurtado",
-        [
-            219, 219, 219, 219, 219, 219, 0, 0, 0, 87, 87, 87, 255, 27, 0, 165, 224, 85, 251, 231,
-            74, 73, 100, 135, 253, 95, 241, 134, 233, 254, 203, 204, 203, 38, 38, 38, 213, 29, 0,
-            165, 223, 85, 251, 232, 74, 137, 190, 255, 192, 1, 193, 134, 234, 254, 219, 219, 219,
-        ],
-    ),
-    (
-        "Hybrid",
-        [
-            148, 163, 165, 148, 163, 165, 20, 20, 20, 40, 42, 46, 165, 66, 66, 140, 148, 64, 222,
-            147, 95, 95, 129, 157, 133, 103, 143, 94, 141, 135, 150, 152, 150, 55, 59, 65, 204,
-            102, 102, 181, 189, 104, 240, 198, 116, 129, 162, 190, 178, 148, 187, 138, 190, 183,
-            197, 200, 198,
-        ],
-    ),
-    (
-        "Ibm 3270",
-        [
-            253, 253, 253, 253, 253, 253, 0, 0, 0, 34, 34, 34, 240, 24, 24, 36, 216, 48, 240, 216,
-            36, 120, 144, 240, 240, 120, 216, 84, 228, 228, 165, 165, 165, 136, 136, 136, 239, 131,
-            131, 126, 214, 132, 239, 226, 139, 179, 191, 239, 239, 179, 227, 156, 226, 226, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Ibm 3270 High Contrast",
-        [
-            253, 253, 253, 253, 253, 253, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 255, 255, 0, 0,
-            191, 255, 255, 192, 203, 64, 224, 208, 190, 190, 190, 65, 65, 65, 255, 165, 0, 152,
-            251, 152, 255, 255, 0, 0, 0, 205, 160, 32, 240, 174, 238, 238, 255, 255, 255,
-        ],
-    ),
-    (
-        "Ic Green Ppl",
-        [
-            217, 239, 211, 217, 239, 211, 58, 61, 63, 31, 31, 31, 251, 0, 42, 51, 156, 36, 101,
-            155, 37, 20, 155, 69, 83, 184, 44, 44, 184, 104, 224, 255, 239, 3, 39, 16, 167, 255,
-            63, 159, 255, 109, 210, 255, 109, 114, 255, 181, 80, 255, 62, 34, 255, 113, 218, 239,
-            208,
-        ],
-    ),
-    (
-        "Ic Orange Ppl",
-        [
-            255, 203, 131, 255, 203, 131, 38, 38, 38, 0, 0, 0, 193, 57, 0, 164, 169, 0, 202, 175,
-            0, 189, 109, 0, 252, 94, 0, 247, 149, 0, 255, 200, 138, 106, 79, 42, 255, 140, 104,
-            246, 255, 64, 255, 227, 110, 255, 190, 85, 252, 135, 79, 198, 151, 82, 250, 250, 255,
-        ],
-    ),
-    (
-        "Idle Toes",
-        [
-            255, 255, 255, 255, 255, 255, 50, 50, 50, 50, 50, 50, 210, 82, 82, 127, 225, 115, 255,
-            198, 109, 64, 153, 255, 246, 128, 255, 190, 214, 255, 238, 238, 236, 83, 83, 83, 240,
-            112, 112, 157, 255, 145, 255, 228, 139, 94, 183, 247, 255, 157, 255, 220, 244, 255,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Ir Black",
-        [
-            255, 165, 96, 238, 238, 238, 0, 0, 0, 78, 78, 78, 255, 108, 96, 168, 255, 96, 255, 255,
-            182, 105, 203, 254, 255, 115, 253, 198, 197, 254, 238, 238, 238, 124, 124, 124, 255,
-            182, 176, 206, 255, 172, 255, 255, 203, 181, 220, 254, 255, 156, 254, 223, 223, 254,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Jackie Brown",
-        [
-            255, 204, 47, 255, 204, 47, 44, 29, 22, 44, 29, 22, 239, 87, 52, 43, 175, 43, 190, 191,
-            0, 36, 110, 178, 208, 94, 193, 0, 172, 238, 191, 191, 191, 102, 102, 102, 229, 0, 0,
-            134, 169, 62, 229, 229, 0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Japanesque",
-        [
-            247, 246, 236, 247, 246, 236, 30, 30, 30, 52, 57, 53, 207, 63, 97, 123, 183, 91, 233,
-            179, 42, 76, 154, 212, 165, 127, 196, 56, 154, 173, 250, 250, 246, 89, 91, 89, 209,
-            143, 166, 118, 127, 44, 120, 89, 47, 19, 89, 121, 96, 66, 145, 118, 187, 202, 178, 181,
-            174,
-        ],
-    ),
-    (
-        "Jellybeans",
-        [
-            222, 222, 222, 222, 222, 222, 18, 18, 18, 146, 146, 146, 226, 115, 115, 148, 185, 121,
-            255, 186, 123, 151, 190, 220, 225, 192, 250, 0, 152, 142, 222, 222, 222, 189, 189, 189,
-            255, 161, 161, 189, 222, 171, 255, 220, 160, 177, 216, 246, 251, 218, 255, 26, 178,
-            168, 255, 255, 255,
-        ],
-    ),
-    (
-        "Jup",
-        [
-            35, 71, 106, 35, 71, 106, 117, 132, 128, 0, 0, 0, 221, 0, 111, 111, 221, 0, 221, 111,
-            0, 0, 111, 221, 111, 0, 221, 0, 221, 111, 242, 242, 242, 125, 125, 125, 255, 116, 185,
-            185, 255, 116, 255, 185, 116, 116, 185, 255, 185, 116, 255, 116, 255, 185, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Kanagawa",
-        [
-            220, 215, 186, 220, 215, 186, 31, 31, 40, 9, 6, 24, 195, 64, 67, 118, 148, 106, 192,
-            163, 110, 126, 156, 216, 149, 127, 184, 106, 149, 137, 220, 215, 186, 114, 113, 105,
-            232, 36, 36, 152, 187, 108, 230, 195, 132, 127, 180, 202, 147, 138, 169, 122, 168, 159,
-            200, 192, 147,
-        ],
-    ),
-    (
-        "Kibble",
-        [
-            247, 247, 247, 247, 247, 247, 14, 16, 10, 77, 77, 77, 199, 0, 49, 41, 207, 19, 216,
-            227, 14, 52, 73, 209, 132, 0, 255, 7, 152, 171, 226, 209, 227, 90, 90, 90, 240, 21,
-            120, 108, 224, 92, 243, 247, 158, 151, 164, 247, 196, 149, 240, 104, 242, 224, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Kokuban",
-        [
-            216, 226, 215, 216, 226, 215, 13, 74, 8, 46, 135, 68, 216, 78, 76, 149, 218, 90, 214,
-            226, 100, 75, 158, 215, 148, 95, 197, 216, 155, 37, 216, 226, 215, 52, 147, 79, 255,
-            79, 89, 175, 245, 106, 252, 255, 117, 87, 174, 255, 174, 99, 233, 255, 170, 43, 255,
-            254, 254,
-        ],
-    ),
-    (
-        "Laserwave",
-        [
-            199, 199, 199, 224, 224, 224, 31, 25, 38, 57, 36, 58, 235, 100, 185, 175, 214, 134,
-            254, 174, 135, 64, 180, 196, 179, 129, 197, 33, 89, 105, 145, 136, 155, 113, 100, 133,
-            252, 35, 119, 80, 250, 123, 255, 226, 97, 116, 223, 196, 109, 117, 224, 180, 220, 231,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Later This Evening",
-        [
-            149, 149, 149, 149, 149, 149, 34, 34, 34, 43, 43, 43, 212, 90, 96, 175, 186, 103, 229,
-            210, 137, 160, 186, 214, 192, 146, 214, 145, 191, 183, 60, 61, 61, 69, 71, 71, 211, 35,
-            47, 170, 187, 57, 229, 190, 57, 102, 153, 214, 171, 83, 214, 95, 192, 174, 193, 194,
-            194,
-        ],
-    ),
-    (
-        "Lavandula",
-        [
-            115, 110, 125, 115, 110, 125, 5, 0, 20, 35, 0, 70, 125, 22, 37, 51, 126, 111, 127, 111,
-            73, 79, 74, 127, 90, 63, 127, 88, 119, 127, 115, 110, 125, 55, 45, 70, 224, 81, 103,
-            82, 224, 196, 224, 195, 134, 142, 135, 224, 167, 118, 224, 154, 212, 224, 140, 145,
-            250,
-        ],
-    ),
-    (
-        "Liquid Carbon",
-        [
-            175, 194, 194, 175, 194, 194, 48, 48, 48, 0, 0, 0, 255, 48, 48, 85, 154, 112, 204, 172,
-            0, 0, 153, 204, 204, 105, 200, 122, 196, 204, 188, 204, 204, 0, 0, 0, 255, 48, 48, 85,
-            154, 112, 204, 172, 0, 0, 153, 204, 204, 105, 200, 122, 196, 204, 188, 204, 204,
-        ],
-    ),
-    (
-        "Liquid Carbon Transparent",
-        [
-            175, 194, 194, 175, 194, 194, 0, 0, 0, 0, 0, 0, 255, 48, 48, 85, 154, 112, 204, 172, 0,
-            0, 153, 204, 204, 105, 200, 122, 196, 204, 188, 204, 204, 0, 0, 0, 255, 48, 48, 85,
-            154, 112, 204, 172, 0, 0, 153, 204, 204, 105, 200, 122, 196, 204, 188, 204, 204,
-        ],
-    ),
-    (
-        "Lunaria Dark",
-        [
-            202, 206, 216, 202, 206, 216, 54, 70, 78, 54, 70, 78, 132, 101, 96, 128, 153, 132, 167,
-            154, 121, 85, 86, 115, 134, 108, 131, 126, 152, 180, 202, 206, 216, 64, 79, 86, 187,
-            146, 139, 191, 220, 194, 241, 223, 182, 119, 119, 152, 191, 157, 185, 189, 220, 255,
-            223, 226, 237,
-        ],
-    ),
-    (
-        "Lunaria Eclipse",
-        [
-            201, 205, 215, 201, 205, 215, 50, 63, 70, 50, 63, 70, 131, 97, 91, 127, 151, 129, 166,
-            152, 117, 83, 81, 111, 133, 104, 128, 125, 150, 178, 201, 205, 215, 61, 73, 80, 186,
-            144, 136, 190, 219, 193, 241, 223, 180, 118, 116, 149, 190, 156, 184, 188, 219, 255,
-            223, 226, 237,
-        ],
-    ),
-    (
-        "Lunaria Light",
-        [
-            72, 70, 70, 72, 70, 70, 235, 228, 225, 62, 60, 61, 120, 60, 31, 73, 125, 70, 143, 117,
-            11, 63, 53, 102, 121, 63, 98, 55, 120, 169, 213, 207, 204, 72, 70, 70, 176, 98, 64,
-            123, 193, 117, 220, 183, 53, 92, 79, 137, 181, 104, 149, 100, 186, 255, 235, 228, 225,
-        ],
-    ),
-    (
-        "Maia",
-        [
-            189, 195, 199, 189, 195, 199, 49, 54, 59, 35, 36, 35, 186, 41, 34, 126, 128, 126, 76,
-            79, 77, 22, 160, 133, 67, 116, 106, 0, 204, 204, 224, 224, 224, 40, 41, 40, 204, 55,
-            44, 141, 143, 141, 78, 82, 79, 19, 191, 157, 72, 125, 114, 0, 209, 209, 232, 232, 232,
-        ],
-    ),
-    (
-        "Man Page",
-        [
-            0, 0, 0, 0, 0, 0, 254, 244, 156, 0, 0, 0, 204, 0, 0, 0, 166, 0, 153, 153, 0, 0, 0, 178,
-            178, 0, 178, 0, 166, 178, 204, 204, 204, 102, 102, 102, 229, 0, 0, 0, 217, 0, 229, 229,
-            0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Mar",
-        [
-            35, 71, 106, 35, 71, 106, 255, 255, 255, 0, 0, 0, 181, 64, 123, 123, 181, 64, 181, 123,
-            64, 64, 123, 181, 123, 64, 181, 64, 181, 123, 248, 248, 248, 115, 115, 115, 205, 115,
-            160, 160, 205, 115, 205, 160, 115, 115, 160, 205, 160, 115, 205, 115, 205, 160, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Material",
-        [
-            101, 123, 131, 195, 199, 209, 30, 40, 44, 7, 54, 65, 235, 96, 107, 195, 232, 141, 247,
-            235, 149, 128, 203, 195, 255, 36, 144, 174, 221, 255, 255, 255, 255, 0, 43, 54, 235,
-            96, 107, 195, 232, 141, 247, 235, 149, 125, 198, 191, 108, 113, 195, 52, 67, 77, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Mathias",
-        [
-            187, 187, 187, 187, 187, 187, 0, 0, 0, 0, 0, 0, 229, 34, 34, 166, 227, 45, 252, 149,
-            30, 196, 141, 255, 250, 37, 115, 103, 217, 240, 242, 242, 242, 85, 85, 85, 255, 85, 85,
-            85, 255, 85, 255, 255, 85, 85, 85, 255, 255, 85, 255, 85, 255, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Medallion",
-        [
-            202, 194, 150, 202, 194, 150, 29, 25, 8, 0, 0, 0, 182, 76, 0, 124, 139, 22, 211, 189,
-            38, 97, 107, 176, 140, 90, 144, 145, 108, 37, 202, 194, 154, 94, 82, 25, 255, 145, 73,
-            178, 202, 59, 255, 229, 74, 172, 184, 255, 255, 160, 255, 255, 188, 81, 254, 214, 152,
-        ],
-    ),
-    (
-        "Misterioso",
-        [
-            225, 225, 224, 225, 225, 224, 45, 55, 67, 0, 0, 0, 255, 66, 66, 116, 175, 104, 255,
-            173, 41, 51, 143, 134, 148, 20, 230, 35, 215, 215, 225, 225, 224, 85, 85, 85, 255, 50,
-            66, 116, 205, 104, 255, 185, 41, 35, 215, 215, 255, 55, 255, 0, 237, 225, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Molokai",
-        [
-            187, 187, 187, 187, 187, 187, 27, 29, 30, 27, 29, 30, 115, 37, 250, 35, 226, 152, 96,
-            212, 223, 208, 128, 16, 255, 0, 135, 208, 168, 67, 187, 187, 187, 85, 85, 85, 157, 102,
-            246, 95, 224, 177, 109, 242, 255, 255, 175, 0, 255, 135, 175, 255, 206, 81, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Mona Lisa",
-        [
-            247, 214, 106, 247, 214, 106, 18, 11, 13, 53, 27, 14, 155, 41, 28, 99, 98, 50, 195,
-            110, 40, 81, 92, 93, 155, 29, 41, 88, 128, 86, 247, 215, 92, 135, 66, 40, 255, 67, 49,
-            180, 178, 100, 255, 149, 102, 158, 178, 180, 255, 91, 106, 138, 205, 143, 255, 229,
-            152,
-        ],
-    ),
-    (
-        "Mono Amber",
-        [
-            255, 148, 0, 255, 148, 0, 43, 25, 0, 64, 37, 0, 255, 148, 0, 255, 148, 0, 255, 148, 0,
-            255, 148, 0, 255, 148, 0, 255, 148, 0, 255, 148, 0, 255, 148, 0, 255, 148, 0, 255, 148,
-            0, 255, 148, 0, 255, 148, 0, 255, 148, 0, 255, 148, 0, 255, 148, 0,
-        ],
-    ),
-    (
-        "Mono Cyan",
-        [
-            0, 204, 255, 0, 204, 255, 0, 34, 43, 0, 51, 64, 0, 204, 255, 0, 204, 255, 0, 204, 255,
-            0, 204, 255, 0, 204, 255, 0, 204, 255, 0, 204, 255, 0, 204, 255, 0, 204, 255, 0, 204,
-            255, 0, 204, 255, 0, 204, 255, 0, 204, 255, 0, 204, 255, 0, 204, 255,
-        ],
-    ),
-    (
-        "Mono Green",
-        [
-            11, 255, 0, 11, 255, 0, 2, 43, 0, 3, 64, 0, 11, 255, 0, 11, 255, 0, 11, 255, 0, 11,
-            255, 0, 11, 255, 0, 11, 255, 0, 11, 255, 0, 11, 255, 0, 11, 255, 0, 11, 255, 0, 11,
-            255, 0, 11, 255, 0, 11, 255, 0, 11, 255, 0, 11, 255, 0,
-        ],
-    ),
-    (
-        "Mono Red",
-        [
-            255, 54, 0, 255, 54, 0, 43, 12, 0, 64, 18, 0, 255, 54, 0, 255, 54, 0, 255, 54, 0, 255,
-            54, 0, 255, 54, 0, 255, 54, 0, 255, 54, 0, 255, 54, 0, 255, 54, 0, 255, 54, 0, 255, 54,
-            0, 255, 54, 0, 255, 54, 0, 255, 54, 0, 255, 54, 0,
-        ],
-    ),
-    (
-        "Mono White",
-        [
-            250, 250, 250, 250, 250, 250, 38, 38, 38, 59, 59, 59, 250, 250, 250, 250, 250, 250,
-            250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250,
-            250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250, 250,
-            250, 250, 250, 250, 250,
-        ],
-    ),
-    (
-        "Mono Yellow",
-        [
-            255, 211, 0, 255, 211, 0, 43, 36, 0, 64, 53, 0, 255, 211, 0, 255, 211, 0, 255, 211, 0,
-            255, 211, 0, 255, 211, 0, 255, 211, 0, 255, 211, 0, 255, 211, 0, 255, 211, 0, 255, 211,
-            0, 255, 211, 0, 255, 211, 0, 255, 211, 0, 255, 211, 0, 255, 211, 0,
-        ],
-    ),
-    (
-        "Monokai Dark",
-        [
-            248, 248, 242, 248, 248, 242, 39, 40, 34, 117, 113, 94, 249, 38, 114, 166, 226, 46,
-            244, 191, 117, 102, 217, 239, 174, 129, 255, 42, 161, 152, 249, 248, 245, 39, 40, 34,
-            249, 38, 114, 166, 226, 46, 244, 191, 117, 102, 217, 239, 174, 129, 255, 42, 161, 152,
-            248, 248, 242,
-        ],
-    ),
-    (
-        "Monokai Pro",
-        [
-            253, 249, 243, 253, 249, 243, 54, 53, 55, 54, 53, 55, 255, 97, 136, 169, 220, 118, 255,
-            216, 102, 252, 152, 103, 171, 157, 242, 120, 220, 232, 253, 249, 243, 144, 142, 143,
-            255, 97, 136, 169, 220, 118, 255, 216, 102, 252, 152, 103, 171, 157, 242, 120, 220,
-            232, 253, 249, 243,
-        ],
-    ),
-    (
-        "Monokai Pro Ristretto",
-        [
-            251, 242, 243, 251, 242, 243, 62, 56, 56, 62, 56, 56, 223, 116, 132, 187, 216, 126,
-            237, 206, 115, 220, 147, 115, 169, 170, 233, 164, 215, 204, 251, 242, 243, 112, 105,
-            106, 223, 116, 132, 187, 216, 126, 237, 206, 115, 220, 147, 115, 169, 170, 233, 164,
-            215, 204, 251, 242, 243,
-        ],
-    ),
-    (
-        "Monokai Soda",
-        [
-            196, 197, 181, 196, 197, 181, 26, 26, 26, 26, 26, 26, 244, 0, 95, 152, 224, 36, 250,
-            132, 25, 157, 101, 255, 244, 0, 95, 88, 209, 235, 196, 197, 181, 98, 94, 76, 244, 0,
-            95, 152, 224, 36, 224, 213, 97, 157, 101, 255, 244, 0, 95, 88, 209, 235, 246, 246, 239,
-        ],
-    ),
-    (
-        "Morada",
-        [
-            255, 255, 255, 255, 255, 255, 33, 31, 70, 4, 4, 4, 15, 73, 196, 72, 177, 23, 232, 115,
-            36, 188, 1, 22, 102, 91, 147, 112, 166, 153, 245, 220, 190, 79, 124, 191, 28, 150, 199,
-            59, 255, 111, 239, 195, 28, 251, 96, 91, 151, 91, 90, 30, 255, 142, 246, 245, 251,
-        ],
-    ),
-    (
-        "N0Tch2K",
-        [
-            160, 160, 160, 160, 160, 160, 34, 34, 34, 56, 56, 56, 169, 85, 81, 102, 102, 102, 169,
-            128, 81, 101, 125, 62, 118, 118, 118, 201, 201, 201, 208, 184, 163, 71, 71, 71, 169,
-            119, 117, 140, 140, 140, 169, 145, 117, 152, 189, 94, 163, 163, 163, 220, 220, 220,
-            216, 200, 187,
-        ],
-    ),
-    (
-        "Neon Night",
-        [
-            199, 200, 255, 199, 200, 255, 32, 36, 45, 32, 36, 45, 255, 142, 142, 126, 253, 208,
-            252, 173, 63, 105, 180, 249, 221, 146, 246, 140, 232, 255, 201, 204, 205, 32, 36, 45,
-            255, 142, 142, 126, 253, 208, 252, 173, 63, 105, 180, 249, 221, 146, 246, 140, 232,
-            255, 201, 204, 205,
-        ],
-    ),
-    (
-        "Neopolitan",
-        [
-            255, 255, 255, 255, 255, 255, 39, 31, 25, 0, 0, 0, 128, 0, 0, 97, 206, 60, 251, 222,
-            45, 37, 59, 118, 255, 0, 128, 141, 166, 206, 248, 248, 248, 0, 0, 0, 128, 0, 0, 97,
-            206, 60, 251, 222, 45, 37, 59, 118, 255, 0, 128, 141, 166, 206, 248, 248, 248,
-        ],
-    ),
-    (
-        "Nep",
-        [
-            35, 71, 106, 35, 71, 106, 117, 132, 128, 0, 0, 0, 221, 111, 0, 0, 221, 111, 111, 221,
-            0, 111, 0, 221, 221, 0, 111, 0, 111, 221, 242, 242, 242, 125, 125, 125, 255, 185, 116,
-            116, 255, 185, 185, 255, 116, 185, 116, 255, 255, 116, 185, 116, 185, 255, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Neutron",
-        [
-            230, 232, 239, 230, 232, 239, 28, 30, 34, 35, 37, 43, 181, 64, 54, 90, 185, 119, 222,
-            181, 102, 106, 124, 147, 164, 121, 157, 63, 148, 168, 230, 232, 239, 35, 37, 43, 181,
-            64, 54, 90, 185, 119, 222, 181, 102, 106, 124, 147, 164, 121, 157, 63, 148, 168, 235,
-            237, 242,
-        ],
-    ),
-    (
-        "Night Fox",
-        [
-            205, 206, 207, 205, 206, 207, 25, 35, 48, 57, 59, 68, 201, 79, 109, 129, 178, 154, 219,
-            192, 116, 113, 156, 214, 157, 121, 214, 99, 205, 207, 223, 223, 224, 87, 88, 96, 209,
-            105, 131, 142, 186, 164, 224, 201, 137, 134, 171, 220, 186, 161, 226, 122, 212, 214,
-            228, 228, 229,
-        ],
-    ),
-    (
-        "Night Owl",
-        [
-            214, 222, 235, 214, 222, 235, 1, 22, 39, 1, 22, 39, 239, 83, 80, 34, 218, 110, 173,
-            219, 103, 130, 170, 255, 199, 146, 234, 33, 199, 168, 255, 255, 255, 87, 86, 86, 239,
-            83, 80, 34, 218, 110, 255, 235, 149, 130, 170, 255, 199, 146, 234, 127, 219, 202, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Nightlion V1",
-        [
-            187, 187, 187, 187, 187, 187, 0, 0, 0, 76, 76, 76, 187, 0, 0, 95, 222, 143, 243, 241,
-            103, 39, 107, 216, 187, 0, 187, 0, 218, 223, 187, 187, 187, 85, 85, 85, 255, 85, 85,
-            85, 255, 85, 255, 255, 85, 85, 85, 255, 255, 85, 255, 85, 255, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Nightlion V2",
-        [
-            187, 187, 187, 187, 187, 187, 23, 23, 23, 76, 76, 76, 187, 0, 0, 4, 246, 35, 243, 241,
-            103, 100, 208, 240, 206, 111, 219, 0, 218, 223, 187, 187, 187, 85, 85, 85, 255, 85, 85,
-            125, 247, 29, 255, 255, 85, 98, 203, 232, 255, 155, 245, 0, 204, 216, 255, 255, 255,
-        ],
-    ),
-    (
-        "Nighty",
-        [
-            223, 223, 223, 223, 223, 223, 47, 47, 47, 55, 61, 72, 155, 62, 70, 9, 91, 50, 128, 128,
-            32, 29, 62, 111, 130, 48, 101, 58, 116, 88, 130, 130, 130, 92, 99, 112, 208, 85, 95,
-            17, 153, 85, 223, 224, 72, 70, 116, 184, 237, 134, 201, 112, 210, 164, 223, 223, 223,
-        ],
-    ),
-    (
-        "Nord",
-        [
-            216, 222, 233, 216, 222, 233, 46, 52, 64, 59, 66, 82, 191, 97, 106, 163, 190, 140, 235,
-            203, 139, 129, 161, 193, 180, 142, 173, 136, 192, 208, 229, 233, 240, 76, 86, 106, 191,
-            97, 106, 163, 190, 140, 235, 203, 139, 129, 161, 193, 180, 142, 173, 143, 188, 187,
-            236, 239, 244,
-        ],
-    ),
-    (
-        "Nord Light",
-        [
-            67, 158, 207, 0, 79, 124, 235, 234, 242, 0, 59, 78, 230, 69, 105, 6, 159, 95, 218, 183,
-            82, 67, 158, 207, 217, 97, 220, 0, 177, 190, 179, 179, 179, 62, 137, 161, 228, 133,
-            154, 162, 204, 161, 225, 227, 135, 111, 187, 226, 229, 134, 231, 150, 220, 218, 222,
-            222, 222,
-        ],
-    ),
-    (
-        "Novel",
-        [
-            59, 35, 34, 59, 35, 34, 223, 219, 195, 0, 0, 0, 204, 0, 0, 0, 150, 0, 208, 107, 0, 0,
-            0, 204, 204, 0, 204, 0, 135, 204, 204, 204, 204, 128, 128, 128, 204, 0, 0, 0, 150, 0,
-            208, 107, 0, 0, 0, 204, 204, 0, 204, 0, 135, 204, 255, 255, 255,
-        ],
-    ),
-    (
-        "Obsidian",
-        [
-            205, 205, 205, 205, 205, 205, 40, 48, 51, 0, 0, 0, 166, 0, 1, 0, 187, 0, 254, 205, 34,
-            58, 155, 219, 187, 0, 187, 0, 187, 187, 187, 187, 187, 85, 85, 85, 255, 0, 3, 147, 200,
-            99, 254, 248, 116, 161, 215, 255, 255, 85, 255, 85, 255, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Ocean Dark",
-        [
-            151, 156, 172, 151, 156, 172, 28, 31, 39, 79, 79, 79, 175, 75, 87, 175, 211, 131, 229,
-            192, 121, 125, 144, 164, 164, 121, 157, 133, 166, 165, 238, 237, 238, 123, 123, 123,
-            175, 75, 87, 206, 255, 171, 255, 254, 204, 181, 220, 254, 251, 155, 254, 223, 223, 253,
-            254, 255, 254,
-        ],
-    ),
-    (
-        "Oceanic Next",
-        [
-            179, 184, 195, 179, 184, 195, 18, 27, 33, 18, 28, 33, 228, 71, 84, 137, 189, 130, 247,
-            189, 81, 84, 134, 192, 183, 126, 184, 80, 165, 164, 255, 255, 255, 82, 96, 107, 228,
-            71, 84, 137, 189, 130, 247, 189, 81, 84, 134, 192, 183, 126, 184, 80, 165, 164, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Ollie",
-        [
-            138, 141, 174, 138, 141, 174, 34, 33, 37, 0, 0, 0, 172, 46, 49, 49, 172, 97, 172, 67,
-            0, 45, 87, 172, 176, 133, 40, 31, 166, 172, 138, 142, 172, 91, 55, 37, 255, 61, 72, 59,
-            255, 153, 255, 94, 30, 68, 136, 255, 255, 194, 29, 31, 250, 255, 91, 110, 167,
-        ],
-    ),
-    (
-        "Omni",
-        [
-            171, 178, 191, 171, 178, 191, 25, 22, 34, 25, 22, 34, 233, 99, 121, 103, 228, 128, 232,
-            158, 100, 120, 209, 225, 152, 139, 199, 255, 121, 198, 171, 178, 191, 0, 0, 0, 233, 99,
-            121, 103, 228, 128, 232, 158, 100, 120, 209, 225, 152, 139, 199, 255, 121, 198, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "One Dark",
-        [
-            92, 99, 112, 92, 99, 112, 30, 33, 39, 0, 0, 0, 224, 108, 117, 152, 195, 121, 209, 154,
-            102, 97, 175, 239, 198, 120, 221, 86, 182, 194, 171, 178, 191, 92, 99, 112, 224, 108,
-            117, 152, 195, 121, 209, 154, 102, 97, 175, 239, 198, 120, 221, 86, 182, 194, 255, 254,
-            254,
-        ],
-    ),
-    (
-        "One Half Black",
-        [
-            220, 223, 228, 220, 223, 228, 0, 0, 0, 40, 44, 52, 224, 108, 117, 152, 195, 121, 229,
-            192, 123, 97, 175, 239, 198, 120, 221, 86, 182, 194, 220, 223, 228, 40, 44, 52, 224,
-            108, 117, 152, 195, 121, 229, 192, 123, 97, 175, 239, 198, 120, 221, 86, 182, 194, 220,
-            223, 228,
-        ],
-    ),
-    (
-        "One Light",
-        [
-            42, 43, 50, 42, 43, 50, 248, 248, 248, 0, 0, 0, 218, 62, 57, 65, 147, 62, 133, 85, 4,
-            49, 94, 238, 147, 0, 146, 14, 111, 173, 142, 143, 150, 42, 43, 50, 218, 62, 57, 65,
-            147, 62, 133, 85, 4, 49, 94, 238, 147, 0, 146, 14, 111, 173, 255, 254, 254,
-        ],
-    ),
-    (
-        "Oxocarbon Dark",
-        [
-            111, 111, 111, 255, 255, 255, 22, 22, 22, 38, 38, 38, 238, 83, 150, 66, 190, 101, 255,
-            233, 123, 51, 177, 255, 255, 126, 182, 61, 219, 217, 221, 225, 230, 57, 57, 57, 238,
-            83, 150, 66, 190, 101, 255, 233, 123, 51, 177, 255, 255, 126, 182, 61, 219, 217, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Palenight",
-        [
-            191, 199, 213, 191, 199, 213, 41, 45, 62, 41, 45, 62, 240, 113, 120, 195, 232, 141,
-            255, 203, 107, 130, 170, 255, 199, 146, 234, 96, 173, 236, 171, 178, 191, 149, 157,
-            203, 240, 113, 120, 195, 232, 141, 255, 85, 114, 130, 170, 255, 255, 203, 107, 103,
-            110, 149, 255, 254, 254,
-        ],
-    ),
-    (
-        "Pali",
-        [
-            217, 230, 242, 217, 230, 242, 35, 46, 55, 10, 10, 10, 171, 143, 116, 116, 171, 143,
-            143, 171, 116, 143, 116, 171, 171, 116, 143, 116, 143, 171, 242, 242, 242, 93, 93, 93,
-            255, 29, 98, 156, 195, 175, 255, 208, 10, 175, 156, 195, 255, 29, 98, 75, 184, 253,
-            160, 32, 240,
-        ],
-    ),
-    (
-        "Panda",
-        [
-            240, 240, 240, 240, 240, 240, 29, 30, 32, 31, 31, 32, 251, 5, 90, 38, 255, 212, 253,
-            170, 90, 92, 159, 255, 252, 89, 166, 38, 255, 212, 240, 240, 240, 92, 99, 112, 251, 5,
-            90, 38, 255, 212, 254, 190, 126, 85, 173, 255, 253, 149, 208, 38, 255, 212, 240, 240,
-            240,
-        ],
-    ),
-    (
-        "Papercolor Dark",
-        [
-            208, 208, 208, 208, 208, 208, 28, 28, 28, 28, 28, 28, 175, 0, 95, 95, 175, 0, 215, 175,
-            95, 95, 175, 215, 128, 128, 128, 215, 135, 95, 208, 208, 208, 88, 88, 88, 95, 175, 95,
-            175, 215, 0, 175, 135, 215, 255, 175, 0, 255, 95, 175, 0, 175, 175, 95, 135, 135,
-        ],
-    ),
-    (
-        "Papercolor Light",
-        [
-            68, 68, 68, 68, 68, 68, 238, 238, 238, 238, 238, 238, 175, 0, 0, 0, 135, 0, 95, 135, 0,
-            0, 135, 175, 135, 135, 135, 0, 95, 135, 68, 68, 68, 188, 188, 188, 215, 0, 0, 215, 0,
-            135, 135, 0, 175, 215, 95, 0, 215, 95, 0, 0, 95, 175, 0, 95, 135,
-        ],
-    ),
-    (
-        "Paraiso Dark",
-        [
-            163, 158, 155, 163, 158, 155, 47, 30, 46, 47, 30, 46, 239, 97, 85, 72, 182, 133, 254,
-            196, 24, 6, 182, 239, 129, 91, 164, 91, 196, 191, 163, 158, 155, 119, 110, 113, 239,
-            97, 85, 72, 182, 133, 254, 196, 24, 6, 182, 239, 129, 91, 164, 91, 196, 191, 231, 233,
-            219,
-        ],
-    ),
-    (
-        "Paul Millr",
-        [
-            242, 242, 242, 242, 242, 242, 0, 0, 0, 42, 42, 42, 255, 0, 0, 121, 255, 15, 211, 191,
-            0, 57, 107, 215, 180, 73, 190, 102, 204, 255, 187, 187, 187, 102, 102, 102, 255, 0,
-            128, 102, 255, 102, 243, 214, 78, 112, 154, 237, 219, 103, 230, 122, 223, 242, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Pencil Dark",
-        [
-            241, 241, 241, 241, 241, 241, 33, 33, 33, 33, 33, 33, 195, 7, 113, 16, 167, 120, 168,
-            156, 20, 0, 142, 196, 82, 60, 121, 32, 165, 186, 217, 217, 217, 66, 66, 66, 251, 0,
-            122, 95, 215, 175, 243, 228, 48, 32, 187, 252, 104, 85, 222, 79, 184, 204, 241, 241,
-            241,
-        ],
-    ),
-    (
-        "Pencil Light",
-        [
-            66, 66, 66, 66, 66, 66, 241, 241, 241, 33, 33, 33, 195, 7, 113, 16, 167, 120, 168, 156,
-            20, 0, 142, 196, 82, 60, 121, 32, 165, 186, 217, 217, 217, 66, 66, 66, 251, 0, 122, 95,
-            215, 175, 243, 228, 48, 32, 187, 252, 104, 85, 222, 79, 184, 204, 241, 241, 241,
-        ],
-    ),
-    (
-        "Peppermint",
-        [
-            187, 187, 187, 199, 199, 199, 0, 0, 0, 53, 53, 53, 230, 69, 105, 137, 210, 135, 218,
-            183, 82, 67, 158, 207, 217, 97, 220, 100, 170, 175, 179, 179, 179, 83, 83, 83, 228,
-            133, 154, 162, 204, 161, 225, 227, 135, 111, 187, 226, 229, 134, 231, 150, 220, 218,
-            222, 222, 222,
-        ],
-    ),
-    (
-        "Pixiefloss",
-        [
-            209, 202, 232, 209, 202, 232, 36, 31, 51, 47, 41, 66, 255, 133, 127, 72, 182, 133, 230,
-            192, 0, 174, 129, 255, 239, 97, 85, 194, 255, 223, 248, 248, 242, 117, 80, 123, 241,
-            86, 142, 90, 219, 162, 213, 164, 37, 197, 163, 255, 239, 97, 85, 194, 255, 255, 248,
-            248, 240,
-        ],
-    ),
-    (
-        "Pnevma",
-        [
-            208, 208, 208, 208, 208, 208, 28, 28, 28, 47, 46, 45, 163, 102, 102, 144, 165, 125,
-            215, 175, 135, 127, 165, 189, 199, 158, 196, 138, 219, 180, 208, 208, 208, 74, 72, 69,
-            215, 135, 135, 175, 190, 162, 228, 201, 175, 161, 189, 206, 215, 190, 218, 177, 231,
-            221, 239, 239, 239,
-        ],
-    ),
-    (
-        "Powershell",
-        [
-            246, 246, 247, 246, 246, 247, 5, 36, 84, 0, 0, 0, 126, 0, 8, 9, 128, 3, 196, 160, 0, 1,
-            0, 131, 211, 54, 130, 14, 128, 127, 127, 124, 127, 128, 128, 128, 239, 41, 41, 28, 254,
-            60, 254, 254, 69, 38, 138, 210, 254, 19, 250, 41, 255, 254, 194, 193, 195,
-        ],
-    ),
-    (
-        "Predawn",
-        [
-            241, 241, 241, 241, 241, 241, 40, 40, 40, 241, 130, 96, 181, 26, 32, 166, 204, 117,
-            254, 211, 58, 129, 178, 177, 235, 108, 78, 129, 178, 177, 234, 234, 234, 102, 102, 102,
-            255, 0, 11, 196, 238, 148, 232, 224, 109, 165, 235, 234, 239, 138, 80, 165, 235, 234,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Pro",
-        [
-            242, 242, 242, 242, 242, 242, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 166, 0, 153, 153, 0, 32,
-            9, 219, 178, 0, 178, 0, 166, 178, 191, 191, 191, 102, 102, 102, 229, 0, 0, 0, 217, 0,
-            229, 229, 0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Purple People Eater",
-        [
-            201, 209, 217, 201, 209, 217, 22, 27, 34, 13, 17, 23, 227, 76, 38, 35, 134, 54, 237,
-            154, 81, 165, 214, 255, 110, 176, 232, 192, 154, 235, 201, 209, 217, 13, 17, 23, 255,
-            123, 114, 59, 171, 74, 255, 166, 87, 165, 214, 255, 121, 192, 255, 182, 148, 223, 201,
-            209, 217,
-        ],
-    ),
-    (
-        "Red Alert",
-        [
-            255, 255, 255, 255, 255, 255, 118, 36, 35, 0, 0, 0, 214, 46, 78, 113, 190, 107, 190,
-            184, 107, 72, 155, 238, 233, 121, 215, 107, 190, 184, 214, 214, 214, 38, 38, 38, 224,
-            37, 83, 175, 240, 140, 223, 221, 183, 101, 170, 241, 221, 183, 223, 183, 223, 221, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Red Sands",
-        [
-            215, 201, 167, 215, 201, 167, 122, 37, 30, 0, 0, 0, 255, 63, 0, 0, 187, 0, 231, 176, 0,
-            0, 114, 255, 187, 0, 187, 0, 187, 187, 187, 187, 187, 85, 85, 85, 187, 0, 0, 0, 187, 0,
-            231, 176, 0, 0, 114, 174, 255, 85, 255, 85, 255, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Relaxed",
-        [
-            217, 217, 217, 217, 217, 217, 53, 58, 68, 21, 21, 21, 188, 86, 83, 144, 157, 99, 235,
-            193, 122, 106, 135, 153, 176, 102, 152, 201, 223, 255, 217, 217, 217, 99, 99, 99, 188,
-            86, 83, 160, 172, 119, 235, 193, 122, 126, 170, 199, 176, 102, 152, 172, 187, 208, 247,
-            247, 247,
-        ],
-    ),
-    (
-        "Rippedcasts",
-        [
-            255, 255, 255, 255, 255, 255, 43, 43, 43, 0, 0, 0, 205, 175, 149, 168, 255, 96, 191,
-            187, 31, 117, 165, 176, 255, 115, 253, 90, 100, 126, 191, 191, 191, 102, 102, 102, 238,
-            203, 173, 188, 238, 104, 229, 229, 0, 134, 189, 201, 229, 0, 229, 140, 155, 196, 229,
-            229, 229,
-        ],
-    ),
-    (
-        "Rose Pine",
-        [
-            224, 222, 244, 224, 222, 244, 25, 23, 36, 38, 35, 58, 235, 111, 146, 156, 207, 216,
-            246, 193, 119, 49, 116, 143, 196, 167, 231, 235, 188, 186, 224, 222, 244, 110, 106,
-            134, 235, 111, 146, 156, 207, 216, 246, 193, 119, 49, 116, 143, 196, 167, 231, 235,
-            188, 186, 224, 222, 244,
-        ],
-    ),
-    (
-        "Rose Pine Dawn",
-        [
-            87, 82, 121, 87, 82, 121, 250, 244, 237, 242, 233, 225, 180, 99, 122, 86, 148, 159,
-            234, 157, 52, 40, 105, 131, 144, 122, 169, 215, 130, 126, 87, 82, 121, 152, 147, 165,
-            180, 99, 122, 86, 148, 159, 234, 157, 52, 40, 105, 131, 144, 122, 169, 215, 130, 126,
-            87, 82, 121,
-        ],
-    ),
-    (
-        "Rose Pine Moon",
-        [
-            224, 222, 244, 224, 222, 244, 35, 33, 54, 57, 53, 82, 235, 111, 146, 156, 207, 216,
-            246, 193, 119, 62, 143, 176, 196, 167, 231, 234, 154, 151, 224, 222, 244, 110, 106,
-            134, 235, 111, 146, 156, 207, 216, 246, 193, 119, 62, 143, 176, 196, 167, 231, 234,
-            154, 151, 224, 222, 244,
-        ],
-    ),
-    (
-        "Royal",
-        [
-            81, 73, 104, 81, 73, 104, 16, 8, 21, 36, 31, 43, 145, 40, 76, 35, 128, 28, 180, 157,
-            39, 101, 128, 176, 103, 77, 150, 138, 170, 190, 82, 73, 102, 49, 45, 61, 213, 53, 108,
-            44, 217, 70, 253, 232, 59, 144, 186, 249, 164, 121, 227, 172, 212, 235, 158, 140, 189,
-        ],
-    ),
-    (
-        "Sat",
-        [
-            35, 71, 106, 35, 71, 106, 117, 132, 128, 0, 0, 0, 221, 0, 7, 7, 221, 0, 221, 214, 0, 0,
-            7, 221, 214, 0, 221, 0, 221, 214, 242, 242, 242, 125, 125, 125, 255, 116, 120, 120,
-            255, 116, 255, 250, 116, 116, 120, 255, 250, 116, 255, 116, 255, 250, 255, 255, 255,
-        ],
-    ),
-    (
-        "Sea Shells",
-        [
-            222, 184, 141, 222, 184, 141, 9, 20, 27, 23, 56, 76, 209, 81, 35, 2, 124, 155, 252,
-            160, 47, 30, 73, 80, 104, 212, 241, 80, 163, 181, 222, 184, 141, 67, 75, 83, 212, 134,
-            120, 98, 141, 152, 253, 211, 159, 27, 188, 221, 187, 227, 238, 135, 172, 180, 254, 228,
-            206,
-        ],
-    ),
-    (
-        "Seafoam Pastel",
-        [
-            212, 231, 212, 212, 231, 212, 36, 52, 53, 117, 117, 117, 130, 93, 77, 114, 140, 98,
-            173, 161, 109, 77, 123, 130, 138, 114, 103, 114, 148, 148, 224, 224, 224, 138, 138,
-            138, 207, 147, 122, 152, 217, 170, 250, 231, 157, 122, 195, 207, 214, 178, 161, 173,
-            224, 224, 224, 224, 224,
-        ],
-    ),
-    (
-        "Seti",
-        [
-            202, 206, 205, 202, 206, 205, 17, 18, 19, 50, 50, 50, 194, 40, 50, 142, 196, 61, 224,
-            198, 79, 67, 165, 213, 139, 87, 181, 142, 196, 61, 238, 238, 238, 50, 50, 50, 194, 40,
-            50, 142, 196, 61, 224, 198, 79, 67, 165, 213, 139, 87, 181, 142, 196, 61, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Shaman",
-        [
-            64, 85, 85, 64, 85, 85, 0, 16, 21, 1, 32, 38, 178, 48, 45, 0, 169, 65, 94, 139, 170,
-            68, 154, 134, 0, 89, 157, 93, 126, 25, 64, 85, 85, 56, 68, 81, 255, 66, 66, 42, 234,
-            94, 142, 212, 253, 97, 213, 186, 18, 152, 255, 152, 208, 40, 88, 251, 214,
-        ],
-    ),
-    (
-        "Shel",
-        [
-            72, 130, 205, 72, 130, 205, 42, 32, 31, 44, 36, 35, 171, 36, 99, 108, 163, 35, 171,
-            100, 35, 44, 100, 162, 108, 36, 162, 44, 163, 99, 145, 137, 136, 145, 137, 136, 245,
-            136, 185, 194, 238, 134, 245, 186, 134, 143, 186, 236, 194, 136, 236, 143, 238, 185,
-            245, 238, 236,
-        ],
-    ),
-    (
-        "Slate",
-        [
-            53, 177, 210, 53, 177, 210, 34, 34, 34, 34, 34, 34, 226, 168, 191, 129, 215, 120, 196,
-            201, 192, 38, 75, 73, 164, 129, 211, 21, 171, 156, 2, 197, 224, 255, 255, 255, 255,
-            205, 217, 190, 255, 168, 208, 204, 202, 122, 176, 210, 197, 167, 217, 140, 223, 224,
-            224, 224, 224,
-        ],
-    ),
-    (
-        "Smyck",
-        [
-            247, 247, 247, 247, 247, 247, 36, 36, 36, 0, 0, 0, 199, 86, 70, 142, 179, 59, 208, 176,
-            60, 114, 179, 204, 200, 160, 209, 33, 134, 147, 176, 176, 176, 93, 93, 93, 224, 150,
-            144, 205, 238, 105, 255, 227, 119, 156, 217, 240, 251, 177, 249, 119, 223, 216, 247,
-            247, 247,
-        ],
-    ),
-    (
-        "Snazzy",
-        [
-            151, 151, 155, 239, 240, 235, 40, 42, 54, 40, 42, 54, 255, 92, 87, 90, 247, 142, 243,
-            249, 157, 87, 199, 255, 255, 106, 193, 154, 237, 254, 241, 241, 240, 104, 104, 104,
-            255, 92, 87, 90, 247, 142, 243, 249, 157, 87, 199, 255, 255, 106, 193, 154, 237, 254,
-            239, 240, 235,
-        ],
-    ),
-    (
-        "Soft Server",
-        [
-            153, 163, 162, 153, 163, 162, 36, 38, 38, 0, 0, 0, 162, 104, 106, 154, 165, 106, 163,
-            144, 106, 107, 143, 163, 106, 113, 163, 107, 165, 143, 153, 163, 162, 102, 108, 108,
-            221, 92, 96, 191, 223, 85, 222, 179, 96, 98, 177, 223, 96, 110, 223, 100, 227, 156,
-            210, 224, 222,
-        ],
-    ),
-    (
-        "Solarized Darcula",
-        [
-            210, 216, 217, 210, 216, 217, 61, 63, 65, 37, 41, 42, 242, 72, 64, 98, 150, 85, 182,
-            136, 0, 32, 117, 199, 121, 127, 212, 21, 150, 141, 210, 216, 217, 37, 41, 42, 242, 72,
-            64, 98, 150, 85, 182, 136, 0, 32, 117, 199, 121, 127, 212, 21, 150, 141, 210, 216, 217,
-        ],
-    ),
-    (
-        "Solarized Dark",
-        [
-            131, 148, 150, 131, 148, 150, 0, 43, 54, 7, 54, 66, 220, 50, 47, 133, 153, 0, 207, 154,
-            107, 38, 139, 210, 211, 54, 130, 42, 161, 152, 238, 232, 213, 101, 123, 131, 216, 121,
-            121, 136, 207, 118, 101, 123, 131, 38, 153, 255, 211, 54, 130, 67, 184, 195, 253, 246,
-            227,
-        ],
-    ),
-    (
-        "Solarized Dark Higher Contrast",
-        [
-            156, 194, 195, 156, 194, 195, 0, 30, 39, 0, 40, 49, 209, 28, 36, 108, 190, 108, 165,
-            119, 6, 33, 118, 199, 198, 28, 111, 37, 146, 134, 234, 227, 203, 0, 100, 136, 245, 22,
-            59, 81, 239, 132, 178, 126, 40, 23, 142, 200, 226, 77, 142, 0, 179, 158, 252, 244, 220,
-        ],
-    ),
-    (
-        "Solarized Light",
-        [
-            101, 123, 131, 101, 123, 131, 253, 246, 227, 7, 54, 66, 220, 50, 47, 133, 153, 0, 181,
-            137, 0, 38, 139, 210, 211, 54, 130, 42, 161, 152, 238, 232, 213, 0, 43, 54, 203, 75,
-            22, 88, 110, 117, 101, 123, 131, 131, 148, 150, 108, 113, 196, 147, 161, 161, 253, 246,
-            227,
-        ],
-    ),
-    (
-        "Sonokai",
-        [
-            226, 226, 227, 226, 226, 227, 44, 46, 52, 44, 46, 52, 252, 93, 124, 158, 208, 114, 231,
-            198, 100, 243, 150, 96, 179, 157, 243, 118, 204, 224, 226, 226, 227, 127, 132, 144,
-            252, 93, 124, 158, 208, 114, 231, 198, 100, 243, 150, 96, 179, 157, 243, 118, 204, 224,
-            226, 226, 227,
-        ],
-    ),
-    (
-        "Spacedust",
-        [
-            236, 240, 193, 236, 240, 193, 10, 30, 36, 110, 83, 70, 227, 91, 0, 92, 171, 150, 227,
-            205, 123, 15, 84, 139, 227, 91, 0, 6, 175, 199, 240, 241, 206, 104, 76, 49, 255, 138,
-            58, 174, 202, 184, 255, 200, 120, 103, 160, 206, 255, 138, 58, 131, 167, 180, 254, 255,
-            241,
-        ],
-    ),
-    (
-        "Spacegray",
-        [
-            179, 184, 195, 179, 184, 195, 32, 36, 45, 0, 0, 0, 176, 75, 87, 135, 179, 121, 229,
-            193, 121, 125, 143, 164, 164, 121, 150, 133, 167, 165, 179, 184, 195, 0, 0, 0, 176, 75,
-            87, 135, 179, 121, 229, 193, 121, 125, 143, 164, 164, 121, 150, 133, 167, 165, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Spacegray Eighties",
-        [
-            189, 186, 174, 189, 186, 174, 34, 34, 34, 21, 23, 28, 236, 95, 103, 129, 167, 100, 254,
-            194, 84, 84, 134, 192, 191, 131, 193, 87, 194, 193, 239, 236, 231, 85, 85, 85, 255,
-            105, 115, 147, 212, 147, 255, 210, 86, 77, 132, 209, 255, 85, 255, 131, 233, 228, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Spacegray Eighties Dull",
-        [
-            201, 198, 188, 201, 198, 188, 34, 34, 34, 21, 23, 28, 178, 74, 86, 146, 180, 119, 198,
-            115, 90, 124, 143, 165, 165, 120, 158, 128, 205, 203, 179, 184, 195, 85, 85, 85, 236,
-            95, 103, 137, 233, 134, 254, 194, 84, 84, 134, 192, 191, 131, 193, 88, 194, 193, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Spring",
-        [
-            236, 240, 193, 236, 240, 193, 10, 30, 36, 0, 0, 0, 255, 77, 131, 31, 140, 59, 31, 201,
-            91, 29, 211, 238, 137, 89, 168, 62, 153, 159, 255, 255, 255, 0, 0, 0, 255, 0, 33, 31,
-            194, 49, 213, 184, 7, 21, 169, 253, 137, 89, 168, 62, 153, 159, 255, 255, 255,
-        ],
-    ),
-    (
-        "Square",
-        [
-            161, 161, 161, 161, 161, 161, 10, 30, 36, 5, 5, 5, 233, 137, 124, 182, 55, 125, 236,
-            235, 190, 169, 205, 235, 117, 80, 123, 201, 202, 236, 242, 242, 242, 20, 20, 20, 249,
-            146, 134, 195, 247, 134, 252, 251, 204, 182, 222, 251, 173, 127, 168, 215, 217, 252,
-            226, 226, 226,
-        ],
-    ),
-    (
-        "Srcery",
-        [
-            251, 184, 41, 252, 232, 195, 28, 27, 25, 28, 27, 25, 239, 47, 39, 81, 159, 80, 251,
-            184, 41, 44, 120, 191, 224, 44, 109, 10, 174, 179, 186, 166, 127, 145, 129, 117, 247,
-            83, 65, 152, 188, 55, 254, 208, 110, 104, 168, 228, 255, 92, 143, 43, 228, 208, 252,
-            232, 195,
-        ],
-    ),
-    (
-        "Summer Pop",
-        [
-            255, 255, 255, 255, 255, 255, 39, 40, 34, 102, 102, 102, 255, 30, 142, 142, 255, 30,
-            255, 251, 0, 30, 142, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229, 102, 102, 102, 255,
-            30, 142, 142, 255, 30, 255, 251, 0, 30, 142, 255, 229, 0, 229, 0, 229, 229, 229, 229,
-            229,
-        ],
-    ),
-    (
-        "Sundried",
-        [
-            201, 201, 201, 201, 201, 201, 26, 24, 24, 48, 43, 42, 167, 70, 61, 88, 119, 68, 157,
-            96, 42, 72, 91, 152, 134, 70, 81, 156, 129, 79, 201, 201, 201, 77, 78, 72, 170, 0, 12,
-            18, 140, 33, 252, 106, 33, 121, 153, 247, 253, 138, 161, 250, 212, 132, 255, 255, 255,
-        ],
-    ),
-    (
-        "Sweet Eliverlara",
-        [
-            195, 199, 209, 195, 199, 209, 40, 44, 52, 40, 44, 52, 237, 37, 78, 113, 247, 159, 249,
-            220, 92, 124, 183, 255, 199, 77, 237, 0, 193, 228, 220, 223, 228, 40, 44, 52, 237, 37,
-            78, 113, 247, 159, 249, 220, 92, 124, 183, 255, 199, 77, 237, 0, 193, 228, 220, 223,
-            228,
-        ],
-    ),
-    (
-        "Sweet Terminal",
-        [
-            255, 255, 255, 255, 255, 255, 34, 34, 53, 63, 63, 84, 246, 0, 85, 6, 201, 147, 151, 0,
-            190, 246, 145, 84, 236, 137, 203, 96, 173, 236, 171, 178, 191, 149, 157, 203, 246, 0,
-            85, 6, 201, 147, 151, 0, 190, 246, 145, 84, 236, 137, 203, 0, 221, 237, 255, 255, 255,
-        ],
-    ),
-    (
-        "Symphonic",
-        [
-            255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 220, 50, 47, 86, 219, 58, 255, 132, 0,
-            0, 132, 212, 183, 41, 217, 204, 204, 255, 255, 255, 255, 27, 29, 33, 220, 50, 47, 86,
-            219, 58, 255, 132, 0, 0, 132, 212, 183, 41, 217, 204, 204, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Synthwave",
-        [
-            3, 237, 249, 255, 255, 255, 38, 35, 53, 1, 22, 39, 254, 68, 80, 114, 241, 184, 254,
-            222, 93, 3, 237, 249, 255, 126, 219, 3, 237, 249, 255, 255, 255, 87, 86, 86, 254, 68,
-            80, 114, 241, 184, 254, 222, 93, 3, 237, 249, 255, 126, 219, 3, 237, 249, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Synthwave Alpha",
-        [
-            242, 242, 227, 242, 242, 227, 36, 27, 48, 36, 27, 48, 154, 0, 72, 0, 152, 108, 173,
-            173, 62, 110, 41, 173, 179, 0, 173, 0, 176, 177, 185, 177, 188, 127, 112, 148, 230, 10,
-            112, 10, 228, 164, 249, 249, 114, 170, 84, 249, 255, 0, 246, 0, 251, 253, 242, 242,
-            227,
-        ],
-    ),
-    (
-        "Teerb",
-        [
-            208, 208, 208, 208, 208, 208, 38, 38, 38, 28, 28, 28, 214, 134, 134, 174, 214, 134,
-            215, 175, 135, 134, 174, 214, 214, 174, 214, 138, 219, 180, 208, 208, 208, 28, 28, 28,
-            214, 134, 134, 174, 214, 134, 228, 201, 175, 134, 174, 214, 214, 174, 214, 177, 231,
-            221, 239, 239, 239,
-        ],
-    ),
-    (
-        "Tender",
-        [
-            238, 238, 238, 238, 238, 238, 40, 40, 40, 29, 29, 29, 197, 21, 47, 201, 208, 92, 255,
-            194, 75, 179, 222, 239, 211, 185, 135, 115, 206, 244, 238, 238, 238, 50, 50, 50, 244,
-            55, 83, 217, 224, 102, 250, 204, 114, 192, 234, 251, 239, 208, 147, 161, 214, 236, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Terminal Basic",
-        [
-            127, 127, 127, 0, 0, 0, 255, 255, 255, 0, 0, 0, 153, 0, 0, 0, 166, 0, 153, 153, 0, 0,
-            0, 178, 178, 0, 178, 0, 166, 178, 191, 191, 191, 102, 102, 102, 229, 0, 0, 0, 217, 0,
-            229, 229, 0, 0, 0, 255, 229, 0, 229, 0, 229, 229, 229, 229, 229,
-        ],
-    ),
-    (
-        "Terminix Dark",
-        [
-            134, 138, 140, 134, 138, 140, 9, 17, 22, 40, 42, 46, 165, 66, 66, 161, 181, 108, 222,
-            147, 95, 34, 85, 85, 133, 103, 143, 94, 141, 135, 119, 119, 119, 55, 59, 65, 198, 53,
-            53, 96, 131, 96, 250, 128, 90, 68, 157, 161, 186, 139, 175, 134, 193, 185, 197, 200,
-            198,
-        ],
-    ),
-    (
-        "Thayer Bright",
-        [
-            248, 248, 248, 248, 248, 248, 27, 29, 30, 27, 29, 30, 249, 38, 114, 77, 248, 64, 244,
-            253, 34, 39, 87, 214, 140, 84, 254, 56, 200, 181, 204, 204, 198, 80, 83, 84, 255, 89,
-            149, 182, 227, 84, 254, 237, 108, 63, 120, 255, 158, 111, 254, 35, 207, 213, 248, 248,
-            242,
-        ],
-    ),
-    (
-        "Tin",
-        [
-            255, 255, 255, 255, 255, 255, 46, 46, 53, 0, 0, 0, 141, 83, 78, 78, 141, 83, 136, 141,
-            78, 83, 78, 141, 141, 78, 136, 78, 136, 141, 255, 255, 255, 0, 0, 0, 181, 125, 120,
-            120, 181, 125, 176, 181, 120, 125, 120, 181, 181, 120, 176, 120, 176, 181, 255, 255,
-            255,
-        ],
-    ),
-    (
-        "Tokyo Night",
-        [
-            192, 202, 245, 192, 202, 245, 26, 27, 38, 65, 72, 104, 247, 118, 142, 158, 206, 106,
-            224, 175, 104, 122, 162, 247, 187, 154, 247, 125, 207, 255, 169, 177, 214, 65, 72, 104,
-            247, 118, 142, 158, 206, 106, 224, 175, 104, 122, 162, 247, 187, 154, 247, 125, 207,
-            255, 192, 202, 245,
-        ],
-    ),
-    (
-        "Tokyo Night Light",
-        [
-            86, 90, 110, 86, 90, 110, 213, 214, 219, 15, 15, 20, 140, 67, 81, 72, 94, 48, 143, 94,
-            21, 52, 84, 138, 90, 74, 120, 15, 75, 110, 52, 59, 88, 150, 153, 163, 140, 67, 81, 72,
-            94, 48, 143, 94, 21, 52, 84, 138, 90, 74, 120, 15, 75, 110, 52, 59, 88,
-        ],
-    ),
-    (
-        "Tokyo Night Storm",
-        [
-            192, 202, 245, 192, 202, 245, 36, 40, 59, 65, 72, 104, 247, 118, 142, 158, 206, 106,
-            224, 175, 104, 122, 162, 247, 187, 154, 247, 125, 207, 255, 192, 202, 245, 65, 72, 104,
-            247, 118, 142, 158, 206, 106, 224, 175, 104, 122, 162, 247, 187, 154, 247, 125, 207,
-            255, 192, 202, 245,
-        ],
-    ),
-    (
-        "Tomorrow",
-        [
-            76, 76, 76, 77, 77, 76, 255, 255, 255, 0, 0, 0, 200, 40, 40, 113, 140, 0, 234, 183, 0,
-            65, 113, 174, 137, 89, 168, 62, 153, 159, 255, 254, 254, 0, 0, 0, 200, 40, 40, 112,
-            139, 0, 233, 182, 0, 65, 112, 174, 137, 88, 167, 61, 153, 159, 255, 254, 254,
-        ],
-    ),
-    (
-        "Tomorrow Night",
-        [
-            196, 200, 197, 197, 200, 198, 29, 31, 33, 0, 0, 0, 204, 102, 102, 181, 189, 104, 240,
-            198, 116, 129, 162, 190, 178, 147, 187, 138, 190, 183, 255, 254, 254, 0, 0, 0, 204,
-            102, 102, 181, 189, 104, 240, 197, 116, 128, 161, 189, 178, 148, 186, 138, 189, 182,
-            255, 254, 254,
-        ],
-    ),
-    (
-        "Tomorrow Night Blue",
-        [
-            255, 254, 254, 255, 254, 254, 0, 36, 81, 0, 0, 0, 255, 157, 163, 209, 241, 169, 255,
-            238, 173, 187, 218, 255, 235, 187, 255, 153, 255, 255, 255, 254, 254, 0, 0, 0, 255,
-            156, 163, 208, 240, 168, 255, 237, 172, 186, 218, 255, 235, 186, 255, 153, 255, 255,
-            255, 254, 254,
-        ],
-    ),
-    (
-        "Tomorrow Night Bright",
-        [
-            233, 233, 233, 233, 233, 233, 0, 0, 0, 0, 0, 0, 213, 78, 83, 185, 202, 73, 231, 197,
-            71, 121, 166, 218, 195, 151, 216, 112, 192, 177, 255, 254, 254, 0, 0, 0, 212, 77, 83,
-            185, 201, 73, 230, 196, 70, 121, 166, 218, 195, 150, 215, 112, 192, 177, 255, 254, 254,
-        ],
-    ),
-    (
-        "Tomorrow Night Eighties",
-        [
-            204, 204, 204, 204, 204, 204, 44, 44, 44, 0, 0, 0, 242, 119, 121, 153, 204, 153, 255,
-            204, 102, 102, 153, 204, 204, 153, 204, 102, 204, 204, 255, 254, 254, 0, 0, 0, 241,
-            119, 121, 153, 204, 153, 255, 204, 102, 102, 153, 204, 204, 153, 204, 102, 204, 204,
-            255, 254, 254,
-        ],
-    ),
-    (
-        "Toy Chest",
-        [
-            49, 208, 123, 49, 208, 123, 36, 54, 75, 44, 63, 88, 190, 45, 38, 26, 145, 114, 219,
-            142, 39, 50, 93, 150, 138, 94, 220, 53, 160, 143, 35, 209, 131, 51, 104, 137, 221, 89,
-            68, 49, 208, 123, 231, 216, 75, 52, 166, 218, 174, 107, 220, 66, 195, 174, 213, 213,
-            213,
-        ],
-    ),
-    (
-        "Treehouse",
-        [
-            120, 107, 83, 120, 107, 83, 25, 25, 25, 50, 19, 0, 178, 39, 14, 68, 169, 0, 170, 130,
-            12, 88, 133, 154, 151, 54, 61, 178, 90, 30, 120, 107, 83, 67, 54, 38, 237, 93, 32, 85,
-            242, 56, 242, 183, 50, 133, 207, 237, 225, 76, 90, 240, 125, 20, 255, 200, 0,
-        ],
-    ),
-    (
-        "Twilight",
-        [
-            255, 255, 212, 255, 255, 212, 20, 20, 20, 20, 20, 20, 192, 109, 68, 175, 185, 122, 194,
-            168, 108, 68, 71, 74, 180, 190, 124, 119, 131, 133, 255, 255, 212, 38, 38, 38, 222,
-            124, 76, 204, 216, 140, 226, 196, 126, 90, 94, 98, 208, 220, 142, 138, 152, 155, 255,
-            255, 212,
-        ],
-    ),
-    (
-        "Ura",
-        [
-            35, 71, 106, 35, 71, 106, 254, 255, 238, 0, 0, 0, 194, 27, 111, 111, 194, 27, 194, 111,
-            27, 27, 111, 194, 111, 27, 194, 27, 194, 111, 128, 128, 128, 128, 128, 128, 238, 132,
-            185, 185, 238, 132, 238, 185, 132, 132, 185, 238, 185, 132, 238, 132, 238, 185, 229,
-            229, 229,
-        ],
-    ),
-    (
-        "Urple",
-        [
-            135, 122, 155, 135, 122, 155, 27, 27, 35, 0, 0, 0, 176, 66, 91, 55, 164, 21, 173, 92,
-            66, 86, 77, 155, 108, 60, 161, 128, 128, 128, 135, 121, 156, 93, 50, 37, 255, 99, 136,
-            41, 230, 32, 240, 129, 97, 134, 122, 237, 160, 94, 238, 234, 234, 234, 191, 163, 255,
-        ],
-    ),
-    (
-        "Vag",
-        [
-            217, 230, 242, 217, 230, 242, 25, 31, 29, 48, 48, 48, 168, 113, 57, 57, 168, 113, 113,
-            168, 57, 113, 57, 168, 168, 57, 113, 57, 113, 168, 138, 138, 138, 73, 73, 73, 176, 118,
-            59, 59, 176, 118, 118, 176, 59, 118, 59, 176, 176, 59, 118, 59, 118, 176, 207, 207,
-            207,
-        ],
-    ),
-    (
-        "Vaughn",
-        [
-            220, 220, 204, 220, 220, 204, 37, 35, 79, 37, 35, 79, 112, 80, 80, 96, 180, 138, 223,
-            175, 143, 85, 85, 255, 240, 140, 195, 140, 208, 211, 112, 144, 128, 112, 144, 128, 220,
-            163, 163, 96, 180, 138, 240, 223, 175, 85, 85, 255, 236, 147, 211, 147, 224, 227, 255,
-            255, 255,
-        ],
-    ),
-    (
-        "Vibrant Ink",
-        [
-            255, 255, 255, 255, 255, 255, 0, 0, 0, 135, 135, 135, 255, 102, 0, 204, 255, 4, 255,
-            204, 0, 68, 180, 204, 153, 51, 204, 68, 180, 204, 245, 245, 245, 85, 85, 85, 255, 0, 0,
-            0, 255, 0, 255, 255, 0, 0, 0, 255, 255, 0, 255, 0, 255, 255, 229, 229, 229,
-        ],
-    ),
-    (
-        "Vs Code Dark+",
-        [
-            204, 204, 204, 204, 204, 204, 30, 30, 30, 106, 120, 122, 233, 101, 59, 57, 233, 168,
-            229, 182, 132, 68, 170, 230, 225, 117, 153, 61, 213, 231, 195, 221, 225, 89, 132, 137,
-            230, 80, 41, 0, 255, 154, 232, 148, 64, 0, 154, 251, 255, 87, 143, 95, 255, 255, 217,
-            251, 255,
-        ],
-    ),
-    (
-        "Vs Code Light+",
-        [
-            2, 2, 2, 2, 2, 2, 249, 249, 249, 2, 2, 2, 205, 50, 50, 0, 188, 0, 165, 169, 0, 7, 82,
-            168, 188, 5, 188, 5, 152, 188, 52, 52, 52, 94, 94, 94, 205, 51, 51, 27, 206, 26, 173,
-            187, 91, 7, 82, 168, 196, 81, 206, 82, 168, 199, 166, 163, 166,
-        ],
-    ),
-    (
-        "Warm Neon",
-        [
-            175, 218, 182, 175, 218, 182, 64, 64, 64, 0, 0, 0, 226, 67, 70, 57, 177, 58, 218, 225,
-            69, 66, 97, 197, 249, 32, 251, 42, 187, 212, 208, 184, 163, 254, 252, 252, 233, 112,
-            113, 156, 192, 144, 221, 218, 122, 123, 145, 214, 246, 116, 186, 94, 209, 229, 216,
-            200, 187,
-        ],
-    ),
-    (
-        "Wez",
-        [
-            179, 179, 179, 179, 179, 179, 0, 0, 0, 0, 0, 0, 204, 85, 85, 85, 204, 85, 205, 205, 85,
-            85, 85, 204, 204, 85, 204, 122, 202, 202, 204, 204, 204, 85, 85, 85, 255, 85, 85, 85,
-            255, 85, 255, 255, 85, 85, 85, 255, 255, 85, 255, 85, 255, 255, 255, 255, 255,
-        ],
-    ),
-    (
-        "Wild Cherry",
-        [
-            218, 250, 255, 218, 250, 255, 31, 23, 38, 0, 5, 7, 217, 64, 133, 42, 178, 80, 255, 209,
-            111, 136, 60, 220, 236, 236, 236, 193, 184, 183, 255, 248, 222, 0, 156, 201, 218, 107,
-            172, 244, 220, 165, 234, 192, 102, 48, 140, 186, 174, 99, 107, 255, 145, 157, 228, 131,
-            141,
-        ],
-    ),
-    (
-        "Wombat",
-        [
-            222, 218, 207, 222, 218, 207, 23, 23, 23, 0, 0, 0, 255, 97, 90, 177, 233, 105, 235,
-            217, 156, 93, 169, 246, 232, 106, 255, 130, 255, 247, 222, 218, 207, 49, 49, 49, 245,
-            140, 128, 221, 248, 143, 238, 229, 178, 165, 199, 255, 221, 170, 255, 183, 255, 249,
-            255, 255, 255,
-        ],
-    ),
-    (
-        "Wryan",
-        [
-            153, 153, 147, 153, 153, 147, 16, 16, 16, 51, 51, 51, 140, 70, 101, 40, 115, 115, 124,
-            124, 153, 57, 85, 115, 94, 70, 140, 49, 101, 140, 137, 156, 161, 61, 61, 61, 191, 77,
-            128, 83, 166, 166, 158, 158, 203, 71, 122, 179, 126, 98, 179, 96, 150, 191, 192, 192,
-            192,
-        ],
-    ),
-    (
-        "Wzoreck",
-        [
-            252, 252, 250, 252, 252, 250, 66, 64, 67, 46, 52, 54, 252, 99, 134, 66, 64, 67, 252,
-            233, 79, 251, 151, 107, 117, 80, 123, 52, 226, 226, 255, 255, 255, 152, 149, 149, 252,
-            99, 134, 169, 220, 118, 252, 233, 79, 251, 151, 107, 171, 157, 242, 52, 226, 226, 209,
-            209, 192,
-        ],
-    ),
-    (
-        "Zenburn",
-        [
-            220, 220, 204, 220, 220, 204, 63, 63, 63, 77, 77, 77, 112, 80, 80, 96, 180, 138, 240,
-            223, 175, 80, 96, 112, 220, 140, 195, 140, 208, 211, 220, 220, 204, 112, 144, 128, 220,
-            163, 163, 195, 191, 159, 224, 207, 159, 148, 191, 243, 236, 147, 211, 147, 224, 227,
-            255, 255, 255,
-        ],
-    ),
-];
+//! The built-in themes, generated by `build.rs` from the sources in `themes/`.
+
+use std::borrow::Cow;
+
+use crate::color::Rgb;
+use crate::theme::Theme;
+
+include!(concat!(env!("OUT_DIR"), "/themes.rs"));
@@ -0,0 +1,245 @@
+use std::fmt::Write;
+
+/// The number of unchanged lines shown around each change.
+const CONTEXT: usize = 3;
+
+/// A line in an edit script, including its newline if it has one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit<'a> {
+    /// A line present in both texts.
+    Keep(&'a str),
+    /// A line only present in the old text.
+    Delete(&'a str),
+    /// A line only present in the new text.
+    Insert(&'a str),
+}
+
+/// Returns a unified diff of `old` and `new`, or an empty string if they are
+/// identical.
+///
+/// Lines are prefixed with the given ANSI `colors` for removals, additions
+/// and hunk headers, in that order, followed by `clear`.
+pub fn unified_diff(
+    old: &str,
+    new: &str,
+    old_label: &str,
+    new_label: &str,
+    colors: [&str; 3],
+    clear: &str,
+) -> String {
+    let edits = edit_script(old, new);
+
+    if edits.iter().all(|edit| matches!(edit, Edit::Keep(_))) {
+        return String::new();
+    }
+
+    let [del_color, ins_color, hunk_color] = colors;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "{del_color}--- {old_label}{clear}");
+    let _ = writeln!(out, "{ins_color}+++ {new_label}{clear}");
+
+    for (start, end) in hunks(&edits) {
+        // Line numbers of the hunk's first line in each text.
+        let old_start = 1 + edits[..start]
+            .iter()
+            .filter(|e| !matches!(e, Edit::Insert(_)))
+            .count();
+        let new_start = 1 + edits[..start]
+            .iter()
+            .filter(|e| !matches!(e, Edit::Delete(_)))
+            .count();
+
+        let hunk = &edits[start..end];
+        let old_len = hunk
+            .iter()
+            .filter(|e| !matches!(e, Edit::Insert(_)))
+            .count();
+        let new_len = hunk
+            .iter()
+            .filter(|e| !matches!(e, Edit::Delete(_)))
+            .count();
+
+        let _ = writeln!(
+            out,
+            "{hunk_color}@@ -{} +{} @@{clear}",
+            range(old_start, old_len),
+            range(new_start, new_len)
+        );
+
+        for edit in hunk {
+            match edit {
+                Edit::Keep(line) => write_line(&mut out, " ", line, ["", ""]),
+                Edit::Delete(line) => write_line(&mut out, "-", line, [del_color, clear]),
+                Edit::Insert(line) => write_line(&mut out, "+", line, [ins_color, clear]),
+            }
+        }
+    }
+
+    out
+}
+
+// Writes a line of a hunk between the given color and clear sequences. A
+// last line without a newline is marked as such, so that a diff that only
+// adds or removes the final newline still shows a change.
+fn write_line(out: &mut String, prefix: &str, line: &str, [color, clear]: [&str; 2]) {
+    let _ = match line.strip_suffix('\n') {
+        Some(line) => writeln!(out, "{color}{prefix}{line}{clear}"),
+        None => writeln!(
+            out,
+            "{color}{prefix}{line}{clear}\n\\ No newline at end of file"
+        ),
+    };
+}
+
+// Formats a hunk range, which by convention omits a length of 1 and starts
+// an empty range on the line before it.
+fn range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{},0", start - 1),
+        1 => start.to_string(),
+        _ => format!("{start},{len}"),
+    }
+}
+
+// Computes the shortest edit script turning `old` into `new` from a table of
+// longest common subsequence lengths. Settings files are small, so the
+// quadratic table is not a concern.
+//
+// Lines keep their line endings, so that changing only a newline or a
+// carriage return is still a change.
+fn edit_script<'a>(old: &'a str, new: &'a str) -> Vec<Edit<'a>> {
+    let old = old.split_inclusive('\n').collect::<Vec<&str>>();
+    let new = new.split_inclusive('\n').collect::<Vec<&str>>();
+
+    // lcs[i][j] is the LCS length of old[i..] and new[j..].
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            edits.push(Edit::Keep(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            edits.push(Edit::Delete(old[i]));
+            i += 1;
+        } else {
+            edits.push(Edit::Insert(new[j]));
+            j += 1;
+        }
+    }
+
+    edits.extend(old[i..].iter().map(|line| Edit::Delete(line)));
+    edits.extend(new[j..].iter().map(|line| Edit::Insert(line)));
+
+    edits
+}
+
+// Groups the changes in an edit script into hunks with surrounding context,
+// returning the index range of each hunk.
+fn hunks(edits: &[Edit<'_>]) -> Vec<(usize, usize)> {
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+
+    for (idx, edit) in edits.iter().enumerate() {
+        if matches!(edit, Edit::Keep(_)) {
+            continue;
+        }
+
+        let start = idx.saturating_sub(CONTEXT);
+        let end = (idx + 1 + CONTEXT).min(edits.len());
+
+        match hunks.last_mut() {
+            // Merge hunks whose context overlaps or touches.
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(old: &str, new: &str) -> String {
+        unified_diff(old, new, "a", "b", ["", "", ""], "")
+    }
+
+    #[test]
+    fn identical_input_has_no_diff() {
+        assert_eq!(diff("", ""), "");
+        assert_eq!(diff("a\nb\n", "a\nb\n"), "");
+    }
+
+    #[test]
+    fn insert_only() {
+        assert_eq!(
+            diff("a\nb\n", "a\nx\nb\n"),
+            "--- a\n+++ b\n@@ -1,2 +1,3 @@\n a\n+x\n b\n"
+        );
+        assert_eq!(diff("", "x\n"), "--- a\n+++ b\n@@ -0,0 +1 @@\n+x\n");
+    }
+
+    #[test]
+    fn delete_only() {
+        assert_eq!(
+            diff("a\nx\nb\n", "a\nb\n"),
+            "--- a\n+++ b\n@@ -1,3 +1,2 @@\n a\n-x\n b\n"
+        );
+        assert_eq!(diff("x\n", ""), "--- a\n+++ b\n@@ -1 +0,0 @@\n-x\n");
+    }
+
+    #[test]
+    fn nearby_changes_share_a_hunk() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\nn\no\np\n";
+
+        // Six unchanged lines between changes are covered by both contexts.
+        let merged = diff(old, &old.replace("b\n", "x\n").replace("i\n", "y\n"));
+        assert_eq!(merged.matches("@@ -").count(), 1, "{merged}");
+        assert!(merged.contains("@@ -1,12 +1,12 @@"), "{merged}");
+
+        // Seven aren't.
+        let split = diff(old, &old.replace("b\n", "x\n").replace("j\n", "y\n"));
+        assert_eq!(split.matches("@@ -").count(), 2, "{split}");
+        assert!(split.contains("@@ -1,5 +1,5 @@"), "{split}");
+        assert!(split.contains("@@ -7,7 +7,7 @@"), "{split}");
+    }
+
+    #[test]
+    fn missing_final_newline_is_a_change() {
+        assert_eq!(
+            diff("a\nb\n", "a\nb"),
+            "--- a\n+++ b\n@@ -1,2 +1,2 @@\n a\n-b\n+b\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn line_endings_are_a_change() {
+        assert_eq!(
+            diff("a\n", "a\r\n"),
+            "--- a\n+++ b\n@@ -1 +1 @@\n-a\n+a\r\n"
+        );
+    }
+
+    #[test]
+    fn range_omits_a_length_of_one() {
+        assert_eq!(range(5, 0), "4,0");
+        assert_eq!(range(1, 0), "0,0");
+        assert_eq!(range(5, 1), "5");
+        assert_eq!(range(5, 3), "5,3");
+    }
+}
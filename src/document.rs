@@ -0,0 +1,318 @@
+//! Themux's own TOML files, read with the `toml` crate into tables of
+//! entries that remember the line they are on, for error messages.
+
+use std::fmt::Write as _;
+
+use toml::de::{DeTable, DeValue, Error};
+use toml::Value;
+
+/// A key and its value.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub key: String,
+    pub value: Value,
+    /// The 1-based line the key is on.
+    pub line: usize,
+}
+
+impl Entry {
+    /// Returns the value as a string, or an error naming the key.
+    pub fn as_str(&self) -> Result<&str, String> {
+        match &self.value {
+            Value::String(s) => Ok(s),
+            value => Err(self.type_error("a string", value)),
+        }
+    }
+
+    /// Returns the value as an integer, or an error naming the key.
+    pub fn as_integer(&self) -> Result<i64, String> {
+        match self.value {
+            Value::Integer(n) => Ok(n),
+            ref value => Err(self.type_error("an integer", value)),
+        }
+    }
+
+    /// Returns the value as an array of strings, or an error naming the key.
+    pub fn as_strings(&self) -> Result<Vec<&str>, String> {
+        let Value::Array(values) = &self.value else {
+            return Err(self.type_error("an array of strings", &self.value));
+        };
+
+        values
+            .iter()
+            .map(|value| match value {
+                Value::String(s) => Ok(s.as_str()),
+                value => Err(self.type_error("an array of strings", value)),
+            })
+            .collect()
+    }
+
+    /// Returns an error for this entry.
+    pub fn error(&self, message: &str) -> String {
+        format!("line {}: {message}", self.line)
+    }
+
+    fn type_error(&self, expected: &str, value: &Value) -> String {
+        let actual = match value {
+            Value::String(_) => "a string",
+            Value::Integer(_) => "an integer",
+            Value::Float(_) => "a float",
+            Value::Boolean(_) => "a boolean",
+            Value::Datetime(_) => "a date",
+            Value::Array(_) => "an array",
+            Value::Table(_) => "a table",
+        };
+
+        self.error(&format!("`{}` must be {expected}, not {actual}", self.key))
+    }
+}
+
+/// A table: the entries under a `[name]` header, or before the first header
+/// for the root table, whose name is empty.
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    pub name: String,
+    /// The entries, in the order they appear.
+    pub entries: Vec<Entry>,
+    /// The 1-based line of the header, or 0 for the root table.
+    pub line: usize,
+}
+
+impl Table {
+    /// Returns the entry for a key.
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+
+    /// Returns an error for the first key not in `known`.
+    pub fn check_keys(&self, known: &[&str]) -> Result<(), String> {
+        self.entries
+            .iter()
+            .find(|entry| !known.contains(&entry.key.as_str()))
+            .map_or(Ok(()), |entry| {
+                Err(entry.error(&format!("unknown key `{}`", entry.key)))
+            })
+    }
+}
+
+/// A parsed file.
+#[derive(Clone, Debug)]
+pub struct Document {
+    /// The root table, then the other tables in the order they appear.
+    pub tables: Vec<Table>,
+}
+
+impl Document {
+    /// Parses a file's content.
+    ///
+    /// A table at the top level, whether written as a `[name]` header or
+    /// inline, is one of the document's tables. Tables nested deeper are
+    /// left as values.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let line_of = |offset: usize| content[..offset].matches('\n').count() + 1;
+
+        let parse_error = |e: Error| {
+            let message = e.message().trim();
+
+            e.span().map_or_else(
+                || message.to_string(),
+                |span| format!("line {}: {message}", line_of(span.start)),
+            )
+        };
+
+        // The crate's tables give the values, and its spanned tables where
+        // their keys are.
+        let spanned = DeTable::parse(content).map_err(parse_error)?.into_inner();
+        let values = content.parse::<toml::Table>().map_err(parse_error)?;
+
+        let mut root = Table::default();
+        let mut tables = Vec::new();
+
+        for (offset, key, value, spanned_value) in in_order(values, &spanned) {
+            let line = line_of(offset);
+
+            match (value, spanned_value) {
+                (Value::Table(table), Some(DeValue::Table(spanned_table))) => {
+                    let entries = in_order(table, spanned_table)
+                        .map(|(offset, key, value, _)| Entry {
+                            key,
+                            value,
+                            line: line_of(offset),
+                        })
+                        .collect();
+
+                    tables.push(Table {
+                        name: key,
+                        entries,
+                        line,
+                    });
+                }
+                (value, _) => root.entries.push(Entry { key, value, line }),
+            }
+        }
+
+        tables.insert(0, root);
+
+        Ok(Self { tables })
+    }
+
+    /// Returns the root table.
+    pub fn root(&self) -> &Table {
+        &self.tables[0]
+    }
+
+    /// Returns the table with the given name.
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables[1..].iter().find(|table| table.name == name)
+    }
+
+    /// Returns an error for the first table not in `known`. The root table
+    /// is always known.
+    pub fn check_tables(&self, known: &[&str]) -> Result<(), String> {
+        self.tables[1..]
+            .iter()
+            .find(|table| !known.contains(&table.name.as_str()))
+            .map_or(Ok(()), |table| {
+                Err(format!(
+                    "line {}: unknown table [{}]",
+                    table.line, table.name
+                ))
+            })
+    }
+}
+
+/// Returns a string quoted as a TOML basic string.
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04X}", u32::from(c));
+            }
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Returns a key, quoted if it isn't a valid bare key.
+pub fn key(key: &str) -> String {
+    let is_bare = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+
+    if !key.is_empty() && key.chars().all(is_bare) {
+        key.to_string()
+    } else {
+        quote(key)
+    }
+}
+
+// Returns a table's entries in the order they appear, each with the offset
+// of its key and its value in the spanned table.
+fn in_order<'a>(
+    table: toml::Table,
+    spanned: &'a DeTable<'_>,
+) -> impl Iterator<Item = (usize, String, Value, Option<&'a DeValue<'a>>)> {
+    let mut entries = table
+        .into_iter()
+        .map(|(key, value)| {
+            let spanned = spanned.iter().find(|(k, _)| k.get_ref() == &key);
+            let offset = spanned.map_or(0, |(k, _)| k.span().start);
+
+            (offset, key, value, spanned.map(|(_, v)| v.get_ref()))
+        })
+        .collect::<Vec<_>>();
+
+    entries.sort_by_key(|(offset, ..)| *offset);
+    entries.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_and_entries_keep_their_order_and_lines() {
+        let doc = Document::parse(
+            "b = 1\na = \"x\"\n\n[second]\nz = [\"p\", \"q\"]\ny = true\n\n[first]\n",
+        )
+        .unwrap();
+
+        let names = doc
+            .tables
+            .iter()
+            .map(|table| (table.name.as_str(), table.line))
+            .collect::<Vec<_>>();
+        assert_eq!(names, [("", 0), ("second", 4), ("first", 8)]);
+
+        let root = doc.root();
+        assert_eq!(root.entries[0].key, "b");
+        assert_eq!(root.entries[1].key, "a");
+        assert_eq!(root.get("a").unwrap().line, 2);
+
+        let second = doc.table("second").unwrap();
+        assert_eq!(second.get("z").unwrap().as_strings().unwrap(), ["p", "q"]);
+        assert_eq!(second.entries[1].line, 6);
+    }
+
+    #[test]
+    fn inline_tables_are_tables() {
+        let doc = Document::parse("hooks = { timeout = 5 }\n").unwrap();
+
+        assert_eq!(
+            doc.table("hooks")
+                .unwrap()
+                .get("timeout")
+                .unwrap()
+                .as_integer(),
+            Ok(5)
+        );
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        assert_eq!(
+            Document::parse("a = 1\nb = \n").unwrap_err().lines().next(),
+            Some("line 2: string values must be quoted, expected literal string")
+        );
+
+        let doc = Document::parse("[x]\nkey = 1\n").unwrap();
+        let entry = doc.table("x").unwrap().get("key").unwrap();
+
+        assert_eq!(
+            entry.as_str().unwrap_err(),
+            "line 2: `key` must be a string, not an integer"
+        );
+        assert_eq!(
+            doc.check_tables(&[]).unwrap_err(),
+            "line 1: unknown table [x]"
+        );
+    }
+
+    #[test]
+    fn quoted_strings_round_trip() {
+        for s in [
+            "plain",
+            "It's \"quoted\"",
+            "back\\slash",
+            "tab\tand\nnewline",
+            "\u{1}",
+        ] {
+            let doc = Document::parse(&format!("{} = {}\n", key(s), quote(s))).unwrap();
+            let entry = &doc.root().entries[0];
+
+            assert_eq!(entry.key, s);
+            assert_eq!(entry.as_str(), Ok(s));
+        }
+
+        assert_eq!(key("terminal-margin"), "terminal-margin");
+        assert_eq!(key("a.b"), "\"a.b\"");
+    }
+}
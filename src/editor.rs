@@ -0,0 +1,569 @@
+use std::fmt::Write as _;
+use std::io;
+
+use console::{Key, Term};
+use themux::{Rgb, Theme, Themes};
+
+use crate::style::CLR;
+use crate::user_themes;
+use crate::util::shell_quote;
+use crate::watch::preview_sequences;
+
+/// Switches to the terminal's alternate screen, as full-screen programs do.
+const ENTER_SCREEN: &str = "\x1b[?1049h";
+
+/// Switches back from the alternate screen.
+const LEAVE_SCREEN: &str = "\x1b[?1049l";
+
+/// Resets the palette, foreground, background and cursor colors changed by
+/// the live preview.
+const RESET_COLORS: &str = "\x1b]104\x07\x1b]110\x07\x1b]111\x07\x1b]112\x07";
+
+/// The number of cells in a slider.
+const SLIDER_WIDTH: u32 = 24;
+
+/// How many changes can be undone.
+const MAX_UNDO: usize = 200;
+
+/// How many small steps make a large one.
+const LARGE_STEP: f64 = 10.0;
+
+/// A color model offered by the sliders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Model {
+    Hsl,
+    Oklch,
+}
+
+/// One of a color model's coordinates.
+#[derive(Clone, Copy, Debug)]
+struct Component {
+    name: &'static str,
+    /// The largest value, the smallest being 0.
+    max: f64,
+    /// The change made by a small step.
+    step: f64,
+    /// The factor, decimals and unit used to display values.
+    scale: f64,
+    decimals: usize,
+    unit: &'static str,
+    /// True for hues, which wrap around instead of stopping at the ends.
+    wraps: bool,
+}
+
+impl Component {
+    const fn new(name: &'static str, max: f64, step: f64) -> Self {
+        Self {
+            name,
+            max,
+            step,
+            scale: 1.0,
+            decimals: 2,
+            unit: "",
+            wraps: false,
+        }
+    }
+
+    const fn hue() -> Self {
+        Self {
+            decimals: 0,
+            unit: "°",
+            wraps: true,
+            ..Self::new("H", 360.0, 1.0)
+        }
+    }
+
+    const fn percent(name: &'static str) -> Self {
+        Self {
+            scale: 100.0,
+            decimals: 0,
+            unit: "%",
+            ..Self::new(name, 1.0, 0.01)
+        }
+    }
+
+    // Returns a value moved by a number of steps.
+    fn moved(self, value: f64, steps: f64) -> f64 {
+        let value = self.step.mul_add(steps, value);
+
+        if self.wraps {
+            value.rem_euclid(self.max)
+        } else {
+            value.clamp(0.0, self.max)
+        }
+    }
+
+    // Formats a value for display.
+    fn display(self, value: f64) -> String {
+        format!("{:.*}{}", self.decimals, value * self.scale, self.unit)
+    }
+}
+
+impl Model {
+    // Returns the model's name.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Hsl => "HSL",
+            Self::Oklch => "OKLCH",
+        }
+    }
+
+    // Returns the other model.
+    const fn toggled(self) -> Self {
+        match self {
+            Self::Hsl => Self::Oklch,
+            Self::Oklch => Self::Hsl,
+        }
+    }
+
+    // Returns the model's coordinates, in order.
+    const fn components(self) -> [Component; 3] {
+        match self {
+            Self::Hsl => [
+                Component::hue(),
+                Component::percent("S"),
+                Component::percent("L"),
+            ],
+            Self::Oklch => [
+                Component::new("L", 1.0, 0.01),
+                Component {
+                    decimals: 3,
+                    ..Component::new("C", 0.37, 0.005)
+                },
+                Component::hue(),
+            ],
+        }
+    }
+
+    // Returns a color's coordinates.
+    fn values(self, color: Rgb) -> [f64; 3] {
+        match self {
+            Self::Hsl => color.to_hsl().into(),
+            Self::Oklch => color.to_oklch().into(),
+        }
+    }
+
+    // Returns the color with the given coordinates.
+    fn color(self, [a, b, c]: [f64; 3]) -> Rgb {
+        match self {
+            Self::Hsl => Rgb::from_hsl(a, b, c),
+            Self::Oklch => Rgb::from_oklch(a, b, c),
+        }
+    }
+}
+
+/// A line of input being typed at the bottom of the screen.
+#[derive(Clone, Debug)]
+enum Prompt {
+    /// A hex color for the selected slot.
+    Hex(String),
+    /// The name to save the theme as.
+    Name(String),
+    /// Whether to quit without saving.
+    Discard,
+}
+
+/// The full-screen palette editor.
+pub struct Editor {
+    term: Term,
+    theme: Theme,
+    /// The colors as last saved, to tell whether there are changes.
+    saved: [(&'static str, Rgb); 19],
+    /// The index of the selected slot in `Theme::slots`.
+    selected: usize,
+    model: Model,
+    /// The index of the slider that the arrow keys move.
+    component: usize,
+    /// The slider values for the selected slot. They are kept apart from its
+    /// color so that, for example, the hue survives passing through gray.
+    values: [f64; 3],
+    undo: Vec<Theme>,
+    redo: Vec<Theme>,
+    is_preview: bool,
+    prompt: Option<Prompt>,
+    message: String,
+}
+
+/// Holds the terminal on the alternate screen, and restores it when dropped,
+/// even if the editor fails or panics.
+struct Screen(Term);
+
+impl Screen {
+    // Switches to the alternate screen and hides the cursor.
+    fn enter(term: Term) -> io::Result<Self> {
+        term.write_str(ENTER_SCREEN)?;
+        let screen = Self(term);
+        screen.0.hide_cursor()?;
+
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        // There is nowhere left to report a failure to.
+        let _ = self.0.write_str(RESET_COLORS);
+        let _ = self.0.show_cursor();
+        let _ = self.0.write_str(LEAVE_SCREEN);
+    }
+}
+
+impl Editor {
+    /// Edits a theme until the user quits.
+    pub fn run(theme: Theme) -> io::Result<()> {
+        let term = Term::stdout();
+
+        if !term.is_term() {
+            return Err(io::Error::other("themux edit needs a terminal"));
+        }
+
+        let mut editor = Self {
+            term,
+            saved: theme.slots(),
+            values: Model::Hsl.values(theme.color0),
+            theme,
+            selected: 0,
+            model: Model::Hsl,
+            component: 0,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            is_preview: true,
+            prompt: None,
+            message: String::new(),
+        };
+
+        let _screen = Screen::enter(editor.term.clone())?;
+
+        editor.preview()?;
+        editor.event_loop()
+    }
+
+    // Draws the screen and handles keys until the user quits.
+    fn event_loop(&mut self) -> io::Result<()> {
+        loop {
+            self.term.write_str(&self.render())?;
+
+            // Reading raw keys turns Ctrl-C into a key instead of a signal, so
+            // that it reaches the discard prompt and the screen is restored.
+            let key = self.term.read_key_raw()?;
+
+            if let Some(prompt) = self.prompt.take() {
+                if self.handle_prompt(prompt, key) {
+                    return Ok(());
+                }
+
+                continue;
+            }
+
+            self.message.clear();
+
+            match key {
+                Key::ArrowUp | Key::Char('k') => self.select(self.selected + 18),
+                Key::ArrowDown | Key::Char('j') => self.select(self.selected + 1),
+                Key::ArrowLeft | Key::Char('h') => self.adjust(-1.0),
+                Key::ArrowRight | Key::Char('l') => self.adjust(1.0),
+                Key::Char('-') => self.adjust(-LARGE_STEP),
+                Key::Char('+' | '=') => self.adjust(LARGE_STEP),
+                Key::Tab => self.component = (self.component + 1) % 3,
+                Key::BackTab => self.component = (self.component + 2) % 3,
+                Key::Char('m') => {
+                    self.model = self.model.toggled();
+                    self.values = self.model.values(self.color());
+                }
+                Key::Char('#') | Key::Enter => {
+                    let hex = self.color().to_string();
+                    self.prompt = Some(Prompt::Hex(hex[1..].to_string()));
+                }
+                Key::Char('u') => self.undo(),
+                Key::Char('r') => self.redo(),
+                Key::Char('p') => {
+                    self.is_preview = !self.is_preview;
+
+                    if self.is_preview {
+                        self.preview()?;
+                    } else {
+                        self.term.write_str(RESET_COLORS)?;
+                    }
+                }
+                Key::Char('s') => self.prompt = Some(Prompt::Name(self.save_name())),
+                Key::Char('q') | Key::Escape | Key::CtrlC => {
+                    if !self.is_modified() {
+                        return Ok(());
+                    }
+
+                    self.prompt = Some(Prompt::Discard);
+                }
+                _ => {}
+            }
+
+            self.preview()?;
+        }
+    }
+
+    // Handles a key typed at a prompt. Returns true if the editor should
+    // quit.
+    fn handle_prompt(&mut self, prompt: Prompt, key: Key) -> bool {
+        match (prompt, key) {
+            (Prompt::Discard, Key::Char('y' | 'Y')) => return true,
+            (Prompt::Discard, _) | (_, Key::Escape | Key::CtrlC) => {}
+            (Prompt::Hex(hex), Key::Enter) => match format!("#{hex}").parse::<Rgb>() {
+                Ok(color) => self.set_color(color),
+                Err(e) => self.message = e.to_string(),
+            },
+            (Prompt::Name(name), Key::Enter) => self.save(name.trim()),
+            (Prompt::Hex(mut hex), Key::Char(c)) => {
+                if c.is_ascii_hexdigit() && hex.len() < 6 {
+                    hex.push(c.to_ascii_uppercase());
+                }
+
+                self.prompt = Some(Prompt::Hex(hex));
+            }
+            (Prompt::Name(mut name), Key::Char(c)) => {
+                if !c.is_control() {
+                    name.push(c);
+                }
+
+                self.prompt = Some(Prompt::Name(name));
+            }
+            (Prompt::Hex(mut hex), Key::Backspace) => {
+                hex.pop();
+                self.prompt = Some(Prompt::Hex(hex));
+            }
+            (Prompt::Name(mut name), Key::Backspace) => {
+                name.pop();
+                self.prompt = Some(Prompt::Name(name));
+            }
+            (prompt, _) => self.prompt = Some(prompt),
+        }
+
+        false
+    }
+
+    // Returns the selected slot's color.
+    const fn color(&self) -> Rgb {
+        self.theme.slots()[self.selected].1
+    }
+
+    // Returns true if the colors differ from the last save.
+    fn is_modified(&self) -> bool {
+        self.theme.slots() != self.saved
+    }
+
+    // Selects a slot, wrapping around at the ends.
+    fn select(&mut self, idx: usize) {
+        self.selected = idx % 19;
+        self.values = self.model.values(self.color());
+    }
+
+    // Moves the active slider by a number of steps.
+    fn adjust(&mut self, steps: f64) {
+        let component = self.model.components()[self.component];
+
+        self.values[self.component] = component.moved(self.values[self.component], steps);
+
+        let values = self.values;
+        self.set_color(self.model.color(values));
+        self.values = values;
+    }
+
+    // Changes the selected slot's color, remembering the old one for undo.
+    fn set_color(&mut self, color: Rgb) {
+        if color == self.color() {
+            return;
+        }
+
+        self.undo.push(self.theme.clone());
+
+        if self.undo.len() > MAX_UNDO {
+            self.undo.remove(0);
+        }
+
+        self.redo.clear();
+
+        let key = self.theme.slots()[self.selected].0;
+
+        if let Some(slot) = self.theme.slot_mut(key) {
+            *slot = color;
+        }
+
+        self.values = self.model.values(color);
+    }
+
+    // Undoes the last change.
+    fn undo(&mut self) {
+        let Some(theme) = self.undo.pop() else {
+            self.message = String::from("Nothing to undo");
+            return;
+        };
+
+        self.redo.push(std::mem::replace(&mut self.theme, theme));
+        self.values = self.model.values(self.color());
+    }
+
+    // Redoes the last undone change.
+    fn redo(&mut self) {
+        let Some(theme) = self.redo.pop() else {
+            self.message = String::from("Nothing to redo");
+            return;
+        };
+
+        self.undo.push(std::mem::replace(&mut self.theme, theme));
+        self.values = self.model.values(self.color());
+    }
+
+    // Returns the name the save prompt starts with. A user theme can't take
+    // a built-in theme's name, so an edited built-in theme gets a new one.
+    fn save_name(&self) -> String {
+        let name = &self.theme.name;
+
+        if Themes::init().get(name).is_some() {
+            format!("{name} (edited)")
+        } else {
+            name.to_string()
+        }
+    }
+
+    // Saves the theme as a user theme with the given name.
+    fn save(&mut self, name: &str) {
+        let mut theme = self.theme.clone();
+        theme.name = name.to_string().into();
+
+        match user_themes::save(&theme) {
+            Ok(path) => {
+                self.message = format!(
+                    "Saved {}; apply it with: themux apply {}",
+                    path.display(),
+                    shell_quote(name)
+                );
+                self.theme = theme;
+                self.saved = self.theme.slots();
+            }
+            Err(e) => self.message = e,
+        }
+    }
+
+    // Sends the theme to the terminal, if the preview is on.
+    fn preview(&self) -> io::Result<()> {
+        if self.is_preview {
+            self.term.write_str(&preview_sequences(&self.theme))?;
+        }
+
+        Ok(())
+    }
+
+    // Returns the escape sequences and text that draw the whole screen.
+    fn render(&self) -> String {
+        let bg = self.theme.background;
+        let fg = self.theme.foreground;
+        let mut out = String::from("\x1b[H");
+
+        let _ = write!(
+            out,
+            " themux edit: {}{}\x1b[K\n\x1b[K\n   {:<11} {:<13} contrast\x1b[K\n",
+            self.theme.name,
+            if self.is_modified() {
+                " (modified)"
+            } else {
+                ""
+            },
+            "slot",
+            "color"
+        );
+
+        for (idx, (key, color)) in self.theme.slots().into_iter().enumerate() {
+            // The background is compared with the foreground, and every
+            // other color with the background.
+            let other = if key == "background" { fg } else { bg };
+            let sample = if key == "background" { fg } else { color };
+
+            let _ = writeln!(
+                out,
+                " {} {key:<11} {}    {CLR} {color}  {:>5.2}:1  {}{} Aa {CLR}\x1b[K",
+                if idx == self.selected { '>' } else { ' ' },
+                bg_escape(color),
+                color.contrast(other),
+                bg_escape(bg),
+                fg_escape(sample)
+            );
+        }
+
+        let _ = write!(out, "\x1b[K\n {} (m to switch)\x1b[K\n", self.model.name());
+
+        for (idx, component) in self.model.components().into_iter().enumerate() {
+            let _ = writeln!(
+                out,
+                " {} {} {:>6}  {}\x1b[K",
+                if idx == self.component { '>' } else { ' ' },
+                component.name,
+                component.display(self.values[idx]),
+                self.slider(idx)
+            );
+        }
+
+        let _ = write!(
+            out,
+            "\x1b[K\n ↑↓ slot  ←→ adjust  -/+ by 10  Tab slider  # hex\x1b[K\n \
+            u undo  r redo  p preview ({})  s save  q quit\x1b[K\n\x1b[K\n ",
+            if self.is_preview { "on" } else { "off" }
+        );
+
+        match &self.prompt {
+            Some(Prompt::Hex(hex)) => {
+                let _ = write!(out, "Hex color: #{hex}_");
+            }
+            Some(Prompt::Name(name)) => {
+                let _ = write!(out, "Save as: {name}_");
+            }
+            Some(Prompt::Discard) => out.push_str("Quit without saving? (y/n)"),
+            None => out.push_str(&self.message),
+        }
+
+        out.push_str("\x1b[K\x1b[J");
+        out
+    }
+
+    // Returns a slider as a gradient of the colors it would give, with a
+    // marker at the current value.
+    fn slider(&self, idx: usize) -> String {
+        let component = self.model.components()[idx];
+        let mut out = String::new();
+
+        // The cell holding the value is found by rounding, as its color is
+        // the nearest to the current one.
+        let last = f64::from(SLIDER_WIDTH - 1);
+        let current = (self.values[idx] / component.max * last).round();
+
+        for cell in 0..SLIDER_WIDTH {
+            let cell = f64::from(cell);
+            let mut values = self.values;
+            values[idx] = cell / last * component.max;
+
+            let color = self.model.color(values);
+            let marker = if (cell - current).abs() < 0.5 {
+                '●'
+            } else {
+                ' '
+            };
+            let ink = if color.is_dark() {
+                Rgb::new(0xFF, 0xFF, 0xFF)
+            } else {
+                Rgb::new(0, 0, 0)
+            };
+
+            let _ = write!(out, "{}{}{marker}", bg_escape(color), fg_escape(ink));
+        }
+
+        out.push_str(CLR);
+        out
+    }
+}
+
+// Returns the escape sequence that sets the background color.
+fn bg_escape(color: Rgb) -> String {
+    format!("\x1b[48;2;{};{};{}m", color.r, color.g, color.b)
+}
+
+// Returns the escape sequence that sets the foreground color.
+fn fg_escape(color: Rgb) -> String {
+    format!("\x1b[38;2;{};{};{}m", color.r, color.g, color.b)
+}
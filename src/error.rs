@@ -0,0 +1,79 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io;
+use std::path::PathBuf;
+
+/// A specialized `Result` type for themux operations.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The errors that can occur when working with themes.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading or writing a file failed.
+    Io {
+        /// The file being accessed.
+        path: PathBuf,
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// A color is not in the `#RRGGBB` or `#RGB` format.
+    InvalidColor(String),
+    /// A line in a properties file could not be parsed.
+    Parse {
+        /// The 1-based line number.
+        line: usize,
+        /// What was wrong with the line.
+        message: String,
+    },
+    /// A color settings file sets no colors.
+    Empty,
+    /// A template could not be parsed.
+    Template {
+        /// The 1-based line of the placeholder.
+        line: usize,
+        /// What was wrong with the placeholder.
+        message: String,
+    },
+    /// A theme query could not be parsed.
+    Query {
+        /// The 1-based column, in characters, where the problem starts.
+        column: usize,
+        /// The number of characters at fault.
+        width: usize,
+        /// What was wrong with the query.
+        message: String,
+    },
+}
+
+impl Error {
+    /// Returns a closure that wraps an `io::Error` for the given path.
+    pub fn io(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| Self::Io { path, source }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Self::InvalidColor(msg) => f.write_str(msg),
+            Self::Parse { line, message } | Self::Template { line, message } => {
+                write!(f, "line {line}: {message}")
+            }
+            Self::Empty => f.write_str("no colors are set"),
+            Self::Query {
+                column, message, ..
+            } => write!(f, "column {column}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
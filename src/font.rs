@@ -0,0 +1,137 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::termux::Paths;
+use crate::util::suggest;
+
+/// The extensions of the files listed from the font directory.
+const EXTENSIONS: [&str; 2] = ["ttf", "otf"];
+
+/// Reads a font file, checking that it is a TrueType or OpenType font.
+pub fn read(path: &Path) -> Result<Vec<u8>, String> {
+    let data = fs::read(path).map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+
+    validate(&data).map_err(|e| format!("{} is not a usable font: {e}", path.display()))?;
+
+    Ok(data)
+}
+
+// Checks the header and table directory of a TrueType or OpenType font.
+//
+// See: https://learn.microsoft.com/en-us/typography/opentype/spec/otff
+fn validate(data: &[u8]) -> Result<(), &'static str> {
+    match data.get(..4) {
+        Some([0, 1, 0, 0] | b"true" | b"OTTO") => {}
+        Some(b"ttcf") => return Err("font collections are not supported"),
+        Some(b"wOFF" | b"wOF2") => return Err("web fonts are not supported"),
+        _ => return Err("not a TrueType or OpenType font"),
+    }
+
+    let tables = data
+        .get(4..6)
+        .map(|n| usize::from(u16::from_be_bytes([n[0], n[1]])));
+
+    // The table directory follows the 12-byte header, with 16 bytes per
+    // table.
+    match tables {
+        None | Some(0) => Err("the font has no tables"),
+        Some(n) if data.len() < 12 + 16 * n => Err("the font is truncated"),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Returns the fonts in the font directory, sorted by file name.
+pub fn available() -> Result<Vec<PathBuf>, String> {
+    let dir = Paths::get().fonts_dir();
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Unable to read {}: {e}", dir.display())),
+    };
+
+    let mut fonts = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| is_font_file(path))
+        .collect::<Vec<PathBuf>>();
+
+    fonts.sort();
+
+    Ok(fonts)
+}
+
+// Returns true if the path is a file with a font extension.
+fn is_font_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(OsStr::to_str)
+            .is_some_and(|ext| EXTENSIONS.iter().any(|e| ext.eq_ignore_ascii_case(e)))
+}
+
+/// Returns the name a font file is listed under.
+pub fn name(path: &Path) -> String {
+    path.file_stem()
+        .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned())
+}
+
+/// Finds a font by its path, or by its name or file name in the font
+/// directory.
+pub fn find(font: &str) -> Result<PathBuf, String> {
+    let path = Path::new(font);
+
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+
+    let fonts = available()?;
+
+    let found = fonts.iter().find(|path| {
+        name(path).eq_ignore_ascii_case(font)
+            || path
+                .file_name()
+                .is_some_and(|file| file.eq_ignore_ascii_case(font))
+    });
+
+    if let Some(path) = found {
+        return Ok(path.clone());
+    }
+
+    let mut msg = format!(
+        "\"{font}\" is not a font file or a font in {}",
+        Paths::get().fonts_dir().display()
+    );
+
+    let names = fonts.iter().map(|path| name(path)).collect::<Vec<String>>();
+
+    if let Some(similar) = suggest(font, names.iter().map(String::as_str)) {
+        msg.push_str("; did you mean \"");
+        msg.push_str(similar);
+        msg.push_str("\"?");
+    }
+
+    Err(msg)
+}
+
+/// Returns a hash identifying a font by its content.
+//
+// FNV-1a, which is plenty for telling a handful of fonts apart.
+pub fn hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xCBF2_9CE4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+/// Returns the hash of the installed font, or `None` if Termux is using
+/// its default font.
+pub fn installed_hash() -> Result<Option<u64>, String> {
+    let path = Paths::get().font_file();
+
+    match fs::read(&path) {
+        Ok(data) => Ok(Some(hash(&data))),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+    }
+}
@@ -0,0 +1,976 @@
+//! Renders a `Theme` as configuration for other programs, so that they use
+//! colors that match the terminal.
+//!
+//! ```
+//! use themux::generate::Target;
+//! use themux::Themes;
+//!
+//! let theme = Themes::init().resolve("tokyo-night").unwrap();
+//! let tmux = Target::Tmux.render(theme);
+//!
+//! assert!(tmux.contains("set -g status-style"));
+//! ```
+
+use std::fmt::Write as _;
+
+use crate::color::Rgb;
+use crate::theme::Theme;
+
+/// A program, or a part of one, that configuration can be generated for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// fzf `--color` options, as an addition to `FZF_DEFAULT_OPTS`.
+    Fzf,
+    /// The `LS_COLORS` variable used by `ls` and other file listers.
+    LsColors,
+    /// A `dircolors` database, for `eval "$(dircolors FILE)"`.
+    Dircolors,
+    /// tmux status line, pane border and message styles.
+    Tmux,
+    /// A starship palette.
+    Starship,
+    /// The `LESS_TERMCAP_*` variables that color man pages in `less`.
+    Less,
+    /// The `GREP_COLORS` variable used by GNU grep.
+    Grep,
+    /// A Neovim colorscheme in Lua.
+    Neovim,
+    /// A Vim colorscheme.
+    Vim,
+    /// A Helix theme.
+    Helix,
+    /// A Micro colorscheme.
+    Micro,
+    /// nano's interface colors, as nanorc settings.
+    Nano,
+}
+
+impl Target {
+    /// Every target, in the order they are listed.
+    pub const ALL: [Self; 12] = [
+        Self::Fzf,
+        Self::LsColors,
+        Self::Dircolors,
+        Self::Tmux,
+        Self::Starship,
+        Self::Less,
+        Self::Grep,
+        Self::Neovim,
+        Self::Vim,
+        Self::Helix,
+        Self::Micro,
+        Self::Nano,
+    ];
+
+    /// Returns the name used to select the target.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Fzf => "fzf",
+            Self::LsColors => "ls-colors",
+            Self::Dircolors => "dircolors",
+            Self::Tmux => "tmux",
+            Self::Starship => "starship",
+            Self::Less => "less",
+            Self::Grep => "grep",
+            Self::Neovim => "neovim",
+            Self::Vim => "vim",
+            Self::Helix => "helix",
+            Self::Micro => "micro",
+            Self::Nano => "nano",
+        }
+    }
+
+    /// Returns the conventional name of a file holding the output.
+    #[must_use]
+    pub const fn file_name(self) -> &'static str {
+        match self {
+            Self::Fzf => "fzf.sh",
+            Self::LsColors => "ls-colors.sh",
+            Self::Dircolors => "dircolors",
+            Self::Tmux => "tmux.conf",
+            Self::Starship => "starship.toml",
+            Self::Less => "less.sh",
+            Self::Grep => "grep.sh",
+            Self::Neovim => "themux.lua",
+            Self::Vim => "themux.vim",
+            Self::Helix => "helix.toml",
+            Self::Micro => "themux.micro",
+            Self::Nano => "nanorc",
+        }
+    }
+
+    /// Returns the target with the given name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|target| target.name() == name)
+    }
+
+    /// Returns the names of every target.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        Self::ALL.into_iter().map(Self::name)
+    }
+
+    /// Renders the configuration for a theme.
+    #[must_use]
+    pub fn render(self, theme: &Theme) -> String {
+        match self {
+            Self::Fzf => fzf(theme),
+            Self::LsColors => ls_colors(theme),
+            Self::Dircolors => dircolors(theme),
+            Self::Tmux => tmux(theme),
+            Self::Starship => starship(theme),
+            Self::Less => less(theme),
+            Self::Grep => grep(theme),
+            Self::Neovim => neovim(theme),
+            Self::Vim => vim(theme),
+            Self::Helix => helix(theme),
+            Self::Micro => micro(theme),
+            Self::Nano => nano(theme),
+        }
+    }
+}
+
+// Returns the first line of a generated file.
+fn header(comment: &str, what: &str, theme: &Theme) -> String {
+    format!(
+        "{comment} {what} for {}, generated by themux.\n",
+        theme.name
+    )
+}
+
+// Returns a background slightly raised toward the foreground, for bars.
+fn surface(theme: &Theme) -> Rgb {
+    theme.background.mix(theme.foreground, 0.08)
+}
+
+// Returns a background raised further toward the foreground, for selections.
+fn selection(theme: &Theme) -> Rgb {
+    theme.background.mix(theme.foreground, 0.2)
+}
+
+// Returns the SGR parameters that set a truecolor foreground.
+fn fg(color: Rgb) -> String {
+    format!("38;2;{};{};{}", color.r, color.g, color.b)
+}
+
+// Returns the SGR parameters that set a truecolor background.
+fn bg(color: Rgb) -> String {
+    format!("48;2;{};{};{}", color.r, color.g, color.b)
+}
+
+fn fzf(theme: &Theme) -> String {
+    let mut out = header("#", "fzf colors", theme);
+
+    let _ = write!(
+        out,
+        "export FZF_DEFAULT_OPTS=\"$FZF_DEFAULT_OPTS
+  --color=fg:{},bg:{},hl:{}
+  --color=fg+:{},bg+:{},hl+:{}
+  --color=info:{},prompt:{},pointer:{}
+  --color=marker:{},spinner:{},header:{}
+  --color=border:{},gutter:{},query:{}\"\n",
+        theme.foreground,
+        theme.background,
+        theme.color4,
+        theme.foreground,
+        selection(theme),
+        theme.color12,
+        theme.color3,
+        theme.color6,
+        theme.color5,
+        theme.color2,
+        theme.color5,
+        theme.color8,
+        theme.color8,
+        theme.background,
+        theme.foreground,
+    );
+
+    out
+}
+
+// Returns the `LS_COLORS` keys and `dircolors` keywords for file types, with
+// their styles.
+fn file_type_styles(theme: &Theme) -> [(&'static str, &'static str, String); 15] {
+    let on = |fore: Rgb, back: Rgb| format!("{};{}", fg(fore), bg(back));
+
+    [
+        ("di", "DIR", format!("01;{}", fg(theme.color4))),
+        ("ln", "LINK", fg(theme.color6)),
+        ("pi", "FIFO", fg(theme.color3)),
+        ("so", "SOCK", format!("01;{}", fg(theme.color5))),
+        ("do", "DOOR", format!("01;{}", fg(theme.color5))),
+        ("bd", "BLK", format!("01;{}", fg(theme.color3))),
+        ("cd", "CHR", format!("01;{}", fg(theme.color3))),
+        ("or", "ORPHAN", format!("01;{}", fg(theme.color1))),
+        ("mi", "MISSING", fg(theme.color1)),
+        ("su", "SETUID", on(theme.background, theme.color1)),
+        ("sg", "SETGID", on(theme.background, theme.color3)),
+        ("ca", "CAPABILITY", on(theme.background, theme.color1)),
+        (
+            "tw",
+            "STICKY_OTHER_WRITABLE",
+            on(theme.background, theme.color2),
+        ),
+        ("ow", "OTHER_WRITABLE", on(theme.color4, selection(theme))),
+        ("ex", "EXEC", format!("01;{}", fg(theme.color2))),
+    ]
+}
+
+// Returns file extensions, grouped by the slot used for them.
+fn extension_styles(theme: &Theme) -> [(&'static [&'static str], String); 3] {
+    [
+        (
+            &[
+                "7z", "apk", "bz2", "deb", "gz", "rar", "tar", "tgz", "xz", "zip", "zst",
+            ],
+            fg(theme.color1),
+        ),
+        (
+            &["bmp", "gif", "jpeg", "jpg", "png", "svg", "webp"],
+            fg(theme.color5),
+        ),
+        (
+            &["flac", "mkv", "mov", "mp3", "mp4", "ogg", "wav", "webm"],
+            fg(theme.color6),
+        ),
+    ]
+}
+
+fn ls_colors(theme: &Theme) -> String {
+    let mut out = header("#", "LS_COLORS", theme);
+    let mut value = String::new();
+
+    for (key, _, style) in file_type_styles(theme) {
+        let _ = write!(value, "{key}={style}:");
+    }
+
+    for (extensions, style) in extension_styles(theme) {
+        for ext in extensions {
+            let _ = write!(value, "*.{ext}={style}:");
+        }
+    }
+
+    let _ = writeln!(out, "export LS_COLORS='{value}'");
+
+    out
+}
+
+fn dircolors(theme: &Theme) -> String {
+    let mut out = header("#", "dircolors database", theme);
+
+    out.push_str("# Use with: eval \"$(dircolors FILE)\"\n\nTERM *\n\n");
+
+    for (_, keyword, style) in file_type_styles(theme) {
+        let _ = writeln!(out, "{keyword} {style}");
+    }
+
+    for (extensions, style) in extension_styles(theme) {
+        out.push('\n');
+
+        for ext in extensions {
+            let _ = writeln!(out, ".{ext} {style}");
+        }
+    }
+
+    out
+}
+
+fn tmux(theme: &Theme) -> String {
+    let mut out = header("#", "tmux colors", theme);
+    let (bar, selected) = (surface(theme), selection(theme));
+
+    let options = [
+        ("status-style", format!("fg={},bg={bar}", theme.foreground)),
+        (
+            "status-left-style",
+            format!("fg={},bg={},bold", theme.background, theme.color4),
+        ),
+        (
+            "status-right-style",
+            format!("fg={},bg={bar}", theme.foreground),
+        ),
+        (
+            "window-status-style",
+            format!("fg={},bg={bar}", theme.color8),
+        ),
+        (
+            "window-status-current-style",
+            format!("fg={},bg={selected},bold", theme.color4),
+        ),
+        (
+            "window-status-activity-style",
+            format!("fg={},bg={bar}", theme.color3),
+        ),
+        (
+            "window-status-bell-style",
+            format!("fg={},bg={bar},bold", theme.color1),
+        ),
+        ("pane-border-style", format!("fg={}", theme.color8)),
+        ("pane-active-border-style", format!("fg={}", theme.color4)),
+        (
+            "message-style",
+            format!("fg={},bg={selected}", theme.foreground),
+        ),
+        (
+            "message-command-style",
+            format!("fg={},bg={selected}", theme.foreground),
+        ),
+        (
+            "mode-style",
+            format!("fg={},bg={}", theme.background, theme.color3),
+        ),
+        ("clock-mode-colour", theme.color4.to_string()),
+        ("display-panes-colour", theme.color8.to_string()),
+        ("display-panes-active-colour", theme.color4.to_string()),
+    ];
+
+    for (option, value) in options {
+        let _ = writeln!(out, "set -g {option} \"{value}\"");
+    }
+
+    out
+}
+
+fn starship(theme: &Theme) -> String {
+    const NAMES: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "purple", "cyan", "white",
+    ];
+
+    let mut out = header("#", "starship palette", theme);
+
+    out.push_str(
+        "# Merge into starship.toml. The palette overrides the color names that\n\
+         # starship's modules use.\n\npalette = \"themux\"\n\n[palettes.themux]\n",
+    );
+
+    let slots = theme.slots();
+
+    for (idx, name) in NAMES.iter().enumerate() {
+        let _ = writeln!(out, "{name} = \"{}\"", slots[idx].1);
+    }
+
+    for (idx, name) in NAMES.iter().enumerate() {
+        let _ = writeln!(out, "bright-{name} = \"{}\"", slots[idx + 8].1);
+    }
+
+    let _ = write!(
+        out,
+        "background = \"{}\"\nforeground = \"{}\"\ncursor = \"{}\"\n",
+        theme.background, theme.foreground, theme.cursor
+    );
+
+    out
+}
+
+fn less(theme: &Theme) -> String {
+    let mut out = header("#", "less and man page colors", theme);
+
+    let variables = [
+        ("mb", "Blinking text.", format!("01;{}", fg(theme.color1))),
+        (
+            "md",
+            "Bold text, such as headings.",
+            format!("01;{}", fg(theme.color4)),
+        ),
+        ("me", "", String::from("0")),
+        (
+            "so",
+            "Standout text: the prompt and search matches.",
+            format!("{};{}", fg(theme.background), bg(theme.color3)),
+        ),
+        ("se", "", String::from("0")),
+        (
+            "us",
+            "Underlined text, such as arguments.",
+            format!("04;{}", fg(theme.color2)),
+        ),
+        ("ue", "", String::from("0")),
+    ];
+
+    for (name, comment, style) in variables {
+        if !comment.is_empty() {
+            let _ = writeln!(out, "# {comment}");
+        }
+
+        let _ = writeln!(
+            out,
+            "export LESS_TERMCAP_{name}=\"$(printf '\\033[{style}m')\""
+        );
+    }
+
+    out.push_str(
+        "# Recent groff versions emit their own escapes, bypassing the above.\n\
+         export GROFF_NO_SGR=1\n",
+    );
+
+    out
+}
+
+fn grep(theme: &Theme) -> String {
+    let mut out = header("#", "GNU grep colors", theme);
+
+    let _ = writeln!(
+        out,
+        "export GREP_COLORS='ms=01;{0}:mc=01;{0}:sl=:cx=:fn={1}:ln={2}:bn={2}:se={3}'",
+        fg(theme.color1),
+        fg(theme.color5),
+        fg(theme.color2),
+        fg(theme.color8),
+    );
+
+    out
+}
+
+/// A color taken from a theme, for the editor colorschemes.
+#[derive(Clone, Copy, Debug)]
+enum Paint {
+    /// One of the 16 ANSI slots.
+    Ansi(u8),
+    Background,
+    Foreground,
+    Cursor,
+    /// The raised background used for bars and the cursor line.
+    Surface,
+    /// The background of selected text.
+    Selection,
+}
+
+const GRAY: Paint = Paint::Ansi(8);
+const RED: Paint = Paint::Ansi(1);
+const GREEN: Paint = Paint::Ansi(2);
+const YELLOW: Paint = Paint::Ansi(3);
+const BLUE: Paint = Paint::Ansi(4);
+const MAGENTA: Paint = Paint::Ansi(5);
+const CYAN: Paint = Paint::Ansi(6);
+const WHITE: Paint = Paint::Ansi(15);
+const BRIGHT_YELLOW: Paint = Paint::Ansi(11);
+const BRIGHT_MAGENTA: Paint = Paint::Ansi(13);
+const BG: Paint = Paint::Background;
+const FG: Paint = Paint::Foreground;
+const SURFACE: Paint = Paint::Surface;
+const SELECTION: Paint = Paint::Selection;
+
+/// The names of the ANSI colors, without their bright variants.
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+impl Paint {
+    // Returns the color in a theme.
+    fn color(self, theme: &Theme) -> Rgb {
+        match self {
+            Self::Ansi(idx) => theme.slots()[usize::from(idx)].1,
+            Self::Background => theme.background,
+            Self::Foreground => theme.foreground,
+            Self::Cursor => theme.cursor,
+            Self::Surface => surface(theme),
+            Self::Selection => selection(theme),
+        }
+    }
+
+    // Returns the closest ANSI slot, for terminals without truecolor. The
+    // default colors are left to the terminal, which already uses them.
+    fn ansi(self, theme: &Theme) -> Option<u8> {
+        match self {
+            Self::Ansi(idx) => Some(idx),
+            Self::Surface if theme.background.is_dark() => Some(0),
+            Self::Surface => Some(7),
+            Self::Selection => Some(8),
+            Self::Background | Self::Foreground | Self::Cursor => None,
+        }
+    }
+
+    // Returns the color's name in a Helix palette.
+    fn name(self) -> String {
+        match self {
+            Self::Ansi(idx) if idx < 8 => ANSI_NAMES[usize::from(idx)].to_string(),
+            Self::Ansi(idx) => format!("bright-{}", ANSI_NAMES[usize::from(idx % 8)]),
+            Self::Background => String::from("background"),
+            Self::Foreground => String::from("foreground"),
+            Self::Cursor => String::from("cursor"),
+            Self::Surface => String::from("surface"),
+            Self::Selection => String::from("selection"),
+        }
+    }
+
+    // Returns the color's name in a nanorc, where names refer to the
+    // terminal's palette and "normal" to its default colors.
+    fn nano_name(self) -> String {
+        match self {
+            Self::Ansi(idx) if idx < 8 => ANSI_NAMES[usize::from(idx)].to_string(),
+            Self::Ansi(idx) => format!("light{}", ANSI_NAMES[usize::from(idx % 8)]),
+            _ => String::from("normal"),
+        }
+    }
+}
+
+/// How an editor element is drawn.
+///
+/// With the `undercurl` attribute, `fg` is the color of the curl rather than
+/// of the text.
+#[derive(Clone, Copy, Debug)]
+struct Style {
+    group: &'static str,
+    fg: Option<Paint>,
+    bg: Option<Paint>,
+    attr: Option<&'static str>,
+}
+
+impl Style {
+    const fn new(group: &'static str, fg: Paint, bg: Paint) -> Self {
+        Self {
+            group,
+            fg: Some(fg),
+            bg: Some(bg),
+            attr: None,
+        }
+    }
+
+    const fn fg(group: &'static str, fg: Paint) -> Self {
+        Self {
+            group,
+            fg: Some(fg),
+            bg: None,
+            attr: None,
+        }
+    }
+
+    const fn bg(group: &'static str, bg: Paint) -> Self {
+        Self {
+            group,
+            fg: None,
+            bg: Some(bg),
+            attr: None,
+        }
+    }
+
+    const fn with(self, attr: &'static str) -> Self {
+        Self {
+            attr: Some(attr),
+            ..self
+        }
+    }
+
+    fn is_undercurl(&self) -> bool {
+        self.attr == Some("undercurl")
+    }
+}
+
+/// The highlight groups set by the Vim and Neovim colorschemes.
+static VIM_GROUPS: &[Style] = &[
+    // Interface.
+    Style::new("Normal", FG, BG),
+    Style::new("NormalFloat", FG, SURFACE),
+    Style::new("Cursor", BG, Paint::Cursor),
+    Style::new("TermCursor", BG, Paint::Cursor),
+    Style::bg("CursorLine", SURFACE),
+    Style::bg("CursorColumn", SURFACE),
+    Style::bg("ColorColumn", SURFACE),
+    Style::fg("LineNr", GRAY),
+    Style::fg("CursorLineNr", FG).with("bold"),
+    Style::bg("SignColumn", BG),
+    Style::new("Folded", GRAY, SURFACE),
+    Style::bg("Visual", SELECTION),
+    Style::new("Search", BG, YELLOW),
+    Style::new("IncSearch", BG, BRIGHT_YELLOW),
+    Style::bg("MatchParen", SELECTION).with("bold"),
+    Style::new("StatusLine", FG, SURFACE),
+    Style::new("StatusLineNC", GRAY, SURFACE),
+    Style::fg("VertSplit", GRAY),
+    Style::fg("WinSeparator", GRAY),
+    Style::new("TabLine", GRAY, SURFACE),
+    Style::new("TabLineSel", FG, BG).with("bold"),
+    Style::bg("TabLineFill", SURFACE),
+    Style::new("Pmenu", FG, SURFACE),
+    Style::new("PmenuSel", BG, BLUE),
+    Style::bg("PmenuSbar", SURFACE),
+    Style::bg("PmenuThumb", GRAY),
+    Style::fg("NonText", GRAY),
+    Style::fg("SpecialKey", GRAY),
+    Style::fg("Directory", BLUE),
+    Style::fg("Title", BLUE).with("bold"),
+    Style::fg("ErrorMsg", RED).with("bold"),
+    Style::fg("WarningMsg", YELLOW),
+    Style::fg("ModeMsg", FG).with("bold"),
+    Style::fg("MoreMsg", GREEN),
+    Style::fg("Question", GREEN),
+    Style::fg("DiffAdd", GREEN),
+    Style::fg("DiffChange", YELLOW),
+    Style::fg("DiffDelete", RED),
+    Style::fg("DiffText", BLUE).with("bold"),
+    Style::fg("SpellBad", RED).with("undercurl"),
+    Style::fg("SpellCap", BLUE).with("undercurl"),
+    Style::fg("DiagnosticError", RED),
+    Style::fg("DiagnosticWarn", YELLOW),
+    Style::fg("DiagnosticInfo", BLUE),
+    Style::fg("DiagnosticHint", CYAN),
+    Style::fg("DiagnosticUnderlineError", RED).with("undercurl"),
+    Style::fg("DiagnosticUnderlineWarn", YELLOW).with("undercurl"),
+    Style::fg("DiagnosticUnderlineInfo", BLUE).with("undercurl"),
+    Style::fg("DiagnosticUnderlineHint", CYAN).with("undercurl"),
+    // Syntax.
+    Style::fg("Comment", GRAY).with("italic"),
+    Style::fg("Constant", YELLOW),
+    Style::fg("String", GREEN),
+    Style::fg("Character", GREEN),
+    Style::fg("Number", YELLOW),
+    Style::fg("Boolean", YELLOW),
+    Style::fg("Identifier", FG),
+    Style::fg("Function", BLUE),
+    Style::fg("Statement", MAGENTA),
+    Style::fg("Keyword", MAGENTA),
+    Style::fg("Operator", FG),
+    Style::fg("PreProc", RED),
+    Style::fg("Type", CYAN),
+    Style::fg("Special", BRIGHT_MAGENTA),
+    Style::fg("Delimiter", FG),
+    Style::fg("Underlined", BLUE).with("underline"),
+    Style::fg("Error", RED).with("bold"),
+    Style::new("Todo", BG, YELLOW).with("bold"),
+];
+
+/// The scopes set by the Helix theme.
+static HELIX_SCOPES: &[Style] = &[
+    // Interface.
+    Style::bg("ui.background", BG),
+    Style::fg("ui.text", FG),
+    Style::new("ui.cursor", BG, Paint::Cursor),
+    Style::new("ui.cursor.primary", BG, Paint::Cursor),
+    Style::bg("ui.cursor.match", SELECTION),
+    Style::bg("ui.cursorline.primary", SURFACE),
+    Style::bg("ui.selection", SELECTION),
+    Style::fg("ui.linenr", GRAY),
+    Style::fg("ui.linenr.selected", FG),
+    Style::new("ui.statusline", FG, SURFACE),
+    Style::new("ui.statusline.inactive", GRAY, SURFACE),
+    Style::new("ui.statusline.normal", BG, BLUE).with("bold"),
+    Style::new("ui.statusline.insert", BG, GREEN).with("bold"),
+    Style::new("ui.statusline.select", BG, MAGENTA).with("bold"),
+    Style::new("ui.popup", FG, SURFACE),
+    Style::new("ui.help", FG, SURFACE),
+    Style::new("ui.menu", FG, SURFACE),
+    Style::new("ui.menu.selected", BG, BLUE),
+    Style::fg("ui.window", GRAY),
+    Style::fg("ui.virtual.whitespace", GRAY),
+    Style::fg("ui.virtual.indent-guide", SELECTION),
+    Style::fg("ui.virtual.inlay-hint", GRAY),
+    Style::bg("ui.virtual.ruler", SURFACE),
+    Style::fg("error", RED),
+    Style::fg("warning", YELLOW),
+    Style::fg("info", BLUE),
+    Style::fg("hint", CYAN),
+    Style::fg("diagnostic.error", RED).with("undercurl"),
+    Style::fg("diagnostic.warning", YELLOW).with("undercurl"),
+    Style::fg("diagnostic.info", BLUE).with("undercurl"),
+    Style::fg("diagnostic.hint", CYAN).with("undercurl"),
+    Style::fg("diff.plus", GREEN),
+    Style::fg("diff.delta", YELLOW),
+    Style::fg("diff.minus", RED),
+    // Syntax.
+    Style::fg("comment", GRAY).with("italic"),
+    Style::fg("constant", YELLOW),
+    Style::fg("constant.character.escape", BRIGHT_MAGENTA),
+    Style::fg("string", GREEN),
+    Style::fg("variable", FG),
+    Style::fg("function", BLUE),
+    Style::fg("keyword", MAGENTA),
+    Style::fg("label", MAGENTA),
+    Style::fg("operator", FG),
+    Style::fg("punctuation", FG),
+    Style::fg("attribute", RED),
+    Style::fg("tag", RED),
+    Style::fg("type", CYAN),
+    Style::fg("namespace", CYAN),
+    Style::fg("special", BRIGHT_MAGENTA),
+    Style::fg("markup.heading", BLUE).with("bold"),
+    Style::fg("markup.list", MAGENTA),
+    Style::fg("markup.bold", FG).with("bold"),
+    Style::fg("markup.italic", FG).with("italic"),
+    Style::fg("markup.link.url", BLUE).with("underline"),
+    Style::fg("markup.raw", GREEN),
+];
+
+/// The groups set by the Micro colorscheme.
+//
+// Micro draws `cursor-line` and `color-column` with their foreground color
+// as the background.
+static MICRO_GROUPS: &[Style] = &[
+    // Interface.
+    Style::new("default", FG, BG),
+    Style::fg("cursor-line", SURFACE),
+    Style::fg("color-column", SURFACE),
+    Style::new("line-number", GRAY, BG),
+    Style::new("current-line-number", FG, BG),
+    Style::new("selection", FG, SELECTION),
+    Style::new("statusline", FG, SURFACE),
+    Style::new("tabbar", FG, SURFACE),
+    Style::new("divider", GRAY, BG),
+    Style::fg("indent-char", GRAY),
+    Style::fg("scrollbar", GRAY),
+    Style::new("match-brace", BG, BLUE),
+    Style::fg("message", FG),
+    Style::fg("error-message", RED).with("bold"),
+    Style::fg("gutter-error", RED),
+    Style::fg("gutter-warning", YELLOW),
+    Style::fg("diff-added", GREEN),
+    Style::fg("diff-modified", YELLOW),
+    Style::fg("diff-deleted", RED),
+    // Syntax.
+    Style::fg("comment", GRAY).with("italic"),
+    Style::fg("constant", YELLOW),
+    Style::fg("constant.string", GREEN),
+    Style::fg("constant.specialChar", BRIGHT_MAGENTA),
+    Style::fg("identifier", BLUE),
+    Style::fg("statement", MAGENTA),
+    Style::fg("symbol", FG),
+    Style::fg("preproc", RED),
+    Style::fg("type", CYAN),
+    Style::fg("special", BRIGHT_MAGENTA),
+    Style::fg("underlined", BLUE).with("underline"),
+    Style::fg("error", RED).with("bold"),
+    Style::new("todo", BG, YELLOW).with("bold"),
+];
+
+/// The interface colors set by the nanorc block. Syntax colors in nano are
+/// set by each syntax definition, by name, so they already follow the
+/// terminal's palette.
+static NANO_COLORS: &[Style] = &[
+    Style::new("titlecolor", WHITE, BLUE).with("bold"),
+    Style::new("statuscolor", WHITE, GREEN).with("bold"),
+    Style::new("errorcolor", WHITE, RED).with("bold"),
+    Style::new("promptcolor", Paint::Ansi(0), YELLOW),
+    Style::new("spotlightcolor", Paint::Ansi(0), BRIGHT_YELLOW),
+    Style::new("selectedcolor", WHITE, MAGENTA),
+    Style::bg("stripecolor", Paint::Ansi(0)),
+    Style::fg("scrollercolor", CYAN),
+    Style::fg("numbercolor", GRAY),
+    Style::fg("keycolor", CYAN),
+    Style::fg("functioncolor", GREEN),
+];
+
+// Returns "dark" or "light", for editors that adjust to the background.
+fn background_kind(theme: &Theme) -> &'static str {
+    if theme.background.is_dark() {
+        "dark"
+    } else {
+        "light"
+    }
+}
+
+fn neovim(theme: &Theme) -> String {
+    let mut out = header("--", "Neovim colorscheme", theme);
+
+    let _ = write!(
+        out,
+        "\nvim.cmd(\"highlight clear\")\n\
+         if vim.fn.exists(\"syntax_on\") == 1 then\n  vim.cmd(\"syntax reset\")\nend\n\n\
+         vim.o.background = \"{}\"\nvim.g.colors_name = \"themux\"\n\n\
+         local set = vim.api.nvim_set_hl\n\n",
+        background_kind(theme)
+    );
+
+    for style in VIM_GROUPS {
+        let mut spec = Vec::new();
+        let color_key = if style.is_undercurl() { "sp" } else { "fg" };
+
+        if let Some(paint) = style.fg {
+            spec.push(format!("{color_key} = \"{}\"", paint.color(theme)));
+        }
+
+        if let Some(paint) = style.bg {
+            spec.push(format!("bg = \"{}\"", paint.color(theme)));
+        }
+
+        if let Some(idx) = style
+            .fg
+            .filter(|_| !style.is_undercurl())
+            .and_then(|p| p.ansi(theme))
+        {
+            spec.push(format!("ctermfg = {idx}"));
+        }
+
+        if let Some(idx) = style.bg.and_then(|paint| paint.ansi(theme)) {
+            spec.push(format!("ctermbg = {idx}"));
+        }
+
+        if let Some(attr) = style.attr {
+            spec.push(format!("{attr} = true, cterm = {{ {attr} = true }}"));
+        }
+
+        let _ = writeln!(
+            out,
+            "set(0, \"{}\", {{ {} }})",
+            style.group,
+            spec.join(", ")
+        );
+    }
+
+    out.push('\n');
+
+    for (idx, (_, color)) in theme.slots().iter().take(16).enumerate() {
+        let _ = writeln!(out, "vim.g.terminal_color_{idx} = \"{color}\"");
+    }
+
+    out
+}
+
+// Returns a Vim `cterm` color, where NONE is the terminal's default.
+fn cterm(paint: Paint, theme: &Theme) -> String {
+    paint
+        .ansi(theme)
+        .map_or_else(|| String::from("NONE"), |idx| idx.to_string())
+}
+
+fn vim(theme: &Theme) -> String {
+    let mut out = header("\"", "Vim colorscheme", theme);
+
+    let _ = write!(
+        out,
+        "\nset background={}\nhighlight clear\n\
+         if exists('syntax_on')\n  syntax reset\nendif\n\
+         let g:colors_name = 'themux'\n\n",
+        background_kind(theme)
+    );
+
+    for style in VIM_GROUPS {
+        let _ = write!(out, "highlight {}", style.group);
+
+        if let Some(paint) = style.fg {
+            if style.is_undercurl() {
+                let _ = write!(out, " guisp={}", paint.color(theme));
+            } else {
+                let (color, cterm) = (paint.color(theme), cterm(paint, theme));
+                let _ = write!(out, " guifg={color} ctermfg={cterm}");
+            }
+        }
+
+        if let Some(paint) = style.bg {
+            let (color, cterm) = (paint.color(theme), cterm(paint, theme));
+            let _ = write!(out, " guibg={color} ctermbg={cterm}");
+        }
+
+        let attr = style.attr.unwrap_or("NONE");
+        let _ = writeln!(out, " gui={attr} cterm={attr}");
+    }
+
+    out.push_str("\nlet g:terminal_ansi_colors = [\n");
+
+    for (_, color) in theme.slots().iter().take(16) {
+        let _ = writeln!(out, "  \\ '{color}',");
+    }
+
+    out.push_str("  \\ ]\n");
+
+    out
+}
+
+fn helix(theme: &Theme) -> String {
+    let mut out = header("#", "Helix theme", theme);
+
+    out.push('\n');
+
+    for style in HELIX_SCOPES {
+        let mut spec = Vec::new();
+
+        match (style.fg, style.attr) {
+            (Some(paint), Some("undercurl")) => {
+                spec.push(format!(
+                    "underline = {{ color = \"{}\", style = \"curl\" }}",
+                    paint.name()
+                ));
+            }
+            (fg, attr) => {
+                if let Some(paint) = fg {
+                    spec.push(format!("fg = \"{}\"", paint.name()));
+                }
+
+                if let Some(paint) = style.bg {
+                    spec.push(format!("bg = \"{}\"", paint.name()));
+                }
+
+                match attr {
+                    Some("underline") => {
+                        spec.push(String::from("underline = { style = \"line\" }"));
+                    }
+                    Some(attr) => spec.push(format!("modifiers = [\"{attr}\"]")),
+                    None => {}
+                }
+            }
+        }
+
+        let _ = writeln!(out, "\"{}\" = {{ {} }}", style.group, spec.join(", "));
+    }
+
+    out.push_str("\n[palette]\n");
+
+    let paints = (0..16)
+        .map(Paint::Ansi)
+        .chain([BG, FG, Paint::Cursor, SURFACE, SELECTION]);
+
+    for paint in paints {
+        let _ = writeln!(out, "{} = \"{}\"", paint.name(), paint.color(theme));
+    }
+
+    out
+}
+
+fn micro(theme: &Theme) -> String {
+    let mut out = header("#", "Micro colorscheme", theme);
+
+    out.push('\n');
+
+    for style in MICRO_GROUPS {
+        let mut spec = String::new();
+
+        if let Some(attr) = style.attr {
+            // Micro has no undercurl.
+            let _ = write!(
+                spec,
+                "{} ",
+                if style.is_undercurl() {
+                    "underline"
+                } else {
+                    attr
+                }
+            );
+        }
+
+        if let Some(paint) = style.fg {
+            let _ = write!(spec, "{}", paint.color(theme));
+        }
+
+        if let Some(paint) = style.bg {
+            let _ = write!(spec, ",{}", paint.color(theme));
+        }
+
+        let _ = writeln!(out, "color-link {} \"{spec}\"", style.group);
+    }
+
+    out
+}
+
+fn nano(theme: &Theme) -> String {
+    let mut out = header("#", "nano interface colors", theme);
+
+    out.push_str("# Add to ~/.nanorc. Color names follow the terminal's palette.\n\n");
+
+    for style in NANO_COLORS {
+        let mut spec = style
+            .attr
+            .map_or_else(String::new, |attr| format!("{attr},"));
+
+        if let Some(paint) = style.fg {
+            spec.push_str(&paint.nano_name());
+        }
+
+        if let Some(paint) = style.bg {
+            let _ = write!(spec, ",{}", paint.nano_name());
+        }
+
+        let _ = writeln!(out, "set {} {spec}", style.group);
+    }
+
+    out
+}
@@ -0,0 +1,50 @@
+use std::fs;
+use std::io::ErrorKind;
+
+use crate::termux::Paths;
+use crate::verbose;
+
+/// The number of theme names kept in the history.
+const MAX_ENTRIES: usize = 100;
+
+/// Returns the names of recently applied themes, most recent first.
+///
+/// A missing or unreadable history is treated as empty.
+pub fn read() -> Vec<String> {
+    let path = Paths::get().history_file();
+
+    match fs::read_to_string(&path) {
+        Ok(content) => content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect(),
+        Err(e) => {
+            if e.kind() != ErrorKind::NotFound {
+                verbose!("unable to read {}: {e}", path.display());
+            }
+
+            Vec::new()
+        }
+    }
+}
+
+/// Moves a theme name to the top of the history.
+pub fn record(name: &str) -> Result<(), String> {
+    let path = Paths::get().history_file();
+
+    let mut names = read();
+    names.retain(|entry| !entry.eq_ignore_ascii_case(name));
+    names.insert(0, name.to_string());
+    names.truncate(MAX_ENTRIES);
+
+    let mut content = names.join("\n");
+    content.push('\n');
+
+    verbose!("writing {}", path.display());
+
+    fs::create_dir_all(Paths::get().config_dir())
+        .and_then(|()| fs::write(&path, content))
+        .map_err(|e| format!("Unable to write {}: {e}", path.display()))
+}
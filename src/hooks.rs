@@ -0,0 +1,126 @@
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use themux::Theme;
+
+use crate::util::wait_timeout;
+use crate::verbose;
+
+/// How long a hook may run before it is stopped, unless the configuration
+/// file says otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// When a hook runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hook {
+    /// Before the theme is written. A failure leaves the theme unchanged.
+    PreApply,
+    /// After the theme is written, Termux has reloaded it and the generated
+    /// files are up to date. A failure is reported, but the theme stays.
+    PostApply,
+}
+
+impl Hook {
+    /// Returns the hook's name, as used in the configuration file.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PreApply => "pre-apply",
+            Self::PostApply => "post-apply",
+        }
+    }
+}
+
+/// The hook commands listed in the configuration file.
+#[derive(Clone, Debug)]
+pub struct Hooks {
+    /// The `pre-apply` commands. The first one that fails stops the command
+    /// with status 1 before anything is written.
+    pub pre_apply: Vec<String>,
+    /// The `post-apply` commands. A failure is reported and the rest still
+    /// run; the command then exits with status 1, keeping the new theme.
+    pub post_apply: Vec<String>,
+    /// How long each command may run before it is killed and counted as
+    /// failed. Set in whole seconds as `timeout`, 10 by default.
+    pub timeout: Duration,
+}
+
+impl Default for Hooks {
+    fn default() -> Self {
+        Self {
+            pre_apply: Vec::new(),
+            post_apply: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl Hooks {
+    /// Returns the commands run for a hook.
+    pub fn commands(&self, hook: Hook) -> &[String] {
+        match hook {
+            Hook::PreApply => &self.pre_apply,
+            Hook::PostApply => &self.post_apply,
+        }
+    }
+}
+
+/// Returns the environment variables that describe a theme, for hooks and
+/// `themux env`: `THEMUX_NAME`, `THEMUX_IS_DARK`, and for every slot a hex
+/// color and its channels, such as `THEMUX_COLOR4` and `THEMUX_COLOR4_RGB`.
+pub fn theme_env(theme: &Theme) -> Vec<(String, String)> {
+    let is_dark = if theme.background.is_dark() {
+        "true"
+    } else {
+        "false"
+    };
+
+    let mut vars = vec![
+        (String::from("THEMUX_NAME"), theme.name.to_string()),
+        (String::from("THEMUX_IS_DARK"), String::from(is_dark)),
+    ];
+
+    for (slot, color) in theme.slots() {
+        let key = format!("THEMUX_{}", slot.to_uppercase());
+        let rgb = (
+            format!("{key}_RGB"),
+            format!("{},{},{}", color.r, color.g, color.b),
+        );
+
+        vars.push((key, color.to_string()));
+        vars.push(rgb);
+    }
+
+    vars
+}
+
+/// Runs a hook command with `sh -c`, stopping it if it takes longer than
+/// `timeout`.
+pub fn run(cmd: &str, hook: Hook, theme: &Theme, timeout: Duration) -> Result<(), String> {
+    verbose!("running {} hook: {cmd}", hook.name());
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .envs(theme_env(theme))
+        .env("THEMUX_HOOK", hook.name())
+        .stdin(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Unable to run the {} hook `{cmd}`: {e}", hook.name()))?;
+
+    match wait_timeout(&mut child, timeout) {
+        Ok(Some(status)) if status.success() => Ok(()),
+        Ok(Some(status)) => Err(format!(
+            "The {} hook `{cmd}` failed ({status})",
+            hook.name()
+        )),
+        Ok(None) => Err(format!(
+            "The {} hook `{cmd}` was stopped after {}s",
+            hook.name(),
+            timeout.as_secs()
+        )),
+        Err(e) => Err(format!(
+            "Unable to wait for the {} hook `{cmd}`: {e}",
+            hook.name()
+        )),
+    }
+}
@@ -0,0 +1,326 @@
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+
+use themux::Rgb;
+
+/// The size JPEG images are scaled down to while decoding. Their colors are
+/// sampled anyway, so decoding every pixel of a large photo would be wasted.
+const JPEG_SCALE: u16 = 512;
+
+/// Reads a PNG, JPEG or PPM image and returns its opaque pixels. The format
+/// is recognized from the content, not the file name.
+pub fn read(path: &Path) -> Result<Vec<Rgb>, String> {
+    let label = path.display();
+    let bytes = fs::read(path).map_err(|e| format!("Unable to read {label}: {e}"))?;
+
+    let pixels = match bytes.get(..2) {
+        Some(b"\x89P") => png(&bytes),
+        Some(b"\xFF\xD8") => jpeg(&bytes),
+        Some(b"P3" | b"P6") => ppm(&bytes),
+        _ => Err(String::from("not a PNG, JPEG or PPM image")),
+    };
+
+    pixels.map_err(|e| format!("Unable to decode {label}: {e}"))
+}
+
+// Decodes a PNG image, skipping pixels that are mostly transparent.
+fn png(bytes: &[u8]) -> Result<Vec<Rgb>, String> {
+    let mut decoder = png::Decoder::new(Cursor::new(bytes));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info().map_err(|e| e.to_string())?;
+    let size = reader
+        .output_buffer_size()
+        .ok_or("the image is too large")?;
+    let mut buf = vec![0; size];
+    let info = reader.next_frame(&mut buf).map_err(|e| e.to_string())?;
+    let data = &buf[..info.buffer_size()];
+
+    let pixels = match info.color_type {
+        png::ColorType::Grayscale => data.iter().map(|&v| Rgb::new(v, v, v)).collect(),
+        png::ColorType::GrayscaleAlpha => data
+            .chunks_exact(2)
+            .filter(|px| px[1] >= 0x80)
+            .map(|px| Rgb::new(px[0], px[0], px[0]))
+            .collect(),
+        png::ColorType::Rgb => data
+            .chunks_exact(3)
+            .map(|px| Rgb::new(px[0], px[1], px[2]))
+            .collect(),
+        png::ColorType::Rgba => data
+            .chunks_exact(4)
+            .filter(|px| px[3] >= 0x80)
+            .map(|px| Rgb::new(px[0], px[1], px[2]))
+            .collect(),
+        // Palettes are expanded by the transformations.
+        png::ColorType::Indexed => return Err(String::from("unexpected indexed colors")),
+    };
+
+    Ok(pixels)
+}
+
+// Decodes a JPEG image at a reduced size.
+fn jpeg(bytes: &[u8]) -> Result<Vec<Rgb>, String> {
+    let mut decoder = jpeg_decoder::Decoder::new(bytes);
+
+    decoder
+        .read_info()
+        .and_then(|()| decoder.scale(JPEG_SCALE, JPEG_SCALE))
+        .map_err(|e| e.to_string())?;
+
+    let data = decoder.decode().map_err(|e| e.to_string())?;
+    let format = decoder
+        .info()
+        .ok_or("missing image information")?
+        .pixel_format;
+
+    let pixels = match format {
+        jpeg_decoder::PixelFormat::L8 => data.iter().map(|&v| Rgb::new(v, v, v)).collect(),
+        // Samples are big-endian, so the first byte is the high one.
+        jpeg_decoder::PixelFormat::L16 => data
+            .chunks_exact(2)
+            .map(|px| Rgb::new(px[0], px[0], px[0]))
+            .collect(),
+        jpeg_decoder::PixelFormat::RGB24 => data
+            .chunks_exact(3)
+            .map(|px| Rgb::new(px[0], px[1], px[2]))
+            .collect(),
+        jpeg_decoder::PixelFormat::CMYK32 => data
+            .chunks_exact(4)
+            .map(|px| {
+                let [c, m, y, k] = [px[0], px[1], px[2], px[3]].map(u16::from);
+                let channel =
+                    |ink: u16| u8::try_from((255 - ink) * (255 - k) / 255).unwrap_or(u8::MAX);
+                Rgb::new(channel(c), channel(m), channel(y))
+            })
+            .collect(),
+    };
+
+    Ok(pixels)
+}
+
+// Decodes a binary (P6) or plain (P3) PPM image.
+fn ppm(bytes: &[u8]) -> Result<Vec<Rgb>, String> {
+    let mut pos = 2;
+
+    // Reads the next number, skipping whitespace and comments.
+    let number = |pos: &mut usize| -> Option<u32> {
+        loop {
+            match bytes.get(*pos) {
+                Some(b) if b.is_ascii_whitespace() => *pos += 1,
+                Some(b'#') => {
+                    while bytes.get(*pos).is_some_and(|&b| b != b'\n') {
+                        *pos += 1;
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        let start = *pos;
+
+        while bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+            *pos += 1;
+        }
+
+        std::str::from_utf8(&bytes[start..*pos]).ok()?.parse().ok()
+    };
+
+    let mut header = || number(&mut pos).ok_or_else(|| String::from("malformed header"));
+
+    let width = header()?;
+    let height = header()?;
+    let max = header()?;
+
+    if max == 0 || max > 0xFFFF {
+        return Err(format!("invalid maximum value {max}"));
+    }
+
+    let too_large = || String::from("the image is too large");
+    let truncated = || String::from("the image data is truncated");
+
+    let count = u64::from(width)
+        .checked_mul(u64::from(height))
+        .and_then(|pixels| usize::try_from(pixels).ok())
+        .ok_or_else(too_large)?;
+
+    let scale = |value: u32| u8::try_from(value.min(max) * 255 / max).unwrap_or(u8::MAX);
+
+    if &bytes[..2] == b"P6" {
+        let sample_size = if max < 0x100 { 1 } else { 2 };
+        let size = count.checked_mul(3 * sample_size).ok_or_else(too_large)?;
+
+        // A single whitespace character separates the header from the data.
+        let data = bytes
+            .get(pos + 1..)
+            .and_then(|data| data.get(..size))
+            .ok_or_else(truncated)?;
+
+        let sample = |v: &[u8]| match v {
+            [high, low] => u32::from(u16::from_be_bytes([*high, *low])),
+            _ => u32::from(v[0]),
+        };
+
+        return Ok(data
+            .chunks_exact(3 * sample_size)
+            .map(|px| {
+                let [r, g, b] =
+                    [0, 1, 2].map(|i| scale(sample(&px[i * sample_size..][..sample_size])));
+                Rgb::new(r, g, b)
+            })
+            .collect());
+    }
+
+    // Each sample takes at least a digit and a separator, so this stops an
+    // absurd header from reserving more memory than the file could fill.
+    if count.saturating_mul(6) > bytes.len() - pos + 1 {
+        return Err(truncated());
+    }
+
+    let mut pixels = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let mut sample = || number(&mut pos).map(scale).ok_or_else(truncated);
+        pixels.push(Rgb::new(sample()?, sample()?, sample()?));
+    }
+
+    Ok(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Encodes a one-row PNG image.
+    fn encode_png(color: png::ColorType, width: u32, data: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, width, 1);
+        encoder.set_color(color);
+
+        if color == png::ColorType::Indexed {
+            encoder.set_palette(vec![10, 20, 30, 200, 100, 50]);
+        }
+
+        encoder
+            .write_header()
+            .unwrap()
+            .write_image_data(data)
+            .unwrap();
+        bytes
+    }
+
+    // Returns true if two colors differ by at most `tolerance` in each channel.
+    fn is_close(a: Rgb, b: Rgb, tolerance: u8) -> bool {
+        a.r.abs_diff(b.r) <= tolerance
+            && a.g.abs_diff(b.g) <= tolerance
+            && a.b.abs_diff(b.b) <= tolerance
+    }
+
+    #[test]
+    fn ppm_binary() {
+        let mut bytes = b"P6\n# made by hand\n2 1\n255\n".to_vec();
+        bytes.extend([255, 0, 0, 0, 128, 255]);
+
+        let pixels = ppm(&bytes).unwrap();
+
+        assert_eq!(pixels, [Rgb::new(255, 0, 0), Rgb::new(0, 128, 255)]);
+    }
+
+    #[test]
+    fn ppm_binary_with_two_byte_samples() {
+        let mut bytes = b"P6 1 1 65535\n".to_vec();
+        bytes.extend([0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00]);
+
+        assert_eq!(ppm(&bytes).unwrap(), [Rgb::new(255, 127, 0)]);
+    }
+
+    #[test]
+    fn ppm_plain_scales_samples() {
+        let bytes = b"P3\n3 1 # width and height\n15\n0 0 0  15 15 15\n15 0 0\n";
+
+        let pixels = ppm(bytes).unwrap();
+
+        assert_eq!(
+            pixels,
+            [
+                Rgb::new(0, 0, 0),
+                Rgb::new(255, 255, 255),
+                Rgb::new(255, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn ppm_truncated() {
+        let mut bytes = b"P6 2 1 255\n".to_vec();
+        bytes.extend([1, 2, 3, 4]);
+
+        assert_eq!(ppm(&bytes).unwrap_err(), "the image data is truncated");
+        assert_eq!(
+            ppm(b"P3 2 1 255 1 2 3 4").unwrap_err(),
+            "the image data is truncated"
+        );
+    }
+
+    #[test]
+    fn ppm_too_large() {
+        let huge = b"P6 4294967295 4294967295 255\n";
+        assert_eq!(ppm(huge).unwrap_err(), "the image is too large");
+
+        // Plain images are checked against the size of the file first.
+        let huge = b"P3 4294967295 4294967295 255\n1 2 3\n";
+        assert_eq!(ppm(huge).unwrap_err(), "the image data is truncated");
+    }
+
+    #[test]
+    fn ppm_bad_header() {
+        assert_eq!(ppm(b"P6 2 x 255\n").unwrap_err(), "malformed header");
+        assert_eq!(
+            ppm(b"P6 1 1 0\n\0\0\0").unwrap_err(),
+            "invalid maximum value 0"
+        );
+    }
+
+    #[test]
+    fn png_skips_transparent_pixels() {
+        let data = [255, 0, 0, 255, 0, 255, 0, 0, 0, 0, 255, 200];
+        let pixels = png(&encode_png(png::ColorType::Rgba, 3, &data)).unwrap();
+
+        assert_eq!(pixels, [Rgb::new(255, 0, 0), Rgb::new(0, 0, 255)]);
+    }
+
+    #[test]
+    fn png_expands_gray_and_palettes() {
+        let gray = png(&encode_png(png::ColorType::Grayscale, 2, &[0, 200])).unwrap();
+        assert_eq!(gray, [Rgb::new(0, 0, 0), Rgb::new(200, 200, 200)]);
+
+        let indexed = png(&encode_png(png::ColorType::Indexed, 2, &[1, 0])).unwrap();
+        assert_eq!(indexed, [Rgb::new(200, 100, 50), Rgb::new(10, 20, 30)]);
+    }
+
+    #[test]
+    fn jpeg_rgb_and_cmyk() {
+        let red = Rgb::new(200, 30, 40);
+        let blue = Rgb::new(30, 60, 200);
+
+        for bytes in [
+            &include_bytes!("../tests/fixtures/red-blue.jpg")[..],
+            &include_bytes!("../tests/fixtures/red-blue-cmyk.jpg")[..],
+        ] {
+            let pixels = jpeg(bytes).unwrap();
+
+            // The image is 16 by 8 pixels, red on the left and blue on the
+            // right. Lossy compression blurs the edge between them.
+            assert_eq!(pixels.len(), 128);
+            assert!(is_close(pixels[2], red, 12), "{}", pixels[2]);
+            assert!(is_close(pixels[13], blue, 12), "{}", pixels[13]);
+        }
+    }
+
+    #[test]
+    fn corrupt_images() {
+        assert!(png(b"\x89PNG\r\n\x1a\n\0\0").is_err());
+        assert!(jpeg(b"\xFF\xD8\xFF").is_err());
+    }
+}
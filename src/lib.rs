@@ -0,0 +1,45 @@
+//! # themux
+//!
+//! The theme catalog behind the `themux` command-line tool, for use by other
+//! tools that want to share Termux color themes.
+//!
+//! ```
+//! use themux::Themes;
+//!
+//! let themes = Themes::init();
+//! let theme = themes.resolve("tokyo-night").unwrap();
+//!
+//! assert_eq!(theme.name, "Tokyo Night");
+//! assert!(theme.background.is_dark());
+//! ```
+//!
+//! Enable the `serde` feature to serialize and deserialize `Theme` and `Rgb`
+//! values, with colors represented as `"#RRGGBB"` strings.
+
+#![deny(clippy::all)]
+#![deny(clippy::cargo)]
+#![deny(clippy::complexity)]
+#![deny(clippy::correctness)]
+#![deny(clippy::nursery)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::perf)]
+#![deny(clippy::style)]
+#![deny(clippy::suspicious)]
+// png depends on two versions of miniz_oxide, one of them through flate2.
+#![allow(clippy::multiple_crate_versions)]
+
+mod color;
+mod data;
+mod error;
+pub mod generate;
+pub mod names;
+pub mod palette;
+mod phf;
+pub mod properties;
+pub mod query;
+pub mod template;
+mod theme;
+
+pub use color::Rgb;
+pub use error::{Error, Result};
+pub use theme::{Theme, Themes, Unresolved};
@@ -0,0 +1,378 @@
+use std::cmp::Reverse;
+use std::io::{self, BufWriter, IsTerminal, Result as IoResult, Write};
+
+use themux::Theme;
+
+use crate::style::{use_color, CLR, GRN};
+use crate::term;
+
+/// The spaces between columns.
+const GAP: usize = 2;
+
+/// The width of a swatch, including the space after it.
+const SWATCH_WIDTH: usize = 4;
+
+/// Whether names are arranged in columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Columns when writing to a terminal, otherwise one name per line.
+    #[default]
+    Auto,
+    /// Always columns.
+    Columns,
+    /// Always one name per line.
+    OnePerLine,
+}
+
+/// The order in which themes are listed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortKey {
+    /// Alphabetically by name.
+    #[default]
+    Name,
+    /// From the darkest background to the lightest.
+    Brightness,
+    /// From the lowest foreground/background contrast to the highest.
+    Contrast,
+    /// Around the color wheel by background hue, with grays first.
+    Hue,
+    /// From the most muted palette to the most vivid.
+    Saturation,
+    /// The most recently applied themes first, then the rest by name.
+    Recent,
+}
+
+impl SortKey {
+    /// Parses a `--sort` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "name" => Some(Self::Name),
+            "brightness" => Some(Self::Brightness),
+            "contrast" => Some(Self::Contrast),
+            "hue" => Some(Self::Hue),
+            "saturation" => Some(Self::Saturation),
+            "recent" => Some(Self::Recent),
+            _ => None,
+        }
+    }
+}
+
+/// How themes are split into sections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupBy {
+    /// By how dark the background is.
+    Brightness,
+    /// By the first word of the name, e.g. "Solarized".
+    Family,
+    /// By the first letter of the name.
+    FirstLetter,
+}
+
+impl GroupBy {
+    /// Parses a `--group-by` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "brightness" => Some(Self::Brightness),
+            "family" => Some(Self::Family),
+            "first-letter" => Some(Self::FirstLetter),
+            _ => None,
+        }
+    }
+}
+
+/// How to lay out a list of themes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Layout {
+    /// Whether names are arranged in columns.
+    pub mode: Mode,
+    /// Print a background/foreground swatch before each name.
+    pub swatch: bool,
+    /// Start a section with a header for each group.
+    pub group_by: Option<GroupBy>,
+}
+
+/// Sorts themes by `key`, breaking ties by name. `recent` holds the names
+/// of recently applied themes, most recent first.
+pub fn sort(themes: &mut [&Theme], key: SortKey, recent: &[String]) {
+    // Themes arrive sorted by name, and stable sorts keep that order for
+    // ties.
+    match key {
+        SortKey::Name => themes.sort(),
+        SortKey::Brightness => {
+            themes.sort_by(|a, b| a.brightness().total_cmp(&b.brightness()));
+        }
+        SortKey::Contrast => themes.sort_by(|a, b| a.contrast().total_cmp(&b.contrast())),
+        SortKey::Hue => themes.sort_by(|a, b| match (a.hue(), b.hue()) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        }),
+        SortKey::Saturation => {
+            themes.sort_by(|a, b| a.saturation().total_cmp(&b.saturation()));
+        }
+        SortKey::Recent => themes.sort_by_key(|theme| {
+            Reverse(
+                recent
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(&theme.name))
+                    .map(Reverse),
+            )
+        }),
+    }
+}
+
+/// Prints theme names to stdout.
+///
+/// Like `ls`, names are arranged in columns sized to the terminal when
+/// writing to one, and printed one per line otherwise.
+pub fn print_list<'a, I>(themes: I, layout: Layout) -> IoResult<()>
+where
+    I: IntoIterator<Item = &'a Theme>,
+{
+    let stdout = io::stdout().lock();
+
+    let is_term = stdout.is_terminal();
+    let is_color = use_color(is_term);
+
+    let mut out = BufWriter::new(stdout);
+
+    let themes = themes.into_iter().collect::<Vec<&Theme>>();
+
+    let groups = match layout.group_by {
+        Some(group_by) => sections(&themes, group_by),
+        None => vec![(None, themes)],
+    };
+
+    let one_per_line = match layout.mode {
+        Mode::Auto => !is_term,
+        Mode::Columns => false,
+        Mode::OnePerLine => true,
+    };
+    let swatch = layout.swatch && is_color;
+
+    for (idx, (header, themes)) in groups.iter().enumerate() {
+        if let Some(header) = header {
+            writeln!(
+                out,
+                "{}{}{header}{}",
+                if idx == 0 { "" } else { "\n" },
+                if is_color { GRN } else { "" },
+                if is_color { CLR } else { "" }
+            )?;
+        }
+
+        if one_per_line {
+            for theme in themes {
+                write_item(&mut out, theme, swatch, 0)?;
+                writeln!(out)?;
+            }
+        } else {
+            write_columns(&mut out, themes, swatch, term::width())?;
+        }
+    }
+
+    out.flush()
+}
+
+// Splits themes into sections, keeping their order within each section.
+fn sections<'a>(themes: &[&'a Theme], group_by: GroupBy) -> Vec<(Option<String>, Vec<&'a Theme>)> {
+    let mut groups: Vec<(Section, Vec<&Theme>)> = Vec::new();
+
+    for &theme in themes {
+        let section = Section::of(theme, group_by);
+
+        match groups
+            .iter_mut()
+            .find(|(other, _)| other.key == section.key)
+        {
+            Some((_, group)) => group.push(theme),
+            None => groups.push((section, vec![theme])),
+        }
+    }
+
+    // Families of one are gathered at the end.
+    if group_by == GroupBy::Family {
+        let (mut families, singles): (Vec<_>, Vec<_>) =
+            groups.into_iter().partition(|(_, group)| group.len() > 1);
+
+        if !singles.is_empty() {
+            let mut others = singles
+                .into_iter()
+                .flat_map(|(_, group)| group)
+                .collect::<Vec<&Theme>>();
+
+            // Keep the caller's order among the other themes.
+            others.sort_by_key(|theme| themes.iter().position(|t| t == theme));
+
+            let section = Section {
+                rank: 1,
+                key: String::new(),
+                header: "Other".into(),
+            };
+            families.push((section, others));
+        }
+
+        groups = families;
+    }
+
+    groups.sort_by(|(a, _), (b, _)| (a.rank, &a.key).cmp(&(b.rank, &b.key)));
+
+    groups
+        .into_iter()
+        .map(|(section, group)| (Some(section.header), group))
+        .collect()
+}
+
+/// A section of a grouped list.
+struct Section {
+    /// Sections are ordered by rank, then by key.
+    rank: usize,
+    /// Identifies the section.
+    key: String,
+    /// The section's header.
+    header: String,
+}
+
+impl Section {
+    /// Background luminance upper bounds and headers for brightness groups.
+    const BRIGHTNESS: [(f64, &'static str); 4] = [
+        (0.01, "Very dark"),
+        (0.05, "Dark"),
+        (0.4, "Medium"),
+        (f64::INFINITY, "Light"),
+    ];
+
+    // Returns the section a theme belongs to.
+    fn of(theme: &Theme, group_by: GroupBy) -> Self {
+        match group_by {
+            GroupBy::Brightness => {
+                let brightness = theme.brightness();
+
+                let rank = Self::BRIGHTNESS
+                    .iter()
+                    .position(|&(max, _)| brightness < max)
+                    .unwrap_or(Self::BRIGHTNESS.len() - 1);
+
+                let header = Self::BRIGHTNESS[rank].1;
+
+                Self {
+                    rank,
+                    key: header.into(),
+                    header: header.into(),
+                }
+            }
+            GroupBy::Family => {
+                let family = theme.name.split_whitespace().next().unwrap_or_default();
+
+                Self {
+                    rank: 0,
+                    key: family.to_lowercase(),
+                    header: family.into(),
+                }
+            }
+            // Names starting with a digit or symbol go under "#".
+            GroupBy::FirstLetter => {
+                let header = match theme.name.chars().next() {
+                    Some(c) if c.is_alphabetic() => c.to_uppercase().to_string(),
+                    _ => String::from("#"),
+                };
+
+                Self {
+                    rank: usize::from(header != "#"),
+                    key: header.clone(),
+                    header,
+                }
+            }
+        }
+    }
+}
+
+// Writes names in columns filled top to bottom, using as many columns as
+// fit in `width`.
+fn write_columns<W: Write>(
+    out: &mut W,
+    themes: &[&Theme],
+    swatch: bool,
+    width: usize,
+) -> IoResult<()> {
+    let extra = if swatch { SWATCH_WIDTH } else { 0 };
+
+    let widths = themes
+        .iter()
+        .map(|theme| theme.name.chars().count() + extra)
+        .collect::<Vec<usize>>();
+
+    let (rows, col_widths) = fit_columns(&widths, width);
+
+    for row in 0..rows {
+        let mut col = 0;
+
+        while let Some(theme) = themes.get(col * rows + row) {
+            let is_last = themes.get((col + 1) * rows + row).is_none();
+
+            // The last name in a row isn't padded.
+            let pad = if is_last { 0 } else { col_widths[col] + GAP };
+
+            write_item(out, theme, swatch, pad)?;
+            col += 1;
+        }
+
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+// Returns the number of rows and the column widths of the layout with the
+// most columns that fits in `width`. A single column is used if nothing
+// fits.
+fn fit_columns(widths: &[usize], width: usize) -> (usize, Vec<usize>) {
+    let count = widths.len();
+
+    for cols in (1..=count).rev() {
+        let rows = count.div_ceil(cols);
+
+        // Avoid layouts with empty trailing columns.
+        if (cols - 1) * rows >= count {
+            continue;
+        }
+
+        let col_widths = widths
+            .chunks(rows)
+            .map(|chunk| chunk.iter().copied().max().unwrap_or(0))
+            .collect::<Vec<usize>>();
+
+        let total = col_widths.iter().sum::<usize>() + GAP * (col_widths.len() - 1);
+
+        if total <= width || cols == 1 {
+            return (rows, col_widths);
+        }
+    }
+
+    (0, Vec::new())
+}
+
+// Writes a theme name, with an optional leading swatch, padded to `pad`
+// characters.
+fn write_item<W: Write>(out: &mut W, theme: &Theme, swatch: bool, pad: usize) -> IoResult<()> {
+    let mut len = theme.name.chars().count();
+
+    if swatch {
+        let (bg, fg) = (theme.background, theme.foreground);
+
+        write!(
+            out,
+            "\x1b[48;2;{};{};{}m\x1b[38;2;{};{};{}m Aa{CLR} ",
+            bg.r, bg.g, bg.b, fg.r, fg.g, fg.b
+        )?;
+
+        len += SWATCH_WIDTH;
+    }
+
+    write!(
+        out,
+        "{}{:pad$}",
+        theme.name,
+        "",
+        pad = pad.saturating_sub(len)
+    )
+}
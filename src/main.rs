@@ -17,22 +17,34 @@
 #![deny(clippy::perf)]
 #![deny(clippy::style)]
 #![deny(clippy::suspicious)]
+// png depends on two versions of miniz_oxide, one of them through flate2.
+#![allow(clippy::multiple_crate_versions)]
 
+mod args;
 mod cli;
-mod data;
+mod config;
+mod diff;
+mod document;
+mod editor;
+mod font;
+mod history;
+mod hooks;
+mod image;
+mod list;
+mod profile;
 mod style;
+mod templates;
+mod term;
+mod termux;
 mod tui;
+mod ui;
+mod user_themes;
 mod util;
+mod watch;
 
 use cli::Cli;
-use util::is_termux_env;
 
 fn main() -> std::process::ExitCode {
-    // Exit if not a Termux environment.
-    if !is_termux_env() {
-        fail!("Not a Termux environment. Exiting.");
-    }
-
     // Parse and handle command-line arguments.
     Cli::handle_args()
 }
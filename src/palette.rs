@@ -0,0 +1,625 @@
+//! Derives a complete theme from a single base color, or from the colors of
+//! an image.
+//!
+//! The palette is built in Oklch, so that colors of the same lightness look
+//! equally light whatever their hue. The base color tints the background,
+//! foreground and grays, and pulls the ANSI hues slightly toward its own,
+//! while red stays red and green stays green.
+//!
+//! ```
+//! use themux::palette::{self, Style};
+//! use themux::Rgb;
+//!
+//! let base: Rgb = "#5E81AC".parse().unwrap();
+//! let theme = palette::generate(base, true, Style::Vivid);
+//!
+//! assert!(theme.background.is_dark());
+//! assert!(theme.foreground.contrast(theme.background) >= 7.0);
+//! ```
+
+use std::borrow::Cow;
+use std::ptr;
+
+use crate::color::Rgb;
+use crate::theme::Theme;
+
+/// How colorful a generated palette is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// Saturated accents on a lightly tinted background.
+    #[default]
+    Vivid,
+    /// Subdued accents and nearly neutral grays.
+    Muted,
+    /// Soft, light accents.
+    Pastel,
+}
+
+impl Style {
+    /// Every style, in the order they are listed.
+    pub const ALL: [Self; 3] = [Self::Vivid, Self::Muted, Self::Pastel];
+
+    /// Returns the name used to select the style.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Vivid => "vivid",
+            Self::Muted => "muted",
+            Self::Pastel => "pastel",
+        }
+    }
+
+    /// Returns the style with the given name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|style| style.name() == name)
+    }
+
+    // Returns the Oklch chroma of the dim accents.
+    const fn chroma(self) -> f64 {
+        match self {
+            Self::Vivid => 0.17,
+            Self::Muted => 0.07,
+            Self::Pastel => 0.09,
+        }
+    }
+
+    // Returns the Oklch lightness of the dim accents.
+    const fn lightness(self, dark: bool) -> f64 {
+        match (self, dark) {
+            (Self::Vivid, true) => 0.72,
+            (Self::Muted, true) => 0.70,
+            (Self::Pastel, true) => 0.82,
+            (Self::Vivid, false) => 0.55,
+            (Self::Muted, false) => 0.52,
+            (Self::Pastel, false) => 0.62,
+        }
+    }
+
+    // Returns the most chroma the base color may give the grays.
+    const fn tint(self) -> f64 {
+        match self {
+            Self::Vivid => 0.02,
+            Self::Muted => 0.008,
+            Self::Pastel => 0.015,
+        }
+    }
+}
+
+/// The Oklch hues of red, green, yellow, blue, magenta and cyan, in ANSI
+/// order.
+const ACCENT_HUES: [f64; 6] = [27.0, 145.0, 100.0, 255.0, 325.0, 195.0];
+
+/// How far, in degrees, an accent hue may be pulled toward the base hue.
+const MAX_HUE_SHIFT: f64 = 8.0;
+
+/// The least contrast the foreground has against the background.
+const TEXT_CONTRAST: f64 = 7.0;
+
+/// The least contrast the dim accents have against the background.
+const ACCENT_CONTRAST: f64 = 4.5;
+
+/// The least contrast the cursor, the gray and the bright accents of light
+/// themes have against the background.
+const BRIGHT_CONTRAST: f64 = 3.0;
+
+/// The most pixels of an image that are clustered. Larger images are sampled
+/// evenly.
+const MAX_SAMPLES: usize = 20_000;
+
+/// The number of clusters an image's colors are grouped into.
+const CLUSTERS: usize = 16;
+
+/// The most rounds of k-means clustering.
+const MAX_ROUNDS: usize = 30;
+
+/// The least chroma for a cluster to count as a color rather than a gray.
+const MIN_ACCENT_CHROMA: f64 = 0.03;
+
+/// How far, in degrees, a cluster's hue may be from an accent's to be used
+/// for it.
+const MAX_HUE_DISTANCE: f64 = 40.0;
+
+/// Generates a theme from a base color, with a dark or light background, in
+/// the given style. The theme is named after the base color.
+#[must_use]
+pub fn generate(base: Rgb, dark: bool, style: Style) -> Theme {
+    let (_, base_chroma, base_hue) = base.to_oklch();
+
+    // Grays take on the base hue, but never more than a hint of it.
+    let tint = base_chroma.min(style.tint());
+
+    let recipe = Recipe {
+        dark,
+        background: (if dark { 0.21 } else { 0.97 }, tint, base_hue),
+        foreground: (if dark { 0.90 } else { 0.32 }, tint, base_hue),
+        tint: (tint, base_hue),
+        lightness: style.lightness(dark),
+        accents: ACCENT_HUES.map(|hue| (style.chroma(), harmonize(hue, base_hue, base_chroma))),
+        // A gray base leaves nothing to color the cursor with.
+        cursor: (base_chroma >= 0.02).then(|| (base_chroma.max(style.chroma()), base_hue)),
+    };
+
+    recipe.build(format!("Generated {base}"))
+}
+
+/// Extracts a theme from the pixels of an image, or returns `None` if there
+/// are none.
+///
+/// The background is dark if `dark` is true, light if it is false, or
+/// whichever suits the image when it is `None`.
+///
+/// The colors are grouped into clusters in Oklab. The background and
+/// foreground come from the darkest and lightest clusters, and each ANSI
+/// color from the cluster closest to it in hue, adjusted to be legible.
+#[must_use]
+pub fn extract(pixels: &[Rgb], dark: Option<bool>) -> Option<Theme> {
+    let clusters = clusters(pixels);
+    let total: usize = clusters.iter().map(|cluster| cluster.weight).sum();
+
+    if total == 0 {
+        return None;
+    }
+
+    let dark = dark.unwrap_or_else(|| {
+        let lightness: f64 = clusters.iter().map(|c| c.lab[0] * to_f64(c.weight)).sum();
+        lightness / to_f64(total) < 0.5
+    });
+
+    // The background is the darkest (or lightest) color that covers a fair
+    // part of the image, so that a few stray pixels can't decide it.
+    let by_lightness = |a: &&Cluster, b: &&Cluster| a.lab[0].total_cmp(&b.lab[0]);
+    let large = clusters
+        .iter()
+        .filter(|cluster| cluster.weight * 20 >= total);
+
+    let background = if dark {
+        large.min_by(by_lightness)
+    } else {
+        large.max_by(by_lightness)
+    };
+
+    let foreground = if dark {
+        clusters.iter().max_by(by_lightness)
+    } else {
+        clusters.iter().min_by(by_lightness)
+    };
+
+    let (Some(background), Some(foreground)) = (background, foreground) else {
+        return None;
+    };
+
+    // The accents come from the other clusters that are colorful enough. The
+    // foreground keeps little of its cluster's color, so that cluster can
+    // still be an accent.
+    let colors: Vec<(f64, f64, f64, usize)> = clusters
+        .iter()
+        .filter(|&cluster| !ptr::eq(cluster, background))
+        .map(|cluster| {
+            let (l, c, h) = cluster.lch();
+            (l, c, h, cluster.weight)
+        })
+        .filter(|&(_, chroma, ..)| chroma >= MIN_ACCENT_CHROMA)
+        .collect();
+
+    // Each accent takes the closest unused color in hue, closest pairs first.
+    let mut pairs: Vec<(f64, usize, usize)> = ACCENT_HUES
+        .iter()
+        .enumerate()
+        .flat_map(|(slot, &hue)| {
+            colors
+                .iter()
+                .enumerate()
+                .map(move |(i, color)| (hue_distance(hue, color.2), slot, i))
+        })
+        .filter(|&(distance, ..)| distance <= MAX_HUE_DISTANCE)
+        .collect();
+
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut matches: [Option<usize>; 6] = [None; 6];
+
+    for (_, slot, i) in pairs {
+        if matches[slot].is_none() && !matches.contains(&Some(i)) {
+            matches[slot] = Some(i);
+        }
+    }
+
+    // The most common color sets the cursor and pulls the accents the image
+    // lacks toward its hue.
+    let dominant = colors.iter().max_by_key(|color| color.3);
+    let (dominant_chroma, dominant_hue) = dominant.map_or((0.0, 0.0), |color| (color.1, color.2));
+
+    let accents = std::array::from_fn(|slot| {
+        matches[slot].map_or_else(
+            || {
+                let hue = harmonize(ACCENT_HUES[slot], dominant_hue, dominant_chroma);
+                (dominant_chroma.clamp(0.06, 0.15), hue)
+            },
+            |i| (colors[i].1.clamp(0.06, 0.2), colors[i].2),
+        )
+    });
+
+    // The accents keep the image's lightness, within what reads well.
+    let used: Vec<_> = matches.iter().flatten().map(|&i| colors[i]).collect();
+    let lightness = if used.is_empty() {
+        if dark {
+            0.72
+        } else {
+            0.55
+        }
+    } else {
+        let sum: f64 = used.iter().map(|color| color.0).sum();
+        let mean = sum / to_f64(used.len());
+        if dark {
+            mean.clamp(0.65, 0.8)
+        } else {
+            mean.clamp(0.45, 0.6)
+        }
+    };
+
+    let (l, c, h) = background.lch();
+
+    let recipe = Recipe {
+        dark,
+        background: if dark {
+            (l.min(0.25), c.min(0.05), h)
+        } else {
+            (l.max(0.93), c.min(0.03), h)
+        },
+        foreground: {
+            let (l, c, h) = foreground.lch();
+            (if dark { l.max(0.86) } else { l.min(0.35) }, c.min(0.04), h)
+        },
+        tint: (c.min(0.02), h),
+        lightness,
+        accents,
+        cursor: dominant.map(|color| (color.1, color.2)),
+    };
+
+    Some(recipe.build(String::from("Extracted")))
+}
+
+// What a theme is built from, in Oklch coordinates.
+struct Recipe {
+    // Whether the background is dark.
+    dark: bool,
+    // The lightness, chroma and hue of the background.
+    background: (f64, f64, f64),
+    // The lightness, chroma and hue of the foreground, before it is made
+    // legible.
+    foreground: (f64, f64, f64),
+    // The chroma and hue the grays are tinted with.
+    tint: (f64, f64),
+    // The lightness of the dim accents.
+    lightness: f64,
+    // The chroma and hue of red, green, yellow, blue, magenta and cyan.
+    accents: [(f64, f64); 6],
+    // The chroma and hue of the cursor, or `None` to use the foreground.
+    cursor: Option<(f64, f64)>,
+}
+
+impl Recipe {
+    // Builds the theme, with each color made legible against the background.
+    fn build(&self, name: String) -> Theme {
+        let dark = self.dark;
+        let (tint, tint_hue) = self.tint;
+        let gray = |lightness: f64| Rgb::from_oklch(lightness, tint, tint_hue);
+
+        let (l, c, h) = self.background;
+        let background = Rgb::from_oklch(l, c, h);
+
+        // Moves a color away from the background until it is legible.
+        let legible = |lightness: f64, chroma: f64, hue: f64, min: f64| {
+            let step = if dark { 0.01 } else { -0.01 };
+            let mut lightness = lightness;
+            let mut color = Rgb::from_oklch(lightness, chroma, hue);
+
+            while color.contrast(background) < min && (0.0..=1.0).contains(&(lightness + step)) {
+                lightness += step;
+                color = Rgb::from_oklch(lightness, chroma, hue);
+            }
+
+            color
+        };
+
+        // The dim accents, then the bright ones: lighter and more colorful on
+        // dark backgrounds, more colorful on light ones.
+        let accents = |bright: bool| {
+            self.accents.map(|(mut chroma, hue)| {
+                let mut lightness = self.lightness;
+
+                // Yellow only looks yellow when it is light.
+                if dark && hue > 70.0 && hue < 120.0 {
+                    lightness += 0.08;
+                }
+
+                if bright {
+                    lightness += if dark { 0.07 } else { 0.03 };
+                    chroma *= 1.15;
+                }
+
+                let min = if bright && !dark {
+                    BRIGHT_CONTRAST
+                } else {
+                    ACCENT_CONTRAST
+                };
+
+                legible(lightness, chroma, hue, min)
+            })
+        };
+
+        let dim = accents(false);
+        let bright = accents(true);
+
+        let (l, c, h) = self.foreground;
+        let foreground = legible(l, c, h, TEXT_CONTRAST);
+
+        let cursor = self.cursor.map_or(foreground, |(chroma, hue)| {
+            legible(self.lightness, chroma, hue, BRIGHT_CONTRAST)
+        });
+
+        Theme {
+            color0: gray(if dark { 0.30 } else { 0.26 }),
+            color1: dim[0],
+            color2: dim[1],
+            color3: dim[2],
+            color4: dim[3],
+            color5: dim[4],
+            color6: dim[5],
+            color7: gray(if dark { 0.80 } else { 0.86 }),
+            color8: legible(0.55, tint, tint_hue, BRIGHT_CONTRAST),
+            color9: bright[0],
+            color10: bright[1],
+            color11: bright[2],
+            color12: bright[3],
+            color13: bright[4],
+            color14: bright[5],
+            color15: gray(if dark { 0.96 } else { 0.99 }),
+            background,
+            foreground,
+            cursor,
+            name: Cow::Owned(name),
+        }
+    }
+}
+
+// A group of similar colors in an image.
+struct Cluster {
+    // The mean color, in Oklab.
+    lab: [f64; 3],
+    // The number of sampled pixels in the group.
+    weight: usize,
+}
+
+impl Cluster {
+    // Returns the mean color in Oklch.
+    fn lch(&self) -> (f64, f64, f64) {
+        let [l, a, b] = self.lab;
+        (l, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0))
+    }
+}
+
+// Groups the colors of an image with k-means clustering in Oklab, starting
+// from colors spread as far apart as possible so that the result doesn't
+// depend on chance.
+fn clusters(pixels: &[Rgb]) -> Vec<Cluster> {
+    let step = pixels.len().div_ceil(MAX_SAMPLES).max(1);
+    let samples: Vec<[f64; 3]> = pixels
+        .iter()
+        .step_by(step)
+        .map(|color| color.to_oklab().into())
+        .collect();
+
+    let Some(first) = samples.first() else {
+        return Vec::new();
+    };
+
+    let mut centers = vec![*first];
+
+    while centers.len() < CLUSTERS {
+        let farthest = samples
+            .iter()
+            .map(|sample| (nearest(&centers, sample).1, sample))
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+
+        match farthest {
+            Some((distance, sample)) if distance > 1e-6 => centers.push(*sample),
+            // Every sample is one of the centers already.
+            _ => break,
+        }
+    }
+
+    let mut labels = vec![usize::MAX; samples.len()];
+
+    for _ in 0..MAX_ROUNDS {
+        let mut changed = false;
+
+        for (label, sample) in labels.iter_mut().zip(&samples) {
+            let (nearest, _) = nearest(&centers, sample);
+            changed |= *label != nearest;
+            *label = nearest;
+        }
+
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![([0.0; 3], 0_usize); centers.len()];
+
+        for (&label, sample) in labels.iter().zip(&samples) {
+            let (sum, count) = &mut sums[label];
+            for (total, value) in sum.iter_mut().zip(sample) {
+                *total += value;
+            }
+            *count += 1;
+        }
+
+        // A center that lost all its samples stays where it was.
+        for (center, (sum, count)) in centers.iter_mut().zip(&sums) {
+            if *count > 0 {
+                *center = sum.map(|total| total / to_f64(*count));
+            }
+        }
+    }
+
+    let mut clusters: Vec<Cluster> = centers
+        .into_iter()
+        .map(|lab| Cluster { lab, weight: 0 })
+        .collect();
+
+    for &label in &labels {
+        clusters[label].weight += 1;
+    }
+
+    clusters.retain(|cluster| cluster.weight > 0);
+    clusters
+}
+
+// Returns the index of the center nearest to a color, and the squared
+// distance to it.
+fn nearest(centers: &[[f64; 3]], color: &[f64; 3]) -> (usize, f64) {
+    centers
+        .iter()
+        .map(|center| {
+            center
+                .iter()
+                .zip(color)
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum::<f64>()
+        })
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("there is at least one center")
+}
+
+// Returns the angle between two hues, from 0 to 180 degrees.
+fn hue_distance(a: f64, b: f64) -> f64 {
+    let delta = (a - b).rem_euclid(360.0);
+    delta.min(360.0 - delta)
+}
+
+// Converts a count to a float for averaging.
+fn to_f64(count: usize) -> f64 {
+    f64::from(u32::try_from(count).unwrap_or(u32::MAX))
+}
+
+// Pulls an accent hue toward the base hue, by more the more colorful the base
+// is, but never so far that the accent changes color.
+fn harmonize(hue: f64, base_hue: f64, base_chroma: f64) -> f64 {
+    let delta = (base_hue - hue + 180.0).rem_euclid(360.0) - 180.0;
+    let strength = (base_chroma / 0.1).min(1.0);
+    let shift = (delta * 0.25).clamp(-MAX_HUE_SHIFT, MAX_HUE_SHIFT) * strength;
+
+    (hue + shift).rem_euclid(360.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_colors_are_legible() {
+        let bases = [
+            "#5E81AC", "#808080", "#FFFF00", "#C0392B", "#000000", "#FFFFFF",
+        ];
+
+        for base in bases.map(|hex| hex.parse::<Rgb>().unwrap()) {
+            for style in Style::ALL {
+                for dark in [true, false] {
+                    let theme = generate(base, dark, style);
+                    let contrast = |color: Rgb| color.contrast(theme.background);
+                    let case = format!("{base} {} dark={dark}", style.name());
+
+                    assert_eq!(theme.background.is_dark(), dark, "{case}");
+                    assert!(contrast(theme.foreground) >= TEXT_CONTRAST, "{case}");
+                    assert!(contrast(theme.color8) >= BRIGHT_CONTRAST, "{case}");
+                    assert!(contrast(theme.cursor) >= BRIGHT_CONTRAST, "{case}");
+
+                    let dim = [
+                        theme.color1,
+                        theme.color2,
+                        theme.color3,
+                        theme.color4,
+                        theme.color5,
+                        theme.color6,
+                    ];
+                    let bright = [
+                        theme.color9,
+                        theme.color10,
+                        theme.color11,
+                        theme.color12,
+                        theme.color13,
+                        theme.color14,
+                    ];
+                    let bright_min = if dark {
+                        ACCENT_CONTRAST
+                    } else {
+                        BRIGHT_CONTRAST
+                    };
+
+                    for color in dim {
+                        assert!(contrast(color) >= ACCENT_CONTRAST, "{case}: {color}");
+                    }
+
+                    for color in bright {
+                        assert!(contrast(color) >= bright_min, "{case}: {color}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generated_accents_keep_their_hues() {
+        // Even a strongly colored base only nudges the accents.
+        for base in ["#FFFF00", "#0000FF", "#FF00FF"] {
+            let theme = generate(base.parse().unwrap(), true, Style::Vivid);
+            let accents = [
+                theme.color1,
+                theme.color2,
+                theme.color3,
+                theme.color4,
+                theme.color5,
+                theme.color6,
+            ];
+
+            for (color, hue) in accents.into_iter().zip(ACCENT_HUES) {
+                let (_, _, actual) = color.to_oklch();
+                assert!(
+                    hue_distance(actual, hue) <= MAX_HUE_SHIFT + 1.0,
+                    "{base}: {color}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn extract_from_two_colors() {
+        let navy = Rgb::new(0x1B, 0x26, 0x4F);
+        let cream = Rgb::new(0xF2, 0xE8, 0xCF);
+
+        // Mostly navy, so dark unless asked otherwise.
+        let mut pixels = vec![navy; 300];
+        pixels.extend([cream; 100]);
+
+        for (dark, is_dark) in [(None, true), (Some(true), true), (Some(false), false)] {
+            let theme = extract(&pixels, dark).unwrap();
+
+            assert_eq!(theme.background.is_dark(), is_dark, "{dark:?}");
+            assert!(theme.foreground.contrast(theme.background) >= TEXT_CONTRAST);
+
+            for color in [theme.color1, theme.color2, theme.color3, theme.color4] {
+                assert!(
+                    color.contrast(theme.background) >= ACCENT_CONTRAST,
+                    "{color}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn extract_from_nothing() {
+        assert!(extract(&[], None).is_none());
+    }
+}
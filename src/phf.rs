@@ -0,0 +1,41 @@
+//! The perfect hash function that indexes the built-in themes by name.
+//!
+//! This module is also compiled into the build script, which searches for
+//! the per-bucket seeds, so it must not depend on the rest of the crate.
+
+/// Hashes a name case-insensitively with the given seed.
+///
+/// This is 64-bit FNV-1a over the ASCII-lowercased bytes, with the seed mixed
+/// into the offset basis and a final avalanche step so that nearby seeds give
+/// unrelated hashes.
+pub const fn hash(name: &[u8], seed: u32) -> u64 {
+    let mut hash = 0xCBF2_9CE4_8422_2325_u64 ^ (seed as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let mut idx = 0;
+
+    while idx < name.len() {
+        hash ^= name[idx].to_ascii_lowercase() as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+        idx += 1;
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    hash ^ (hash >> 33)
+}
+
+/// Returns the bucket of a name among `count` buckets.
+// The remainder is less than `count`, so the cast can't truncate.
+#[allow(clippy::cast_possible_truncation)]
+pub const fn bucket(name: &[u8], count: usize) -> usize {
+    (hash(name, 0) % count as u64) as usize
+}
+
+/// Returns the slot of a name in a table of `len` slots, given the seeds
+/// of its buckets.
+// The remainder is less than `len`, so the cast can't truncate.
+#[allow(clippy::cast_possible_truncation)]
+pub const fn slot(name: &[u8], seeds: &[u32], len: usize) -> usize {
+    let bucket = bucket(name, seeds.len());
+
+    (hash(name, seeds[bucket]) % len as u64) as usize
+}
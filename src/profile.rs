@@ -0,0 +1,480 @@
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use themux::{properties, Rgb, Theme, Themes};
+use toml::Value;
+
+use crate::document::{self, Document, Entry};
+use crate::font;
+use crate::info;
+use crate::termux::{read_properties, Change, Paths};
+use crate::ui::Key;
+use crate::user_themes;
+use crate::util::suggest;
+
+/// The name of a theme given as a palette without a name.
+const UNNAMED: &str = "Custom";
+
+/// A theme, font and appearance settings that are applied together.
+///
+/// Profiles are stored as TOML:
+///
+/// ```toml
+/// theme = "Solarized Light"   # a built-in or user theme
+/// font = "JetBrainsMono"      # a font in the font directory, or a path
+///
+/// [colors]                    # a palette, or changes to the theme's colors
+/// background = "#FFFFFF"
+///
+/// [ui]                        # appearance keys for termux.properties
+/// terminal-margin-horizontal = 8
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    /// The theme, including any color changes.
+    pub theme: Option<Theme>,
+    /// A font name in the font directory, or a path to a font.
+    pub font: Option<String>,
+    /// Appearance keys and their values.
+    pub ui: Vec<(&'static Key, String)>,
+}
+
+impl Profile {
+    /// Parses a profile.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let doc = Document::parse(content)?;
+
+        doc.check_tables(&["colors", "ui"])?;
+        doc.root().check_keys(&["theme", "font"])?;
+
+        let base = doc.root().get("theme").map(resolve).transpose()?;
+
+        let theme = match doc.table("colors") {
+            Some(table) => {
+                let mut theme = base.unwrap_or(Theme {
+                    name: Cow::Borrowed(UNNAMED),
+                    ..Theme::TERMUX_DEFAULT
+                });
+
+                for entry in &table.entries {
+                    if entry.key == "name" {
+                        theme.name = Cow::Owned(entry.as_str()?.to_string());
+                        continue;
+                    }
+
+                    let Some(slot) = theme.slot_mut(&entry.key) else {
+                        return Err(entry.error(&format!("unknown color slot `{}`", entry.key)));
+                    };
+
+                    *slot = entry
+                        .as_str()?
+                        .parse::<Rgb>()
+                        .map_err(|e| entry.error(&e.to_string()))?;
+                }
+
+                Some(theme)
+            }
+            None => base,
+        };
+
+        let font = doc
+            .root()
+            .get("font")
+            .map(|entry| entry.as_str().map(String::from))
+            .transpose()?;
+
+        let ui = doc
+            .table("ui")
+            .map(|table| table.entries.iter().map(ui_setting).collect())
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self { theme, font, ui })
+    }
+
+    /// Returns the profile as TOML.
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+
+        // Built-in themes are saved by name, anything else as a palette.
+        let builtin = self.theme.as_ref().and_then(|theme| {
+            Themes::init()
+                .get(&theme.name)
+                .filter(|builtin| builtin.slots() == theme.slots())
+        });
+
+        if let Some(theme) = builtin {
+            let _ = writeln!(out, "theme = {}", document::quote(&theme.name));
+        }
+
+        if let Some(font) = &self.font {
+            let _ = writeln!(out, "font = {}", document::quote(font));
+        }
+
+        if let Some(theme) = self.theme.as_ref().filter(|_| builtin.is_none()) {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+
+            let _ = writeln!(out, "[colors]\nname = {}", document::quote(&theme.name));
+
+            for (key, color) in theme.slots() {
+                let _ = writeln!(out, "{key} = \"{color}\"");
+            }
+        }
+
+        if !self.ui.is_empty() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+
+            out.push_str("[ui]\n");
+
+            for (key, value) in &self.ui {
+                let _ = writeln!(
+                    out,
+                    "{} = {}",
+                    document::key(key.name),
+                    document::quote(value)
+                );
+            }
+        }
+
+        out
+    }
+
+    /// Returns a profile of the current colors, font and appearance keys.
+    ///
+    /// A font that isn't in the font directory can't be referred to, so it
+    /// is left out with a message.
+    pub fn capture() -> Result<Self, String> {
+        let paths = Paths::get();
+        let settings = paths.settings_file();
+
+        let theme = match properties::read(&settings) {
+            Ok(theme) => Some(theme),
+            Err(themux::Error::Io { source, .. }) if source.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(format!("Unable to read {}: {e}", settings.display())),
+        };
+
+        let font = match font::installed_hash()? {
+            Some(hash) => {
+                let found = font::available()?
+                    .into_iter()
+                    .find(|path| fs::read(path).is_ok_and(|data| font::hash(&data) == hash));
+
+                if found.is_none() {
+                    info!(
+                        "The installed font isn't in {}, so it isn't saved",
+                        paths.fonts_dir().display()
+                    );
+                }
+
+                found.map(|path| font::name(&path))
+            }
+            None => None,
+        };
+
+        let current = read_properties()?;
+
+        let ui = crate::ui::KEYS
+            .iter()
+            .filter_map(|key| {
+                properties::get(&current, key.name).map(|value| (key, value.to_string()))
+            })
+            .collect();
+
+        Ok(Self { theme, font, ui })
+    }
+
+    /// Returns the changes that would apply the profile, leaving out files
+    /// that already match it.
+    pub fn changes(&self) -> Result<Vec<Change>, String> {
+        let mut changes = Vec::new();
+
+        if let Some(theme) = &self.theme {
+            changes.push(Change::settings(theme.to_settings_string())?);
+        }
+
+        if !self.ui.is_empty() {
+            let current = read_properties()?;
+
+            let content = self
+                .ui
+                .iter()
+                .fold(current.clone(), |content, (key, value)| {
+                    properties::set(&content, key.name, value)
+                });
+
+            if content != current {
+                changes.push(Change::properties(content)?);
+            }
+        }
+
+        if let Some(name) = &self.font {
+            let path = font::find(name)?;
+            changes.push(Change::font(font::read(&path)?)?);
+        }
+
+        changes.retain(|change| !change.is_noop());
+
+        Ok(changes)
+    }
+
+    /// Reads a saved profile.
+    pub fn load(name: &str) -> Result<Self, String> {
+        let path = path(name)?;
+        let content = read(name)?;
+
+        Self::parse(&content).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// Saves the profile under a name, replacing any profile with that name.
+    pub fn save(&self, name: &str) -> Result<(), String> {
+        let path = path(name)?;
+        let dir = Paths::get().profiles_dir();
+
+        fs::create_dir_all(&dir)
+            .and_then(|()| fs::write(&path, self.to_toml()))
+            .map_err(|e| format!("Unable to write {}: {e}", path.display()))
+    }
+}
+
+// Resolves the `theme` key to a copy of a user or built-in theme.
+fn resolve(entry: &Entry) -> Result<Theme, String> {
+    let name = entry.as_str()?;
+
+    user_themes::resolve(name)
+        .cloned()
+        .map_err(|_| entry.error(&format!("\"{name}\" is not a valid theme")))
+}
+
+// Validates an entry in the `[ui]` table. Numbers and booleans may be
+// written unquoted.
+fn ui_setting(entry: &Entry) -> Result<(&'static Key, String), String> {
+    let Some(key) = Key::find(&entry.key) else {
+        return Err(entry.error(&format!("unknown appearance key `{}`", entry.key)));
+    };
+
+    let value = match &entry.value {
+        Value::String(s) => s.clone(),
+        Value::Integer(n) => n.to_string(),
+        Value::Boolean(b) => b.to_string(),
+        _ => entry.as_str()?.to_string(),
+    };
+
+    key.validate(&value).map_err(|e| entry.error(&e))?;
+
+    Ok((key, value))
+}
+
+/// Returns the path of a profile, checking that its name is usable as a
+/// file name.
+pub fn path(name: &str) -> Result<PathBuf, String> {
+    let is_valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'));
+
+    if !is_valid {
+        return Err(format!(
+            "\"{name}\" is not a valid profile name (use letters, digits, '-', '_' and '.')"
+        ));
+    }
+
+    Ok(Paths::get().profiles_dir().join(format!("{name}.toml")))
+}
+
+/// Returns the content of a saved profile.
+pub fn read(name: &str) -> Result<String, String> {
+    let path = path(name)?;
+
+    fs::read_to_string(&path).map_err(|e| {
+        if e.kind() != ErrorKind::NotFound {
+            return format!("Unable to read {}: {e}", path.display());
+        }
+
+        let mut msg = format!("There is no profile named \"{name}\"");
+
+        if let Some(similar) = names()
+            .ok()
+            .and_then(|names| suggest(name, names.iter().map(String::as_str)).map(String::from))
+        {
+            let _ = write!(msg, "; did you mean \"{similar}\"?");
+        }
+
+        msg
+    })
+}
+
+/// Deletes a saved profile.
+pub fn delete(name: &str) -> Result<(), String> {
+    // Reading first gives the same error as the other actions when the
+    // profile doesn't exist.
+    read(name)?;
+
+    let path = path(name)?;
+
+    fs::remove_file(&path).map_err(|e| format!("Unable to delete {}: {e}", path.display()))
+}
+
+/// Returns the names of the saved profiles, sorted.
+pub fn names() -> Result<Vec<String>, String> {
+    let dir = Paths::get().profiles_dir();
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Unable to read {}: {e}", dir.display())),
+    };
+
+    let mut names = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .filter_map(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(String::from)
+        })
+        .collect::<Vec<String>>();
+
+    names.sort();
+
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the names and values of a profile's appearance keys.
+    fn ui(profile: &Profile) -> Vec<(&str, &str)> {
+        profile
+            .ui
+            .iter()
+            .map(|(key, value)| (key.name, value.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn builtin_theme_round_trips_by_name() {
+        let profile = Profile {
+            theme: Themes::init().get("Nord").cloned(),
+            font: Some(String::from("Fira \"Code\"")),
+            ui: vec![
+                (
+                    Key::find("terminal-margin-horizontal").unwrap(),
+                    String::from("8"),
+                ),
+                (Key::find("use-black-ui").unwrap(), String::from("true")),
+            ],
+        };
+
+        let saved = profile.to_toml();
+
+        assert_eq!(
+            saved,
+            "theme = \"Nord\"\nfont = \"Fira \\\"Code\\\"\"\n\n\
+             [ui]\nterminal-margin-horizontal = \"8\"\nuse-black-ui = \"true\"\n"
+        );
+
+        let loaded = Profile::parse(&saved).unwrap();
+        let theme = loaded.theme.as_ref().unwrap();
+
+        assert_eq!(theme.name, "Nord");
+        assert_eq!(theme.slots(), profile.theme.as_ref().unwrap().slots());
+        assert_eq!(loaded.font, profile.font);
+        assert_eq!(ui(&loaded), ui(&profile));
+        assert_eq!(loaded.to_toml(), saved);
+    }
+
+    #[test]
+    fn changed_theme_round_trips_as_a_palette() {
+        let mut theme = Themes::init().get("Nord").cloned().unwrap();
+        theme.background = Rgb::new(0, 0, 0);
+
+        let profile = Profile {
+            theme: Some(theme.clone()),
+            ..Profile::default()
+        };
+        let saved = profile.to_toml();
+
+        assert!(saved.starts_with("[colors]\nname = \"Nord\"\n"), "{saved}");
+        assert!(saved.contains("background = \"#000000\"\n"), "{saved}");
+
+        let loaded = Profile::parse(&saved).unwrap().theme.unwrap();
+
+        assert_eq!(loaded.name, "Nord");
+        assert_eq!(loaded.slots(), theme.slots());
+    }
+
+    #[test]
+    fn colors_change_the_theme() {
+        let profile = Profile::parse(
+            "theme = 'nord'\n\n[colors]\nname = 'Nord Black'\nbackground = '#000'\n",
+        )
+        .unwrap();
+        let theme = profile.theme.unwrap();
+        let nord = Themes::init().get("Nord").unwrap();
+
+        assert_eq!(theme.name, "Nord Black");
+        assert_eq!(theme.background, Rgb::new(0, 0, 0));
+        assert_eq!(theme.foreground, nord.foreground);
+
+        // Without a theme, colors change the Termux defaults.
+        let theme = Profile::parse("[colors]\ncolor1 = '#FF0000'")
+            .unwrap()
+            .theme
+            .unwrap();
+
+        assert_eq!(theme.name, UNNAMED);
+        assert_eq!(theme.color1, Rgb::new(255, 0, 0));
+        assert_eq!(theme.background, Theme::TERMUX_DEFAULT.background);
+    }
+
+    #[test]
+    fn ui_values_may_be_unquoted() {
+        let profile =
+            Profile::parse("[ui]\nterminal-margin-vertical = 4\nuse-black-ui = false").unwrap();
+
+        assert_eq!(
+            ui(&profile),
+            [("terminal-margin-vertical", "4"), ("use-black-ui", "false")]
+        );
+        assert!(profile.theme.is_none());
+        assert!(profile.font.is_none());
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let error = |content| Profile::parse(content).unwrap_err();
+
+        assert_eq!(
+            error("theme = 'Nord'\n[fonts]"),
+            "line 2: unknown table [fonts]"
+        );
+        assert_eq!(error("colour = 'Nord'"), "line 1: unknown key `colour`");
+        assert_eq!(
+            error("theme = 'No Such Theme'"),
+            "line 1: \"No Such Theme\" is not a valid theme"
+        );
+        assert_eq!(
+            error("theme = 1"),
+            "line 1: `theme` must be a string, not an integer"
+        );
+        assert_eq!(
+            error("[colors]\ncolor16 = '#000000'"),
+            "line 2: unknown color slot `color16`"
+        );
+        assert!(error("[colors]\nbackground = 'black'").starts_with("line 2: "));
+        assert_eq!(
+            error("[ui]\nterminal-margin = 4"),
+            "line 2: unknown appearance key `terminal-margin`"
+        );
+        assert!(error("[ui]\nterminal-margin-vertical = 400").starts_with("line 2: "));
+    }
+}
@@ -0,0 +1,205 @@
+//! Reading and writing Termux's `colors.properties` file, and editing
+//! properties files such as `termux.properties`.
+
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::color::Rgb;
+use crate::error::{Error, Result};
+use crate::theme::Theme;
+
+/// The keys that may appear in a color settings file.
+pub const COLOR_KEYS: [&str; 19] = [
+    "color0",
+    "color1",
+    "color2",
+    "color3",
+    "color4",
+    "color5",
+    "color6",
+    "color7",
+    "color8",
+    "color9",
+    "color10",
+    "color11",
+    "color12",
+    "color13",
+    "color14",
+    "color15",
+    "background",
+    "foreground",
+    "cursor",
+];
+
+/// The comment prefix themux uses to record a theme's name.
+const NAME_PREFIX: &str = "# Color Theme: ";
+
+/// The name given to themes read from files without a recorded name.
+const UNNAMED: &str = "Custom";
+
+/// Splits a properties file line into a key and value, returning `None` for
+/// blank lines and comments.
+#[must_use]
+pub fn split_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+        return None;
+    }
+
+    let (key, value) = line.split_once(['=', ':']).unwrap_or((line, ""));
+
+    Some((key.trim(), value.trim()))
+}
+
+/// Returns the value of `key` in the content of a properties file. If the
+/// key is set more than once, the last value wins.
+#[must_use]
+pub fn get<'a>(content: &'a str, key: &str) -> Option<&'a str> {
+    content
+        .lines()
+        .rev()
+        .filter_map(split_line)
+        .find_map(|(k, value)| (k == key).then_some(value))
+}
+
+/// Returns the content of a properties file with `key` set to `value`.
+///
+/// The last line that sets the key is changed in place, keeping its
+/// separator and spacing, or a line is appended if none does. Every other
+/// line is kept as is.
+#[must_use]
+pub fn set(content: &str, key: &str, value: &str) -> String {
+    let target = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| split_line(line).is_some_and(|(k, _)| k == key))
+        .last()
+        .map(|(idx, _)| idx);
+
+    let mut out = String::with_capacity(content.len() + key.len() + value.len() + 4);
+
+    for (idx, line) in content.lines().enumerate() {
+        if Some(idx) == target {
+            // Keep everything up to the start of the old value.
+            let end = line.find(['=', ':']).map_or_else(
+                || line.trim_end().len(),
+                |sep| {
+                    let rest = &line[sep + 1..];
+                    sep + 1 + rest.len() - rest.trim_start().len()
+                },
+            );
+
+            out.push_str(&line[..end]);
+
+            if !line[..end].contains(['=', ':']) {
+                out.push_str(" = ");
+            }
+
+            out.push_str(value);
+        } else {
+            out.push_str(line);
+        }
+
+        out.push('\n');
+    }
+
+    if target.is_none() {
+        let _ = writeln!(out, "{key} = {value}");
+    }
+
+    out
+}
+
+/// Parses the content of a color settings file, returning the colors it
+/// sets in the order they appear.
+///
+/// # Errors
+///
+/// Returns an error if a line has an unknown key or an invalid color, or if
+/// no colors are set.
+pub fn parse_colors(content: &str) -> Result<Vec<(&str, Rgb)>> {
+    let mut colors = Vec::new();
+
+    for (idx, line) in content.lines().enumerate() {
+        let Some((key, value)) = split_line(line) else {
+            continue;
+        };
+
+        if !COLOR_KEYS.contains(&key) {
+            return Err(Error::Parse {
+                line: idx + 1,
+                message: format!("unknown key \"{key}\""),
+            });
+        }
+
+        let color = value.parse::<Rgb>().map_err(|e| Error::Parse {
+            line: idx + 1,
+            message: format!("invalid value for \"{key}\": {e}"),
+        })?;
+
+        colors.push((key, color));
+    }
+
+    if colors.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    Ok(colors)
+}
+
+/// Returns the theme name recorded in the content of a color settings file
+/// written by themux.
+pub fn theme_name(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(NAME_PREFIX))
+        .map(str::trim)
+}
+
+/// Parses the content of a color settings file into a `Theme`.
+///
+/// Colors the file doesn't set keep their Termux defaults.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as `parse_colors`.
+pub fn parse(content: &str) -> Result<Theme> {
+    let mut theme = Theme {
+        name: Cow::Owned(theme_name(content).unwrap_or(UNNAMED).to_string()),
+        ..Theme::TERMUX_DEFAULT
+    };
+
+    for (key, color) in parse_colors(content)? {
+        if let Some(slot) = theme.slot_mut(key) {
+            *slot = color;
+        }
+    }
+
+    Ok(theme)
+}
+
+/// Reads a color settings file into a `Theme`.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or parsed.
+pub fn read(path: impl AsRef<Path>) -> Result<Theme> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path).map_err(Error::io(path))?;
+
+    parse(&content)
+}
+
+/// Writes a `Theme` to a color settings file.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be written.
+pub fn write(path: impl AsRef<Path>, theme: &Theme) -> Result<()> {
+    let path = path.as_ref();
+
+    fs::write(path, theme.to_settings_string()).map_err(Error::io(path))
+}
@@ -0,0 +1,797 @@
+//! Queries over theme palettes.
+//!
+//! A query is a boolean expression over a theme's color slots:
+//!
+//! ```
+//! use themux::{query::Query, Themes};
+//!
+//! let query: Query = "bg.l < 0.2 and contrast(fg, bg) > 10".parse().unwrap();
+//!
+//! assert!(Themes::init().find(&query).all(|theme| theme.background.is_dark()));
+//! ```
+//!
+//! Slots are named as in a color settings file (`color0` to `color15`,
+//! `background`, `foreground` and `cursor`), with `bg` and `fg` as short
+//! forms. A slot is a color, which can be compared with `=` or `!=` against
+//! another slot or a `#RRGGBB` literal. Its properties are numbers:
+//!
+//! - `.r`, `.g` and `.b`: the RGB channels, from 0 to 255.
+//! - `.l` or `.lightness`: the Oklch lightness, from 0 to 1.
+//! - `.c` or `.chroma`: the Oklch chroma, from 0 to about 0.32.
+//! - `.h` or `.hue`: the Oklch hue in degrees, or 0 for grays.
+//! - `.hex`: the color itself.
+//!
+//! `contrast(a, b)` is the contrast ratio of two colors, from 1 to 21.
+//! Numbers are compared with `<`, `<=`, `>`, `>=`, `=` and `!=`, or with
+//! `between low and high`, which includes both ends. Comparisons are combined
+//! with `and`, `or`, `not` and parentheses.
+
+use std::fmt::{Display, Formatter, Result as FmtResult, Write as _};
+use std::str::FromStr;
+
+use crate::color::Rgb;
+use crate::error::{Error, Result};
+use crate::names::edit_distance;
+use crate::properties::COLOR_KEYS;
+use crate::theme::Theme;
+
+/// A parsed query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+    expr: Expr,
+}
+
+impl Query {
+    /// Parses a query.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Query` pointing at the offending token if the query
+    /// is malformed.
+    pub fn parse(query: &str) -> Result<Self> {
+        let tokens = tokenize(query)?;
+
+        let mut parser = Parser {
+            query,
+            tokens,
+            pos: 0,
+        };
+        let expr = parser.parse_or()?;
+
+        match parser.peek().token {
+            Token::End => Ok(Self { expr }),
+            _ => Err(parser.error_here("expected `and`, `or` or the end of the query")),
+        }
+    }
+
+    /// Returns true if the theme satisfies the query.
+    #[must_use]
+    pub fn matches(&self, theme: &Theme) -> bool {
+        self.expr.eval(theme)
+    }
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+/// A boolean expression.
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+    Not(Box<Self>),
+    Compare(Value, Op, Value),
+    Between(Value, Value, Value),
+}
+
+impl Expr {
+    fn eval(&self, theme: &Theme) -> bool {
+        match self {
+            Self::And(lhs, rhs) => lhs.eval(theme) && rhs.eval(theme),
+            Self::Or(lhs, rhs) => lhs.eval(theme) || rhs.eval(theme),
+            Self::Not(expr) => !expr.eval(theme),
+            Self::Compare(lhs, op, rhs) => match (lhs.eval(theme), rhs.eval(theme)) {
+                (Scalar::Number(a), Scalar::Number(b)) => op.test(a.partial_cmp(&b)),
+                (Scalar::Color(a), Scalar::Color(b)) => op.test(Some(a.cmp(&b))),
+                // Mismatched types are rejected by the parser.
+                _ => false,
+            },
+            Self::Between(value, low, high) => {
+                let [value, low, high] = [value, low, high].map(|v| v.eval(theme).number());
+                low <= value && value <= high
+            }
+        }
+    }
+}
+
+/// A comparison operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Op {
+    // Returns true if an ordering satisfies the operator. Unordered values
+    // only satisfy `!=`.
+    fn test(self, ordering: Option<std::cmp::Ordering>) -> bool {
+        ordering.map_or(self == Self::Ne, |ordering| match self {
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+        })
+    }
+
+    // Returns true if the operator orders values, rather than only testing
+    // equality.
+    const fn is_ordering(self) -> bool {
+        !matches!(self, Self::Eq | Self::Ne)
+    }
+}
+
+/// A property of a color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Property {
+    Red,
+    Green,
+    Blue,
+    Lightness,
+    Chroma,
+    Hue,
+}
+
+/// An operand of a comparison.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Number(f64),
+    Color(Rgb),
+    /// A slot, by its index in `COLOR_KEYS`.
+    Slot(usize),
+    Property(usize, Property),
+    Contrast(usize, usize),
+}
+
+impl Value {
+    fn eval(&self, theme: &Theme) -> Scalar {
+        let slot = |idx: usize| theme.slots()[idx].1;
+
+        match *self {
+            Self::Number(n) => Scalar::Number(n),
+            Self::Color(color) => Scalar::Color(color),
+            Self::Slot(idx) => Scalar::Color(slot(idx)),
+            Self::Property(idx, property) => {
+                let color = slot(idx);
+                let (lightness, chroma, hue) = color.to_oklch();
+
+                Scalar::Number(match property {
+                    Property::Red => f64::from(color.r),
+                    Property::Green => f64::from(color.g),
+                    Property::Blue => f64::from(color.b),
+                    Property::Lightness => lightness,
+                    Property::Chroma => chroma,
+                    Property::Hue => hue,
+                })
+            }
+            Self::Contrast(a, b) => Scalar::Number(slot(a).contrast(slot(b))),
+        }
+    }
+
+    const fn is_color(&self) -> bool {
+        matches!(self, Self::Color(_) | Self::Slot(_))
+    }
+}
+
+/// An evaluated operand.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Scalar {
+    Number(f64),
+    Color(Rgb),
+}
+
+impl Scalar {
+    const fn number(self) -> f64 {
+        match self {
+            Self::Number(n) => n,
+            Self::Color(_) => f64::NAN,
+        }
+    }
+}
+
+/// A lexical token.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token<'a> {
+    Number(f64),
+    Color(Rgb),
+    Ident(&'a str),
+    Op(Op),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+    End,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Number(n) => write!(f, "`{n}`"),
+            Self::Color(color) => write!(f, "`{color}`"),
+            Self::Ident(ident) => write!(f, "`{ident}`"),
+            Self::Op(op) => f.write_str(match op {
+                Op::Lt => "`<`",
+                Op::Le => "`<=`",
+                Op::Gt => "`>`",
+                Op::Ge => "`>=`",
+                Op::Eq => "`=`",
+                Op::Ne => "`!=`",
+            }),
+            Self::LParen => f.write_str("`(`"),
+            Self::RParen => f.write_str("`)`"),
+            Self::Comma => f.write_str("`,`"),
+            Self::Dot => f.write_str("`.`"),
+            Self::End => f.write_str("the end of the query"),
+        }
+    }
+}
+
+/// A token and the byte range it came from.
+#[derive(Clone, Copy, Debug)]
+struct Spanned<'a> {
+    token: Token<'a>,
+    start: usize,
+    end: usize,
+}
+
+// Returns an error for the given byte range of a query.
+fn error_at(query: &str, start: usize, end: usize, message: String) -> Error {
+    let column = query[..start].chars().count() + 1;
+    let width = query[start..end].chars().count().max(1);
+
+    Error::Query {
+        column,
+        width,
+        message,
+    }
+}
+
+// Splits a query into tokens.
+fn tokenize(query: &str) -> Result<Vec<Spanned<'_>>> {
+    let bytes = query.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let start = pos;
+        let next = bytes.get(pos + 1).copied().unwrap_or(0);
+
+        // Each token takes at least its first byte, and the longer ones
+        // advance `pos` past the rest.
+        pos += 1;
+
+        let token = match bytes[start] {
+            b if b.is_ascii_whitespace() => continue,
+            b'(' => Token::LParen,
+            b')' => Token::RParen,
+            b',' => Token::Comma,
+            b'<' | b'>' | b'=' | b'!' => {
+                let op = match (bytes[start], next) {
+                    (b'<', b'=') => Op::Le,
+                    (b'>', b'=') => Op::Ge,
+                    (b'!', b'=') => Op::Ne,
+                    (b'<', _) => Op::Lt,
+                    (b'>', _) => Op::Gt,
+                    (b'=', _) => Op::Eq,
+                    _ => {
+                        let msg = String::from("expected `!=`; use `not` for negation");
+                        return Err(error_at(query, start, pos, msg));
+                    }
+                };
+
+                pos += usize::from(next == b'=');
+                Token::Op(op)
+            }
+            b'0'..=b'9' | b'-' => {
+                while bytes
+                    .get(pos)
+                    .is_some_and(|b| b.is_ascii_digit() || *b == b'.')
+                {
+                    pos += 1;
+                }
+
+                let text = &query[start..pos];
+
+                let Ok(n) = text.parse::<f64>() else {
+                    let msg = format!("`{text}` is not a number");
+                    return Err(error_at(query, start, pos, msg));
+                };
+
+                Token::Number(n)
+            }
+            b'.' if next.is_ascii_digit() => {
+                while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+                    pos += 1;
+                }
+
+                Token::Number(query[start..pos].parse::<f64>().unwrap_or_default())
+            }
+            b'.' => Token::Dot,
+            // Colors can be quoted to keep the shell from treating `#` as a
+            // comment.
+            b'#' | b'"' | b'\'' => {
+                let text = if bytes[start] == b'#' {
+                    while bytes.get(pos).is_some_and(u8::is_ascii_alphanumeric) {
+                        pos += 1;
+                    }
+
+                    &query[start..pos]
+                } else {
+                    let quote = char::from(bytes[start]);
+
+                    let Some(len) = query[start + 1..].find(quote) else {
+                        let msg = String::from("unterminated color literal");
+                        return Err(error_at(query, start, query.len(), msg));
+                    };
+
+                    pos = start + len + 2;
+                    &query[start + 1..pos - 1]
+                };
+
+                let color = text
+                    .parse::<Rgb>()
+                    .map_err(|e| error_at(query, start, pos, e.to_string()))?;
+
+                Token::Color(color)
+            }
+            b if b.is_ascii_alphabetic() || b == b'_' => {
+                while bytes
+                    .get(pos)
+                    .is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'_')
+                {
+                    pos += 1;
+                }
+
+                Token::Ident(&query[start..pos])
+            }
+            _ => {
+                let c = query[start..].chars().next().unwrap_or_default();
+                let msg = format!("unexpected character `{c}`");
+                return Err(error_at(query, start, start + c.len_utf8(), msg));
+            }
+        };
+
+        tokens.push(Spanned {
+            token,
+            start,
+            end: pos,
+        });
+    }
+
+    tokens.push(Spanned {
+        token: Token::End,
+        start: query.len(),
+        end: query.len(),
+    });
+
+    Ok(tokens)
+}
+
+/// A recursive descent parser over a query's tokens.
+struct Parser<'a> {
+    query: &'a str,
+    tokens: Vec<Spanned<'a>>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Spanned<'a> {
+        self.tokens[self.pos]
+    }
+
+    fn advance(&mut self) -> Spanned<'a> {
+        let token = self.peek();
+
+        if token.token != Token::End {
+            self.pos += 1;
+        }
+
+        token
+    }
+
+    // Consumes the next token if it is the given keyword.
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = matches!(
+            self.peek().token,
+            Token::Ident(ident) if ident.eq_ignore_ascii_case(keyword)
+        );
+
+        if is_keyword {
+            self.pos += 1;
+        }
+
+        is_keyword
+    }
+
+    fn expect(&mut self, token: Token<'_>) -> Result<()> {
+        if self.peek().token == token {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error_here(&format!("expected {token}")))
+        }
+    }
+
+    fn error(&self, spanned: Spanned<'_>, message: String) -> Error {
+        error_at(self.query, spanned.start, spanned.end, message)
+    }
+
+    // Returns an error at the next token, naming what was found there.
+    fn error_here(&self, expected: &str) -> Error {
+        let next = self.peek();
+        self.error(next, format!("{expected}, found {}", next.token))
+    }
+
+    // or = and ("or" and)*
+    fn parse_or(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_and()?;
+
+        while self.keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+
+        Ok(expr)
+    }
+
+    // and = not ("and" not)*
+    fn parse_and(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_not()?;
+
+        while self.keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+
+        Ok(expr)
+    }
+
+    // not = "not" not | "(" or ")" | comparison
+    fn parse_not(&mut self) -> Result<Expr> {
+        if self.keyword("not") {
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+
+        if self.peek().token == Token::LParen {
+            self.pos += 1;
+            let expr = self.parse_or()?;
+            self.expect(Token::RParen)?;
+            return Ok(expr);
+        }
+
+        self.parse_comparison()
+    }
+
+    // comparison = value (op value | "between" value "and" value)
+    fn parse_comparison(&mut self) -> Result<Expr> {
+        let lhs_token = self.peek();
+        let lhs = self.parse_value()?;
+
+        if self.keyword("between") {
+            if lhs.is_color() {
+                let msg = "colors have no order; compare a property such as `.l`";
+                return Err(self.error(lhs_token, msg.into()));
+            }
+
+            let low = self.parse_number()?;
+
+            if !self.keyword("and") {
+                return Err(self.error_here("expected `and`"));
+            }
+
+            let high = self.parse_number()?;
+
+            return Ok(Expr::Between(lhs, low, high));
+        }
+
+        let op_token = self.advance();
+
+        let Token::Op(op) = op_token.token else {
+            self.pos -= usize::from(op_token.token != Token::End);
+            return Err(self.error_here("expected a comparison such as `<` or `between`"));
+        };
+
+        let rhs_token = self.peek();
+        let rhs = self.parse_value()?;
+
+        match (lhs.is_color(), rhs.is_color()) {
+            (true, true) if op.is_ordering() => Err(self.error(
+                op_token,
+                "colors can only be compared with `=` or `!=`".into(),
+            )),
+            (true, false) => Err(self.error(rhs_token, "expected a color to compare with".into())),
+            (false, true) => Err(self.error(rhs_token, "expected a number to compare with".into())),
+            _ => Ok(Expr::Compare(lhs, op, rhs)),
+        }
+    }
+
+    // Parses a value that must be a number.
+    fn parse_number(&mut self) -> Result<Value> {
+        let token = self.peek();
+        let value = self.parse_value()?;
+
+        if value.is_color() {
+            return Err(self.error(token, "expected a number".into()));
+        }
+
+        Ok(value)
+    }
+
+    // value = number | color | slot ("." property)? | "contrast" "(" slot "," slot ")"
+    fn parse_value(&mut self) -> Result<Value> {
+        let token = self.peek();
+
+        match token.token {
+            Token::Number(n) => {
+                self.pos += 1;
+                Ok(Value::Number(n))
+            }
+            Token::Color(color) => {
+                self.pos += 1;
+                Ok(Value::Color(color))
+            }
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("contrast") => {
+                self.pos += 1;
+                self.expect(Token::LParen)?;
+                let a = self.parse_slot()?;
+                self.expect(Token::Comma)?;
+                let b = self.parse_slot()?;
+                self.expect(Token::RParen)?;
+
+                Ok(Value::Contrast(a, b))
+            }
+            Token::Ident(_) => {
+                let slot = self.parse_slot()?;
+
+                if self.peek().token != Token::Dot {
+                    return Ok(Value::Slot(slot));
+                }
+
+                self.pos += 1;
+                let token = self.advance();
+
+                let Token::Ident(name) = token.token else {
+                    let msg = format!("expected a property, found {}", token.token);
+                    return Err(self.error(token, msg));
+                };
+
+                let property = match name.to_ascii_lowercase().as_str() {
+                    "hex" => return Ok(Value::Slot(slot)),
+                    "r" | "red" => Property::Red,
+                    "g" | "green" => Property::Green,
+                    "b" | "blue" => Property::Blue,
+                    "l" | "lightness" => Property::Lightness,
+                    "c" | "chroma" => Property::Chroma,
+                    "h" | "hue" => Property::Hue,
+                    _ => {
+                        let msg =
+                            format!("unknown property `{name}` (expected hex, r, g, b, l, c or h)");
+                        return Err(self.error(token, msg));
+                    }
+                };
+
+                Ok(Value::Property(slot, property))
+            }
+            _ => Err(self.error_here("expected a color slot, a number or a color")),
+        }
+    }
+
+    // Parses a slot name, returning its index in `COLOR_KEYS`.
+    fn parse_slot(&mut self) -> Result<usize> {
+        let token = self.advance();
+
+        let Token::Ident(name) = token.token else {
+            let msg = format!("expected a color slot, found {}", token.token);
+            return Err(self.error(token, msg));
+        };
+
+        let name = name.to_ascii_lowercase();
+
+        let key = match name.as_str() {
+            "bg" => "background",
+            "fg" => "foreground",
+            name => name,
+        };
+
+        if let Some(idx) = COLOR_KEYS.iter().position(|&k| k == key) {
+            return Ok(idx);
+        }
+
+        let mut msg = format!("unknown color slot `{name}`");
+
+        if let Some(similar) = COLOR_KEYS
+            .iter()
+            .filter(|k| edit_distance(&name, k) <= 2)
+            .min_by_key(|k| edit_distance(&name, k))
+        {
+            let _ = write!(msg, "; did you mean `{similar}`?");
+        }
+
+        Err(self.error(token, msg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Themes;
+
+    // Evaluates a query against a catalog theme.
+    fn matches(query: &str, theme: &str) -> bool {
+        let theme = Themes::init().get(theme).unwrap();
+        Query::parse(query).unwrap().matches(theme)
+    }
+
+    // Returns the column, width and message of a query's parse error.
+    fn error(query: &str) -> (usize, usize, String) {
+        match Query::parse(query) {
+            Err(Error::Query {
+                column,
+                width,
+                message,
+            }) => (column, width, message),
+            other => panic!("expected a query error for {query:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn slots_and_properties() {
+        // Nord's background is #2E3440 and its foreground #D8DEE9.
+        assert!(matches("bg.r = 46 and bg.g = 52 and bg.b = 64", "Nord"));
+        assert!(matches("background = #2E3440 and fg != bg", "Nord"));
+        assert!(matches("bg.hex = '#2e3440' and FG = \"#D8DEE9\"", "Nord"));
+        assert!(matches("bg.l < 0.4 and fg.lightness > 0.85", "Nord"));
+        assert!(matches("color4.hue between 230 and 260", "Nord"));
+        assert!(matches("color8.c < 0.05", "Nord"));
+        assert!(!matches("bg.l < 0.5", "Ayu Light"));
+    }
+
+    #[test]
+    fn contrast() {
+        assert!(matches("contrast(bg, bg) = 1", "Nord"));
+        assert!(matches("contrast(fg, bg) = contrast(bg, fg)", "Nord"));
+        assert!(matches("contrast(fg, bg) between 9 and 9.5", "Nord"));
+        assert!(matches(
+            "contrast(color0, color7) > contrast(color0, color8)",
+            "Nord"
+        ));
+        // Ayu Light has a low-contrast orange foreground.
+        assert!(matches("contrast(foreground, background) < 3", "Ayu Light"));
+    }
+
+    #[test]
+    fn between_includes_both_ends() {
+        assert!(matches("bg.r between 46 and 46", "Nord"));
+        assert!(matches("bg.r between 40 and 50 and fg.r > 200", "Nord"));
+        assert!(!matches("bg.r between 47 and 100", "Nord"));
+        assert!(!matches("bg.r between 50 and 40", "Nord"));
+    }
+
+    #[test]
+    fn precedence() {
+        // `and` binds tighter than `or`, and `not` tighter than both.
+        assert_eq!(
+            Query::parse("bg.r = 1 or bg.g = 2 and bg.b = 3").unwrap(),
+            Query::parse("bg.r = 1 or (bg.g = 2 and bg.b = 3)").unwrap()
+        );
+        assert_eq!(
+            Query::parse("not bg.r = 1 and bg.g = 2").unwrap(),
+            Query::parse("(not bg.r = 1) and bg.g = 2").unwrap()
+        );
+
+        assert!(matches("bg.r = 46 or bg.r = 0 and bg.g = 0", "Nord"));
+        assert!(!matches("(bg.r = 46 or bg.r = 0) and bg.g = 0", "Nord"));
+        assert!(matches("not bg.r = 0 and not not bg.g = 52", "Nord"));
+        assert!(matches("bg.r = 0 OR bg.r >= 46 AND bg.r <= 46", "Nord"));
+    }
+
+    #[test]
+    fn errors_point_at_the_token() {
+        assert_eq!(
+            error("bg.l < "),
+            (
+                8,
+                1,
+                "expected a color slot, a number or a color, found the end of the query".into()
+            )
+        );
+        assert_eq!(
+            error("bg.x < 1"),
+            (
+                4,
+                1,
+                "unknown property `x` (expected hex, r, g, b, l, c or h)".into()
+            )
+        );
+        assert_eq!(
+            error("backgrund.l < 1"),
+            (
+                1,
+                9,
+                "unknown color slot `backgrund`; did you mean `background`?".into()
+            )
+        );
+        assert_eq!(
+            error("bg.l < 0.5 fg"),
+            (
+                12,
+                2,
+                "expected `and`, `or` or the end of the query, found `fg`".into()
+            )
+        );
+        assert_eq!(
+            error("(bg.l < 1"),
+            (10, 1, "expected `)`, found the end of the query".into())
+        );
+        assert_eq!(
+            error("bg.l ! 1"),
+            (6, 1, "expected `!=`; use `not` for negation".into())
+        );
+        assert_eq!(
+            error("bg.l between 0 or 1"),
+            (16, 2, "expected `and`, found `or`".into())
+        );
+    }
+
+    #[test]
+    fn errors_for_mismatched_types() {
+        assert_eq!(
+            error("bg < fg"),
+            (4, 1, "colors can only be compared with `=` or `!=`".into())
+        );
+        assert_eq!(
+            error("bg = 0.5"),
+            (6, 3, "expected a color to compare with".into())
+        );
+        assert_eq!(
+            error("bg.l = fg"),
+            (8, 2, "expected a number to compare with".into())
+        );
+        assert_eq!(
+            error("bg between 0 and 1"),
+            (
+                1,
+                2,
+                "colors have no order; compare a property such as `.l`".into()
+            )
+        );
+        assert_eq!(
+            error("bg.l between 0 and #fff"),
+            (20, 4, "expected a number".into())
+        );
+    }
+
+    #[test]
+    fn errors_count_characters() {
+        // Columns and widths are in characters, not bytes.
+        assert_eq!(error("bg.l < é"), (8, 1, "unexpected character `é`".into()));
+        assert_eq!(error("'é' = bg").0, 1);
+        assert_eq!(error("'é' = bg").1, 3);
+        assert_eq!(
+            error("bg = 'fff"),
+            (6, 4, "unterminated color literal".into())
+        );
+        assert_eq!(
+            error("contrast(bg fg)"),
+            (13, 2, "expected `,`, found `fg`".into())
+        );
+    }
+}
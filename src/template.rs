@@ -0,0 +1,475 @@
+//! Templates that render a theme into any text format.
+//!
+//! Text is copied as is, except for placeholders between `{{` and `}}`:
+//!
+//! ```
+//! use themux::{template::Template, Themes};
+//!
+//! let theme = Themes::init().resolve("tokyo-night").unwrap();
+//! let template: Template = "bg = {{ background.hex_stripped }}".parse().unwrap();
+//!
+//! assert_eq!(template.render(theme), "bg = 1A1B26");
+//! ```
+//!
+//! A placeholder is one of:
+//!
+//! - `name`: the theme's name.
+//! - `is_dark`: `true` if the background is dark, otherwise `false`.
+//! - A color slot as named in a color settings file (`color0` to `color15`,
+//!   `background`, `foreground` and `cursor`), with an optional format:
+//!   `.hex` (`#1A1B26`, the default), `.hex_stripped` (`1A1B26`), `.rgb`
+//!   (`26,27,38`), or `.r`, `.g` or `.b` for a single channel.
+//!
+//! Colors can be changed by filters, applied from left to right:
+//!
+//! - `lighten(amount)` mixes in white, from 0 for none to 1 for white.
+//! - `darken(amount)` mixes in black, from 0 for none to 1 for black.
+//! - `alpha(amount)` sets the opacity, from 0 to 1, which `.hex` and
+//!   `.hex_stripped` add as a fourth byte and `.rgb` as a fourth number.
+//!
+//! For example, `{{ color4.rgb | lighten(0.2) | alpha(0.5) }}`. Write `\{{`
+//! for a literal `{{`.
+
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use crate::color::{to_channel, Rgb};
+use crate::error::{Error, Result};
+use crate::names::edit_distance;
+use crate::properties::COLOR_KEYS;
+use crate::theme::Theme;
+
+/// A parsed template.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+/// A piece of a template.
+#[derive(Clone, Debug, PartialEq)]
+enum Part {
+    Text(String),
+    Name,
+    IsDark,
+    Color {
+        /// The slot's index in `Theme::slots`.
+        slot: usize,
+        format: Format,
+        filters: Vec<Filter>,
+    },
+}
+
+/// How a color is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Hex,
+    HexStripped,
+    Rgb,
+    Red,
+    Green,
+    Blue,
+}
+
+/// The formats, by name.
+const FORMATS: [(&str, Format); 6] = [
+    ("hex", Format::Hex),
+    ("hex_stripped", Format::HexStripped),
+    ("rgb", Format::Rgb),
+    ("r", Format::Red),
+    ("g", Format::Green),
+    ("b", Format::Blue),
+];
+
+/// A change to a color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Filter {
+    Lighten(f64),
+    Darken(f64),
+    Alpha(f64),
+}
+
+/// The names of the filters.
+const FILTERS: [&str; 3] = ["lighten", "darken", "alpha"];
+
+impl FromStr for Template {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl Template {
+    /// Parses a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Template` with the line of the first placeholder that
+    /// is unterminated or malformed.
+    pub fn parse(template: &str) -> Result<Self> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find("{{") {
+            if rest[..start].ends_with('\\') {
+                text.push_str(&rest[..start - 1]);
+                text.push_str("{{");
+                rest = &rest[start + 2..];
+                continue;
+            }
+
+            text.push_str(&rest[..start]);
+
+            let offset = template.len() - rest.len() + start;
+            let line = template[..offset].matches('\n').count() + 1;
+
+            let Some(len) = rest[start + 2..].find("}}") else {
+                return Err(Error::Template {
+                    line,
+                    message: String::from("`{{` is not closed by `}}`"),
+                });
+            };
+
+            let placeholder = &rest[start + 2..start + 2 + len];
+
+            let part = parse_placeholder(placeholder)
+                .map_err(|message| Error::Template { line, message })?;
+
+            if !text.is_empty() {
+                parts.push(Part::Text(std::mem::take(&mut text)));
+            }
+
+            parts.push(part);
+            rest = &rest[start + 2 + len + 2..];
+        }
+
+        text.push_str(rest);
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+
+        Ok(Self { parts })
+    }
+
+    /// Renders the template for a theme.
+    #[must_use]
+    pub fn render(&self, theme: &Theme) -> String {
+        let mut out = String::new();
+
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => out.push_str(text),
+                Part::Name => out.push_str(&theme.name),
+                Part::IsDark => {
+                    out.push_str(if theme.background.is_dark() {
+                        "true"
+                    } else {
+                        "false"
+                    });
+                }
+                Part::Color {
+                    slot,
+                    format,
+                    filters,
+                } => {
+                    write_color(&mut out, theme.slots()[*slot].1, *format, filters);
+                }
+            }
+        }
+
+        out
+    }
+}
+
+// Parses the text between `{{` and `}}`.
+fn parse_placeholder(placeholder: &str) -> std::result::Result<Part, String> {
+    let mut pieces = placeholder.split('|').map(str::trim);
+    let head = pieces.next().unwrap_or_default();
+
+    let filters = pieces
+        .map(parse_filter)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    if head.is_empty() {
+        return Err(String::from("empty placeholder"));
+    }
+
+    let (value, format) = head
+        .split_once('.')
+        .map_or((head, None), |(v, f)| (v, Some(f)));
+
+    if matches!(value, "name" | "is_dark") {
+        if let Some(format) = format {
+            return Err(format!("`{value}` has no format `.{format}`"));
+        }
+
+        if !filters.is_empty() {
+            return Err(format!("filters only apply to colors, not `{value}`"));
+        }
+
+        return Ok(if value == "name" {
+            Part::Name
+        } else {
+            Part::IsDark
+        });
+    }
+
+    let Some(slot) = COLOR_KEYS.iter().position(|&key| key == value) else {
+        let mut msg = format!("unknown placeholder `{value}`");
+
+        if let Some(similar) = COLOR_KEYS
+            .iter()
+            .chain(&["name", "is_dark"])
+            .filter(|k| edit_distance(value, k) <= 2)
+            .min_by_key(|k| edit_distance(value, k))
+        {
+            let _ = write!(msg, "; did you mean `{similar}`?");
+        }
+
+        return Err(msg);
+    };
+
+    let format = match format {
+        None => Format::Hex,
+        Some(name) => FORMATS
+            .iter()
+            .find_map(|&(n, format)| (n == name).then_some(format))
+            .ok_or_else(|| {
+                let names = FORMATS.map(|(n, _)| n).join(", ");
+                format!("unknown format `.{name}` (expected one of {names})")
+            })?,
+    };
+
+    Ok(Part::Color {
+        slot,
+        format,
+        filters,
+    })
+}
+
+// Parses a filter such as `lighten(0.2)`.
+fn parse_filter(filter: &str) -> std::result::Result<Filter, String> {
+    let (name, arg) = filter
+        .strip_suffix(')')
+        .and_then(|f| f.split_once('('))
+        .map(|(name, arg)| (name.trim(), arg.trim()))
+        .ok_or_else(|| format!("expected a filter such as `lighten(0.2)`, found `{filter}`"))?;
+
+    if !FILTERS.contains(&name) {
+        return Err(format!(
+            "unknown filter `{name}` (expected one of {})",
+            FILTERS.join(", ")
+        ));
+    }
+
+    let amount = arg
+        .parse::<f64>()
+        .ok()
+        .filter(|amount| (0.0..=1.0).contains(amount))
+        .ok_or_else(|| format!("`{name}` takes a number from 0 to 1, not `{arg}`"))?;
+
+    Ok(match name {
+        "lighten" => Filter::Lighten(amount),
+        "darken" => Filter::Darken(amount),
+        _ => Filter::Alpha(amount),
+    })
+}
+
+// Applies the filters to a color and writes it in the given format.
+fn write_color(out: &mut String, color: Rgb, format: Format, filters: &[Filter]) {
+    let mut alpha = None;
+
+    let color = filters.iter().fold(color, |color, filter| match *filter {
+        Filter::Lighten(amount) => color.mix(Rgb::new(0xFF, 0xFF, 0xFF), amount),
+        Filter::Darken(amount) => color.mix(Rgb::new(0, 0, 0), amount),
+        Filter::Alpha(amount) => {
+            alpha = Some(amount);
+            color
+        }
+    });
+
+    let _ = match format {
+        Format::Hex | Format::HexStripped => {
+            let hex = color.to_string();
+            let hex = if format == Format::Hex {
+                &hex[..]
+            } else {
+                &hex[1..]
+            };
+            let alpha = alpha.map_or_else(String::new, |alpha| {
+                format!("{:02X}", to_channel(alpha * 255.0))
+            });
+
+            write!(out, "{hex}{alpha}")
+        }
+        Format::Rgb => {
+            let alpha = alpha.map_or_else(String::new, |alpha| format!(",{alpha}"));
+
+            write!(out, "{},{},{}{alpha}", color.r, color.g, color.b)
+        }
+        Format::Red => write!(out, "{}", color.r),
+        Format::Green => write!(out, "{}", color.g),
+        Format::Blue => write!(out, "{}", color.b),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Themes;
+
+    // Renders a template for Tokyo Night, whose background is #1A1B26 and
+    // whose color4 is #7AA2F7.
+    fn render(template: &str) -> String {
+        let theme = Themes::init().get("Tokyo Night").unwrap();
+        Template::parse(template).unwrap().render(theme)
+    }
+
+    // Returns the line and message of a template's parse error.
+    fn error(template: &str) -> (usize, String) {
+        match Template::parse(template) {
+            Err(Error::Template { line, message }) => (line, message),
+            other => panic!("expected a template error for {template:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(render("{{ color4 }}"), "#7AA2F7");
+        assert_eq!(render("{{ color4.hex }}"), "#7AA2F7");
+        assert_eq!(render("{{ color4.hex_stripped }}"), "7AA2F7");
+        assert_eq!(render("{{ color4.rgb }}"), "122,162,247");
+        assert_eq!(
+            render("{{ color4.r }} {{ color4.g }} {{ color4.b }}"),
+            "122 162 247"
+        );
+        assert_eq!(
+            render("{{background}}/{{ foreground }}/{{cursor}}"),
+            "#1A1B26/#C0CAF5/#C0CAF5"
+        );
+    }
+
+    #[test]
+    fn name_and_is_dark() {
+        assert_eq!(render("{{ name }}: {{ is_dark }}"), "Tokyo Night: true");
+
+        let light = Themes::init().get("Ayu Light").unwrap();
+        let template = Template::parse("{{ name }}: {{ is_dark }}").unwrap();
+
+        assert_eq!(template.render(light), "Ayu Light: false");
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(render("{{ background | lighten(0.2) }}"), "#484951");
+        assert_eq!(render("{{ color4 | darken(0.1) }}"), "#6E92DE");
+        assert_eq!(render("{{ color4 | lighten(1) }}"), "#FFFFFF");
+        assert_eq!(render("{{ color4 | darken(1.0) }}"), "#000000");
+        assert_eq!(render("{{ color4 | lighten(0) }}"), "#7AA2F7");
+
+        // Filters apply from left to right.
+        assert_eq!(render("{{ color4 | darken(1) | lighten(1) }}"), "#FFFFFF");
+        assert_eq!(render("{{ color4 | lighten(1) | darken(1) }}"), "#000000");
+    }
+
+    #[test]
+    fn alpha_in_each_format() {
+        assert_eq!(render("{{ color4 | alpha(0.5) }}"), "#7AA2F780");
+        assert_eq!(render("{{ color4.hex_stripped | alpha(1) }}"), "7AA2F7FF");
+        assert_eq!(render("{{ color4.rgb | alpha(0.25) }}"), "122,162,247,0.25");
+        assert_eq!(render("{{ color4.r | alpha(0.5) }}"), "122");
+        assert_eq!(
+            render("{{ background.rgb|lighten(0.2)|alpha(0) }}"),
+            "72,73,81,0"
+        );
+    }
+
+    #[test]
+    fn text_and_escapes() {
+        assert_eq!(render(""), "");
+        assert_eq!(render("no placeholders }}"), "no placeholders }}");
+        assert_eq!(render(r"\{{ name }}"), "{{ name }}");
+        assert_eq!(render(r"a \{{ b {{ name }} \{{"), "a {{ b Tokyo Night {{");
+        assert_eq!(render("{{ name }}{{ name }}"), "Tokyo NightTokyo Night");
+        assert_eq!(render("{ {{ color0 }} }"), "{ #414868 }");
+    }
+
+    #[test]
+    fn unknown_slot() {
+        assert_eq!(
+            error("{{ color16 }}"),
+            (
+                1,
+                "unknown placeholder `color16`; did you mean `color1`?".into()
+            )
+        );
+        assert_eq!(
+            error("a\nb\n{{ backgound.hex }}"),
+            (
+                3,
+                "unknown placeholder `backgound`; did you mean `background`?".into()
+            )
+        );
+        assert_eq!(
+            error("{{ primary }}"),
+            (1, "unknown placeholder `primary`".into())
+        );
+        assert_eq!(error("{{  }}"), (1, "empty placeholder".into()));
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert_eq!(
+            error("{{ color4.hsl }}"),
+            (
+                1,
+                "unknown format `.hsl` (expected one of hex, hex_stripped, rgb, r, g, b)".into()
+            )
+        );
+        assert_eq!(
+            error("{{ name.hex }}"),
+            (1, "`name` has no format `.hex`".into())
+        );
+    }
+
+    #[test]
+    fn unknown_filter() {
+        assert_eq!(
+            error("{{ color4 | saturate(0.2) }}"),
+            (
+                1,
+                "unknown filter `saturate` (expected one of lighten, darken, alpha)".into()
+            )
+        );
+        assert_eq!(
+            error("{{ color4 | lighten }}"),
+            (
+                1,
+                "expected a filter such as `lighten(0.2)`, found `lighten`".into()
+            )
+        );
+        assert_eq!(
+            error("{{ color4 | lighten(1.5) }}"),
+            (1, "`lighten` takes a number from 0 to 1, not `1.5`".into())
+        );
+        assert_eq!(
+            error("{{ color4 | alpha(half) }}"),
+            (1, "`alpha` takes a number from 0 to 1, not `half`".into())
+        );
+        assert_eq!(
+            error("{{ is_dark | alpha(0.5) }}"),
+            (1, "filters only apply to colors, not `is_dark`".into())
+        );
+    }
+
+    #[test]
+    fn unclosed_placeholder() {
+        assert_eq!(
+            error("{{ name }}\n{{ color4 }\n"),
+            (2, "`{{` is not closed by `}}`".into())
+        );
+        assert_eq!(error("{{"), (1, "`{{` is not closed by `}}`".into()));
+    }
+}
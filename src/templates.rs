@@ -0,0 +1,58 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io::ErrorKind;
+
+use themux::template::Template;
+
+use crate::termux::Paths;
+use crate::util::suggest;
+
+/// Returns the names of the files in the template directory.
+pub fn names() -> Result<Vec<String>, String> {
+    let dir = Paths::get().templates_dir();
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Unable to read {}: {e}", dir.display())),
+    };
+
+    let mut names = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(String::from)
+        })
+        .filter(|name| !name.starts_with('.'))
+        .collect::<Vec<String>>();
+
+    names.sort();
+
+    Ok(names)
+}
+
+/// Reads and parses a template in the template directory.
+pub fn load(name: &str) -> Result<Template, String> {
+    let path = Paths::get().templates_dir().join(name);
+
+    let content = fs::read_to_string(&path).map_err(|e| {
+        if e.kind() != ErrorKind::NotFound || name.contains('/') {
+            return format!("Unable to read {}: {e}", path.display());
+        }
+
+        let mut msg = format!("There is no template named \"{name}\"");
+
+        if let Some(similar) = names()
+            .ok()
+            .and_then(|names| suggest(name, names.iter().map(String::as_str)).map(String::from))
+        {
+            let _ = write!(msg, "; did you mean \"{similar}\"?");
+        }
+
+        msg
+    })?;
+
+    Template::parse(&content).map_err(|e| format!("{}: {e}", path.display()))
+}
@@ -0,0 +1,74 @@
+use std::env;
+
+/// The width assumed when the terminal width can't be determined.
+const DEFAULT_WIDTH: usize = 80;
+
+/// Returns the width of the terminal in columns.
+///
+/// The width is queried from stdout, stderr and the controlling terminal in
+/// turn, so it is still found when output is piped. Otherwise, `$COLUMNS` is
+/// used if set, and then a default of 80 columns.
+pub fn width() -> usize {
+    sys::fd_width(sys::STDOUT)
+        .or_else(|| sys::fd_width(sys::STDERR))
+        .or_else(sys::tty_width)
+        .or_else(columns_var)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+// Returns the width given by the `COLUMNS` environment variable.
+fn columns_var() -> Option<usize> {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|cols| cols.trim().parse::<usize>().ok())
+        .filter(|&cols| cols > 0)
+}
+
+#[cfg(unix)]
+mod sys {
+    use std::fs::File;
+    use std::os::fd::{AsRawFd, RawFd};
+
+    pub const STDOUT: RawFd = libc::STDOUT_FILENO;
+    pub const STDERR: RawFd = libc::STDERR_FILENO;
+
+    /// Returns the width of the terminal open on `fd`, if it is one.
+    pub fn fd_width(fd: RawFd) -> Option<usize> {
+        let mut size = libc::winsize {
+            ws_row: 0,
+            ws_col: 0,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+
+        // SAFETY: TIOCGWINSZ only writes a `winsize` through the pointer,
+        // which is valid for the duration of the call. An invalid or
+        // non-terminal `fd` is reported through the return value.
+        let res = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) };
+
+        (res == 0 && size.ws_col > 0).then(|| usize::from(size.ws_col))
+    }
+
+    /// Returns the width of the controlling terminal.
+    pub fn tty_width() -> Option<usize> {
+        let tty = File::open("/dev/tty").ok()?;
+
+        fd_width(tty.as_raw_fd())
+    }
+}
+
+#[cfg(not(unix))]
+mod sys {
+    pub const STDOUT: i32 = 1;
+    pub const STDERR: i32 = 2;
+
+    /// Terminal sizes are only queried on Unix.
+    pub const fn fd_width(_fd: i32) -> Option<usize> {
+        None
+    }
+
+    /// Terminal sizes are only queried on Unix.
+    pub const fn tty_width() -> Option<usize> {
+        None
+    }
+}
@@ -0,0 +1,713 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use themux::{properties, Error, Theme};
+
+use crate::util::wait_timeout;
+use crate::verbose;
+
+/// The environment variable that overrides the Termux directory.
+pub const TERMUX_DIR_VAR: &str = "THEMUX_TERMUX_DIR";
+
+/// The environment variable that overrides the reload command.
+pub const RELOAD_COMMAND_VAR: &str = "THEMUX_RELOAD_COMMAND";
+
+/// The command Termux provides for reloading its settings.
+const RELOAD_COMMAND: &str = "termux-reload-settings";
+
+/// The arguments to `am` that send Termux's reload intent, which is all
+/// `termux-reload-settings` does.
+const RELOAD_BROADCAST: [&str; 6] = [
+    "broadcast",
+    "--user",
+    "0",
+    "-a",
+    "com.termux.app.reload_style",
+    "com.termux",
+];
+
+/// How long reloading may take before it is considered failed, unless the
+/// configuration file says otherwise.
+pub const DEFAULT_RELOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+static PATHS: OnceLock<Paths> = OnceLock::new();
+
+/// The locations of the files that themux reads and writes.
+#[derive(Clone, Debug)]
+pub struct Paths {
+    /// Directory containing Termux's configuration files.
+    termux_dir: PathBuf,
+    /// Directory containing themux's own files.
+    config_dir: PathBuf,
+    /// Directory every other path is redirected beneath, if any.
+    root: Option<PathBuf>,
+    /// Whether the Termux directory was chosen, rather than the default, so
+    /// that it may be created.
+    is_termux_dir_chosen: bool,
+}
+
+impl Paths {
+    /// Determines the paths from the command-line overrides, the environment
+    /// and the defaults, in that order.
+    ///
+    /// When `root` is given, every path is redirected beneath it, so that
+    /// `$HOME/.termux` becomes `<root>/$HOME/.termux`.
+    pub fn new(termux_dir: Option<&str>, root: Option<&str>) -> Result<Self, String> {
+        let home = || {
+            env::var_os("HOME")
+                .filter(|home| !home.is_empty())
+                .map(PathBuf::from)
+                .ok_or_else(|| String::from("'HOME' environment variable must be set"))
+        };
+
+        let is_termux_dir_chosen = termux_dir.is_some() || root.is_some();
+
+        let termux_dir = match termux_dir.map(PathBuf::from) {
+            Some(dir) => dir,
+            None => match env::var_os(TERMUX_DIR_VAR) {
+                Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+                _ => home()?.join(".termux"),
+            },
+        };
+
+        // See: https://specifications.freedesktop.org/basedir-spec/latest/
+        let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if Path::new(&dir).is_absolute() => PathBuf::from(dir),
+            _ => home()?.join(".config"),
+        }
+        .join("themux");
+
+        Ok(match root {
+            Some(root) => Self {
+                termux_dir: reroot(Path::new(root), &termux_dir),
+                config_dir: reroot(Path::new(root), &config_dir),
+                root: Some(PathBuf::from(root)),
+                is_termux_dir_chosen,
+            },
+            None => Self {
+                termux_dir,
+                config_dir,
+                root: None,
+                is_termux_dir_chosen,
+            },
+        })
+    }
+
+    /// Sets the paths used for the rest of the program's run.
+    pub fn init(self) {
+        verbose!("using Termux directory {}", self.termux_dir.display());
+        verbose!("using config directory {}", self.config_dir.display());
+
+        PATHS
+            .set(self)
+            .expect("paths should only be initialized once");
+    }
+
+    /// Returns the paths set by `Paths::init`, or the defaults.
+    pub fn get() -> &'static Self {
+        PATHS.get_or_init(|| Self::new(None, None).unwrap_or_else(|e| crate::fail!("{e}")))
+    }
+
+    /// Returns the Termux configuration directory.
+    pub fn termux_dir(&self) -> &Path {
+        &self.termux_dir
+    }
+
+    /// Returns true if Termux's files can be written: the Termux directory
+    /// exists, or was chosen with `--termux-dir` or `--root` and is created
+    /// on the first write.
+    pub fn is_termux_dir_writable(&self) -> bool {
+        self.is_termux_dir_chosen || self.termux_dir.is_dir()
+    }
+
+    /// Returns themux's configuration directory.
+    pub fn config_dir(&self) -> &Path {
+        &self.config_dir
+    }
+
+    /// Returns a path given in a configuration file, with a leading `~/`
+    /// standing for the home directory. Relative paths are taken from the
+    /// config directory. With a root, the result never leaves it.
+    pub fn expand(&self, path: &str) -> PathBuf {
+        let path = match (path.strip_prefix("~/"), env::var_os("HOME")) {
+            (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+            _ => PathBuf::from(path),
+        };
+
+        match &self.root {
+            Some(root) if path.is_relative() => {
+                let config_dir = self
+                    .config_dir
+                    .strip_prefix(root)
+                    .unwrap_or(&self.config_dir);
+                reroot(root, &config_dir.join(path))
+            }
+            Some(root) => reroot(root, &path),
+            None if path.is_relative() => self.config_dir.join(path),
+            None => path,
+        }
+    }
+
+    /// Returns the path to the list of recently applied themes.
+    pub fn history_file(&self) -> PathBuf {
+        self.config_dir.join("history")
+    }
+
+    /// Returns the path to the color settings file.
+    pub fn settings_file(&self) -> PathBuf {
+        self.termux_dir.join("colors.properties")
+    }
+
+    /// Returns the path to the backup of the previous color settings file.
+    pub fn backup_file(&self) -> PathBuf {
+        self.termux_dir.join("colors.properties.bak")
+    }
+
+    /// Returns the path to Termux's general settings file.
+    pub fn properties_file(&self) -> PathBuf {
+        self.termux_dir.join("termux.properties")
+    }
+
+    /// Returns the path to the backup of the previous general settings file.
+    pub fn properties_backup_file(&self) -> PathBuf {
+        self.termux_dir.join("termux.properties.bak")
+    }
+
+    /// Returns the path to the font Termux uses instead of its default.
+    pub fn font_file(&self) -> PathBuf {
+        self.termux_dir.join("font.ttf")
+    }
+
+    /// Returns the path to the backup of the previous font.
+    pub fn font_backup_file(&self) -> PathBuf {
+        self.termux_dir.join("font.ttf.bak")
+    }
+
+    /// Returns the themux configuration file.
+    pub fn config_file(&self) -> PathBuf {
+        self.config_dir.join("config.toml")
+    }
+
+    /// Returns the directory that generated configuration is written to.
+    pub fn generated_dir(&self) -> PathBuf {
+        self.config_dir.join("generated")
+    }
+
+    /// Returns the default desired-state file read by `themux sync`.
+    pub fn sync_file(&self) -> PathBuf {
+        self.config_dir.join("themux.toml")
+    }
+
+    /// Returns the directory of themes saved by `themux edit`.
+    pub fn themes_dir(&self) -> PathBuf {
+        self.config_dir.join("themes")
+    }
+
+    /// Returns the directory of user templates.
+    pub fn templates_dir(&self) -> PathBuf {
+        self.config_dir.join("templates")
+    }
+
+    /// Returns the directory of saved profiles.
+    pub fn profiles_dir(&self) -> PathBuf {
+        self.config_dir.join("profiles")
+    }
+
+    /// Returns the directory of fonts offered by `themux font`.
+    pub fn fonts_dir(&self) -> PathBuf {
+        self.config_dir.join("fonts")
+    }
+}
+
+// Returns `path` relocated beneath `root`.
+fn reroot(root: &Path, path: &Path) -> PathBuf {
+    let mut rerooted = root.to_path_buf();
+
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+            // A `..` never climbs out of the root.
+            Component::ParentDir => {
+                if rerooted != root {
+                    rerooted.pop();
+                }
+            }
+            Component::Normal(part) => rerooted.push(part),
+        }
+    }
+
+    rerooted
+}
+
+/// Returns the path to the color settings file.
+pub fn get_settings_file_path() -> Result<PathBuf, String> {
+    let paths = Paths::get();
+    let path = paths.settings_file();
+
+    if matches!(path.try_exists(), Ok(true)) {
+        return Ok(path);
+    }
+
+    if paths.is_termux_dir_writable() {
+        // The file, and if need be the directory, are created on write.
+        Ok(path)
+    } else {
+        Err(format!(
+            "Color settings file cannot be created ({} is not a directory)",
+            paths.termux_dir().display()
+        ))
+    }
+}
+
+/// A pending change to one of Termux's files.
+#[derive(Clone, Debug)]
+pub struct Change {
+    /// The file to write.
+    pub path: PathBuf,
+    /// Where the file's current content is copied first, if anywhere.
+    pub backup: Option<PathBuf>,
+    /// The file's new content.
+    pub content: Vec<u8>,
+}
+
+impl Change {
+    /// Returns a change to the color settings file.
+    pub fn settings(content: String) -> Result<Self, String> {
+        Ok(Self {
+            path: get_settings_file_path()?,
+            backup: Some(Paths::get().backup_file()),
+            content: content.into_bytes(),
+        })
+    }
+
+    /// Returns a change to `termux.properties`.
+    pub fn properties(content: String) -> Result<Self, String> {
+        let paths = Paths::get();
+
+        if !paths.is_termux_dir_writable() {
+            return Err(format!(
+                "{} cannot be created ({} is not a directory)",
+                paths.properties_file().display(),
+                paths.termux_dir().display()
+            ));
+        }
+
+        Ok(Self {
+            path: paths.properties_file(),
+            backup: Some(paths.properties_backup_file()),
+            content: content.into_bytes(),
+        })
+    }
+
+    /// Returns a change that installs a font.
+    pub fn font(data: Vec<u8>) -> Result<Self, String> {
+        let paths = Paths::get();
+
+        if !paths.is_termux_dir_writable() {
+            return Err(format!(
+                "Font cannot be installed ({} is not a directory)",
+                paths.termux_dir().display()
+            ));
+        }
+
+        Ok(Self {
+            path: paths.font_file(),
+            backup: Some(paths.font_backup_file()),
+            content: data,
+        })
+    }
+
+    /// Returns the change without its backup, so that writing it leaves the
+    /// previous backup as it is.
+    #[must_use]
+    pub fn without_backup(self) -> Self {
+        Self {
+            backup: None,
+            ..self
+        }
+    }
+
+    /// Returns true if the change would leave the file as it is.
+    pub fn is_noop(&self) -> bool {
+        fs::read(&self.path).is_ok_and(|current| current == self.content)
+    }
+
+    /// Writes the new content, first copying the existing file (if any) to
+    /// the backup file.
+    pub fn write(&self) -> Result<(), String> {
+        replace_file(&self.path, self.backup.as_deref(), &self.content)
+    }
+}
+
+/// The files touched by `write_all` as they were before, so that the
+/// changes can be undone.
+#[derive(Debug, Default)]
+pub struct Undo {
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+impl Undo {
+    // Records a file's current content, or that it doesn't exist.
+    fn record(&mut self, path: &Path) {
+        self.files.push((path.to_path_buf(), fs::read(path).ok()));
+    }
+
+    /// Puts every file back as it was, in reverse order.
+    pub fn run(self) -> Result<(), String> {
+        let mut res = Ok(());
+
+        for (path, previous) in self.files.into_iter().rev() {
+            verbose!("rolling back {}", path.display());
+
+            let undone = previous.map_or_else(
+                || match fs::remove_file(&path) {
+                    Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+                    res => res,
+                },
+                |previous| fs::write(&path, previous),
+            );
+
+            if let Err(e) = undone {
+                res = Err(format!("Unable to restore {}: {e}", path.display()));
+            }
+        }
+
+        res
+    }
+}
+
+/// Writes every change, or none of them: if a write fails, the files
+/// already written are put back as they were.
+///
+/// Returns what is needed to undo the changes, and their backups, later.
+pub fn write_all(changes: &[Change]) -> Result<Undo, String> {
+    let mut undo = Undo::default();
+
+    for change in changes {
+        if let Some(backup) = &change.backup {
+            undo.record(backup);
+        }
+
+        undo.record(&change.path);
+
+        if let Err(e) = change.write() {
+            let _ = undo.run();
+            return Err(e);
+        }
+    }
+
+    Ok(undo)
+}
+
+/// Returns the theme in the color settings file, or Termux's default colors
+/// if there is no such file.
+pub fn read_theme() -> Result<Theme, String> {
+    let path = Paths::get().settings_file();
+
+    match properties::read(&path) {
+        Ok(theme) => Ok(theme),
+        Err(Error::Io { source, .. }) if source.kind() == ErrorKind::NotFound => {
+            Ok(Theme::TERMUX_DEFAULT)
+        }
+        Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+    }
+}
+
+/// Returns the content of `termux.properties`, which is empty if the file
+/// doesn't exist.
+pub fn read_properties() -> Result<String, String> {
+    let path = Paths::get().properties_file();
+
+    match fs::read_to_string(&path) {
+        Ok(content) => Ok(content),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+    }
+}
+
+// Writes a file, first copying its existing content to `backup`, if given.
+fn replace_file(path: &Path, backup: Option<&Path>, content: &[u8]) -> Result<(), String> {
+    if let Some(backup) = backup.filter(|_| matches!(path.try_exists(), Ok(true))) {
+        verbose!("backing up {} to {}", path.display(), backup.display());
+
+        fs::copy(path, backup).map_err(|e| format!("Unable to back up {}: {e}", path.display()))?;
+    }
+
+    verbose!("writing {}", path.display());
+
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, content))
+        .map_err(|e| format!("Unable to write {}: {e}", path.display()))
+}
+
+/// How the Termux settings are reloaded.
+#[derive(Clone, Debug)]
+pub struct Reload {
+    /// The command from the configuration file, if any.
+    pub command: Option<String>,
+    /// How long the command may take.
+    pub timeout: Duration,
+}
+
+impl Default for Reload {
+    fn default() -> Self {
+        Self {
+            command: None,
+            timeout: DEFAULT_RELOAD_TIMEOUT,
+        }
+    }
+}
+
+impl Reload {
+    /// Returns the command used to reload the Termux settings:
+    /// `THEMUX_RELOAD_COMMAND`, the configured command, or
+    /// `termux-reload-settings`, in that order.
+    ///
+    /// If `termux-reload-settings` isn't installed, this is the `am` command
+    /// that sends Termux's reload intent instead.
+    pub fn command(&self) -> String {
+        self.program().command()
+    }
+
+    /// Reloads the Termux settings, failing if the command can't be run,
+    /// exits unsuccessfully or takes longer than the timeout.
+    pub fn run(&self) -> Result<(), String> {
+        let program = self.program();
+        let cmd = program.command();
+
+        verbose!("running {cmd}");
+
+        let child = match program {
+            Program::Helper => group_command(RELOAD_COMMAND).spawn(),
+            Program::Broadcast => group_command("am")
+                .args(RELOAD_BROADCAST)
+                .stdout(Stdio::null())
+                .spawn(),
+            Program::Shell(_) => group_command("sh").arg("-c").arg(&cmd).spawn(),
+        };
+
+        let mut child = child
+            .map_err(|e| format!("Unable to reload Termux settings: `{cmd}` could not run: {e}"))?;
+
+        match wait_timeout(&mut child, self.timeout) {
+            Ok(Some(status)) if status.success() => Ok(()),
+            Ok(Some(status)) => Err(format!(
+                "Unable to reload Termux settings: `{cmd}` failed ({status})"
+            )),
+            Ok(None) => Err(format!(
+                "Unable to reload Termux settings: `{cmd}` was stopped after {}s",
+                self.timeout.as_secs()
+            )),
+            Err(e) => Err(format!("Unable to reload Termux settings: {e}")),
+        }
+    }
+
+    // Returns what reloading runs.
+    fn program(&self) -> Program {
+        let is_set = |cmd: &String| !cmd.trim().is_empty();
+
+        let cmd = env::var(RELOAD_COMMAND_VAR)
+            .ok()
+            .filter(is_set)
+            .or_else(|| self.command.clone().filter(is_set));
+
+        match cmd {
+            Some(cmd) => Program::Shell(cmd),
+            None if is_on_path(RELOAD_COMMAND) => Program::Helper,
+            None => {
+                verbose!("{RELOAD_COMMAND} not found, using am");
+                Program::Broadcast
+            }
+        }
+    }
+}
+
+// What reloading the Termux settings runs.
+enum Program {
+    // `termux-reload-settings`.
+    Helper,
+    // `am`, sending the intent `termux-reload-settings` would.
+    Broadcast,
+    // A command run with `sh -c`.
+    Shell(String),
+}
+
+impl Program {
+    // Returns the command line, as shown to the user.
+    fn command(&self) -> String {
+        match self {
+            Self::Helper => RELOAD_COMMAND.to_string(),
+            Self::Broadcast => format!("am {}", RELOAD_BROADCAST.join(" ")),
+            Self::Shell(cmd) => cmd.clone(),
+        }
+    }
+}
+
+// Returns true if `program` is an executable file in one of the `PATH`
+// directories.
+fn is_on_path(program: &str) -> bool {
+    let is_executable = |path: &Path| {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            fs::metadata(path).is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        }
+
+        #[cfg(not(unix))]
+        path.is_file()
+    };
+
+    env::var_os("PATH")
+        .is_some_and(|path| env::split_paths(&path).any(|dir| is_executable(&dir.join(program))))
+}
+
+// Returns a command that runs in a new process group, so that a timeout also
+// stops the processes it starts, such as those run by `sh -c`.
+fn group_command(program: &str) -> Command {
+    let mut cmd = Command::new(program);
+
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+    cmd
+}
+
+/// Checks the environment variables for an indication that we are in Termux.
+pub fn is_termux_env() -> bool {
+    for (var_name, _) in env::vars_os() {
+        if let Some(name) = var_name.as_os_str().to_str() {
+            // Our own variables don't count.
+            if name.contains("TERMUX") && !name.starts_with("THEMUX_") {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns paths beneath a root, as `--root /sb` would give them.
+    fn sandbox() -> Paths {
+        Paths {
+            termux_dir: PathBuf::from("/sb/home/.termux"),
+            config_dir: PathBuf::from("/sb/home/.config/themux"),
+            root: Some(PathBuf::from("/sb")),
+            is_termux_dir_chosen: true,
+        }
+    }
+
+    #[test]
+    fn reroot_moves_paths_beneath_the_root() {
+        let root = Path::new("/sb");
+
+        assert_eq!(
+            reroot(root, Path::new("/home/.termux")),
+            Path::new("/sb/home/.termux")
+        );
+        assert_eq!(
+            reroot(root, Path::new("home/./a/../b")),
+            Path::new("/sb/home/b")
+        );
+    }
+
+    #[test]
+    fn reroot_stops_parent_dirs_at_the_root() {
+        let root = Path::new("/sb");
+
+        assert_eq!(reroot(root, Path::new("/../../etc")), Path::new("/sb/etc"));
+        assert_eq!(
+            reroot(root, Path::new("/a/../../../etc/passwd")),
+            Path::new("/sb/etc/passwd")
+        );
+        assert_eq!(reroot(root, Path::new("..")), Path::new("/sb"));
+    }
+
+    #[test]
+    fn expand_keeps_relative_paths_beneath_the_root() {
+        let paths = sandbox();
+
+        assert_eq!(
+            paths.expand("out/fzf.sh"),
+            Path::new("/sb/home/.config/themux/out/fzf.sh")
+        );
+        assert_eq!(paths.expand("../x"), Path::new("/sb/home/.config/x"));
+        assert_eq!(paths.expand("../../../../../x"), Path::new("/sb/x"));
+    }
+
+    #[test]
+    fn expand_keeps_absolute_paths_beneath_the_root() {
+        let paths = sandbox();
+
+        assert_eq!(paths.expand("/etc/passwd"), Path::new("/sb/etc/passwd"));
+        assert_eq!(
+            paths.expand("/../../etc/passwd"),
+            Path::new("/sb/etc/passwd")
+        );
+    }
+
+    #[test]
+    fn expand_without_a_root_uses_the_config_dir() {
+        let paths = Paths {
+            root: None,
+            ..sandbox()
+        };
+
+        assert_eq!(
+            paths.expand("a.sh"),
+            Path::new("/sb/home/.config/themux/a.sh")
+        );
+        assert_eq!(paths.expand("/etc/a.sh"), Path::new("/etc/a.sh"));
+    }
+
+    #[test]
+    fn write_all_rolls_back_when_a_write_fails() {
+        let dir = env::temp_dir().join(format!("themux-write-all-{}", std::process::id()));
+        fs::create_dir_all(dir.join("blocked")).unwrap();
+
+        let (colors, backup) = (dir.join("colors"), dir.join("colors.bak"));
+        fs::write(&colors, "old").unwrap();
+        fs::write(&backup, "older").unwrap();
+
+        let colors_change = Change {
+            path: colors.clone(),
+            backup: Some(backup.clone()),
+            content: b"new".to_vec(),
+        };
+        let blocked = Change {
+            path: dir.join("blocked"),
+            backup: None,
+            content: b"x".to_vec(),
+        };
+
+        // Writing over a directory fails, so the first change is undone.
+        let res = write_all(&[colors_change.clone(), blocked]);
+
+        assert!(res.is_err());
+        assert_eq!(fs::read_to_string(&colors).unwrap(), "old");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "older");
+
+        // A successful write can be undone later.
+        let undo = write_all(&[colors_change]).unwrap();
+
+        assert_eq!(fs::read_to_string(&colors).unwrap(), "new");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "old");
+
+        undo.run().unwrap();
+
+        assert_eq!(fs::read_to_string(&colors).unwrap(), "old");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "older");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,511 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::color::Rgb;
+use crate::data::{ALL_THEMES, FOLDED_NAMES, NAME_SEEDS, NAME_SLOTS};
+use crate::names::{edit_distance, fold_name};
+use crate::phf;
+use crate::properties::COLOR_KEYS;
+use crate::query::Query;
+
+/// The maximum number of suggestions offered for an unknown theme name.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// The built-in theme catalog.
+///
+/// The catalog is a static table generated at build time, sorted by
+/// case-folded name and indexed by a perfect hash, so looking up, filtering
+/// and listing themes never allocates.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Themes;
+
+impl Themes {
+    /// Returns the built-in catalog.
+    #[must_use]
+    pub const fn init() -> Self {
+        Self
+    }
+
+    /// Returns all themes as a slice, sorted case-insensitively by name.
+    #[must_use]
+    pub const fn as_slice(self) -> &'static [Theme] {
+        &ALL_THEMES
+    }
+
+    /// Returns an iterator over the themes, sorted case-insensitively by
+    /// name.
+    pub fn iter(self) -> std::slice::Iter<'static, Theme> {
+        ALL_THEMES.iter()
+    }
+
+    /// Returns an iterator over the dark themes (those with a dark
+    /// background).
+    pub fn dark(self) -> impl Iterator<Item = &'static Theme> {
+        self.iter().filter(|theme| theme.background.is_dark())
+    }
+
+    /// Returns an iterator over the light themes.
+    pub fn light(self) -> impl Iterator<Item = &'static Theme> {
+        self.iter().filter(|theme| !theme.background.is_dark())
+    }
+
+    /// Returns an iterator over the themes that satisfy a query.
+    pub fn find(self, query: &Query) -> impl Iterator<Item = &'static Theme> + '_ {
+        self.iter().filter(|theme| query.matches(theme))
+    }
+
+    /// Returns the number of themes.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        ALL_THEMES.len()
+    }
+
+    /// Returns true if there are no themes.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        ALL_THEMES.is_empty()
+    }
+
+    /// Returns the `Theme` that matches the given `name` (case insensitively),
+    /// if `name` is a valid theme name.
+    #[must_use]
+    pub fn get(self, query: &str) -> Option<&'static Theme> {
+        let slot = phf::slot(query.as_bytes(), &NAME_SEEDS, NAME_SLOTS.len());
+        let theme = &ALL_THEMES[usize::from(NAME_SLOTS[slot])];
+
+        theme.name.eq_ignore_ascii_case(query).then_some(theme)
+    }
+
+    /// Resolves a possibly inexact theme name to a `Theme`.
+    ///
+    /// In order of preference, `query` matches the theme whose name equals it
+    /// case insensitively, the theme whose name equals it when both ignore
+    /// case, whitespace and punctuation (so "tokyo-night-storm" matches
+    /// "Tokyo Night Storm"), or the only theme whose name starts with it
+    /// under those same rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns the matching themes if the name is ambiguous, or the closest
+    /// names if nothing matches.
+    pub fn resolve(self, query: &str) -> Result<&'static Theme, Unresolved<'static>> {
+        if let Some(theme) = self.get(query) {
+            return Ok(theme);
+        }
+
+        self.resolve_with(query, &[])
+    }
+
+    /// Resolves a possibly inexact theme name as `resolve` does, among both
+    /// `extra` and the built-in themes. A theme in `extra` is preferred to a
+    /// built-in theme whose name folds to the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns the matching themes if the name is ambiguous, or the closest
+    /// names if nothing matches.
+    pub fn resolve_with(
+        self,
+        query: &str,
+        extra: &'static [Theme],
+    ) -> Result<&'static Theme, Unresolved<'static>> {
+        if fold_bytes(query).next().is_none() {
+            return Err(Unresolved::NotFound(Vec::new()));
+        }
+
+        let folded_names = extra
+            .iter()
+            .map(|theme| (theme, Cow::Owned(fold_name(&theme.name))))
+            .chain(
+                ALL_THEMES
+                    .iter()
+                    .zip(FOLDED_NAMES)
+                    .map(|(theme, name)| (theme, Cow::Borrowed(name))),
+            );
+
+        if let Some((theme, _)) = folded_names
+            .clone()
+            .find(|(_, folded)| folded.bytes().eq(fold_bytes(query)))
+        {
+            return Ok(theme);
+        }
+
+        let mut prefixed = folded_names
+            .clone()
+            .filter(|(_, folded)| {
+                let mut folded = folded.bytes();
+                fold_bytes(query).all(|b| folded.next() == Some(b))
+            })
+            .map(|(theme, _)| theme);
+
+        match (prefixed.next(), prefixed.next()) {
+            (None, _) => {}
+            (Some(theme), None) => return Ok(theme),
+            (Some(first), Some(second)) => {
+                let mut themes = vec![first, second];
+                themes.extend(prefixed);
+                return Err(Unresolved::Ambiguous(themes));
+            }
+        }
+
+        // No match, so suggest the closest names. Ranking on the distance to
+        // a prefix of each name first keeps long names from being penalized
+        // for the characters the user didn't type. Names further away than a
+        // plausible typo aren't suggested at all.
+        let folded = fold_name(query);
+        let max_distance = (folded.len() / 3).max(1);
+
+        let mut ranked = folded_names
+            .map(|(theme, name)| {
+                let prefix = &name[..name.len().min(folded.len())];
+                let key = (
+                    edit_distance(&folded, prefix),
+                    edit_distance(&folded, &name),
+                );
+                (key, theme)
+            })
+            .collect::<Vec<((usize, usize), &Theme)>>();
+
+        ranked.retain(|((distance, _), _)| *distance <= max_distance);
+        ranked.sort_by_key(|(key, _)| *key);
+
+        let suggestions = ranked
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, theme)| theme)
+            .collect();
+
+        Err(Unresolved::NotFound(suggestions))
+    }
+}
+
+impl IntoIterator for Themes {
+    type Item = &'static Theme;
+    type IntoIter = std::slice::Iter<'static, Theme>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Returns the bytes of `name` that `fold_name` keeps, lowercased.
+fn fold_bytes(name: &str) -> impl Iterator<Item = u8> + '_ {
+    name.bytes()
+        .filter(u8::is_ascii_alphanumeric)
+        .map(|b| b.to_ascii_lowercase())
+}
+
+/// The reason a theme name could not be resolved.
+#[derive(Clone, Debug)]
+pub enum Unresolved<'a> {
+    /// More than one theme starts with the name.
+    Ambiguous(Vec<&'a Theme>),
+    /// No theme matches the name; contains the closest names.
+    NotFound(Vec<&'a Theme>),
+}
+
+impl Unresolved<'_> {
+    /// Returns the candidate themes to present to the user.
+    #[must_use]
+    pub fn candidates(&self) -> &[&Theme] {
+        match self {
+            Self::Ambiguous(themes) | Self::NotFound(themes) => themes,
+        }
+    }
+}
+
+/// A theme palette.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    /// Black.
+    pub color0: Rgb,
+    /// Dim red.
+    pub color1: Rgb,
+    /// Dim green.
+    pub color2: Rgb,
+    /// Dim yellow.
+    pub color3: Rgb,
+    /// Dim blue.
+    pub color4: Rgb,
+    /// Dim purple.
+    pub color5: Rgb,
+    /// Dim cyan.
+    pub color6: Rgb,
+    /// Dim white.
+    pub color7: Rgb,
+    /// Medium gray.
+    pub color8: Rgb,
+    /// Bright red.
+    pub color9: Rgb,
+    /// Bright green.
+    pub color10: Rgb,
+    /// Bright yellow.
+    pub color11: Rgb,
+    /// Light blue.
+    pub color12: Rgb,
+    /// Bright purple.
+    pub color13: Rgb,
+    /// Bright cyan.
+    pub color14: Rgb,
+    /// Bright white.
+    pub color15: Rgb,
+    /// Background color.
+    pub background: Rgb,
+    /// Foreground color.
+    pub foreground: Rgb,
+    /// Cursor color.
+    pub cursor: Rgb,
+    /// Theme name.
+    pub name: Cow<'static, str>,
+}
+
+impl PartialEq for Theme {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl Eq for Theme {}
+
+impl PartialOrd for Theme {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Themes are ordered and hashed by their names, ignoring ASCII case, to be
+// consistent with `PartialEq`.
+impl Ord for Theme {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lower1 = self.name.bytes().map(|b| b.to_ascii_lowercase());
+        let lower2 = other.name.bytes().map(|b| b.to_ascii_lowercase());
+        lower1.cmp(lower2)
+    }
+}
+
+impl Hash for Theme {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.name.bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+
+        // Terminate the name as `str` hashing does.
+        state.write_u8(0xFF);
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::TERMUX_DEFAULT
+    }
+}
+
+impl Theme {
+    /// The colors Termux uses when no color settings file is present.
+    pub const TERMUX_DEFAULT: Self = Self {
+        color0: Rgb::new(0x00, 0x00, 0x00),
+        color1: Rgb::new(0xCD, 0x00, 0x00),
+        color2: Rgb::new(0x00, 0xCD, 0x00),
+        color3: Rgb::new(0xCD, 0xCD, 0x00),
+        color4: Rgb::new(0x64, 0x95, 0xED),
+        color5: Rgb::new(0xCD, 0x00, 0xCD),
+        color6: Rgb::new(0x00, 0xCD, 0xCD),
+        color7: Rgb::new(0xE5, 0xE5, 0xE5),
+        color8: Rgb::new(0x7F, 0x7F, 0x7F),
+        color9: Rgb::new(0xFF, 0x00, 0x00),
+        color10: Rgb::new(0x00, 0xFF, 0x00),
+        color11: Rgb::new(0xFF, 0xFF, 0x00),
+        color12: Rgb::new(0x5C, 0x5C, 0xFF),
+        color13: Rgb::new(0xFF, 0x00, 0xFF),
+        color14: Rgb::new(0x00, 0xFF, 0xFF),
+        color15: Rgb::new(0xFF, 0xFF, 0xFF),
+        background: Rgb::new(0x00, 0x00, 0x00),
+        foreground: Rgb::new(0xFF, 0xFF, 0xFF),
+        cursor: Rgb::new(0xFF, 0xFF, 0xFF),
+        name: Cow::Borrowed("Termux Default"),
+    };
+
+    /// Returns the relative luminance of the background, from 0 for black
+    /// to 1 for white.
+    #[must_use]
+    pub fn brightness(&self) -> f64 {
+        self.background.luminance()
+    }
+
+    /// Returns the contrast ratio of the foreground against the background,
+    /// from 1 to 21.
+    #[must_use]
+    pub fn contrast(&self) -> f64 {
+        self.foreground.contrast(self.background)
+    }
+
+    /// Returns the hue of the background in degrees, or `None` if it is a
+    /// pure gray.
+    #[must_use]
+    pub fn hue(&self) -> Option<f64> {
+        let (hue, saturation, _) = self.background.to_hsl();
+
+        (saturation > 0.0).then_some(hue)
+    }
+
+    /// Returns the average saturation of the dim and bright red through cyan
+    /// colors, from 0 to 1.
+    #[must_use]
+    pub fn saturation(&self) -> f64 {
+        let accents = [
+            self.color1,
+            self.color2,
+            self.color3,
+            self.color4,
+            self.color5,
+            self.color6,
+            self.color9,
+            self.color10,
+            self.color11,
+            self.color12,
+            self.color13,
+            self.color14,
+        ];
+
+        accents.iter().map(|color| color.to_hsl().1).sum::<f64>() / 12.0
+    }
+
+    /// Returns the theme's colors keyed by their color settings file names,
+    /// in the order of `COLOR_KEYS`.
+    #[must_use]
+    pub const fn slots(&self) -> [(&'static str, Rgb); 19] {
+        [
+            (COLOR_KEYS[0], self.color0),
+            (COLOR_KEYS[1], self.color1),
+            (COLOR_KEYS[2], self.color2),
+            (COLOR_KEYS[3], self.color3),
+            (COLOR_KEYS[4], self.color4),
+            (COLOR_KEYS[5], self.color5),
+            (COLOR_KEYS[6], self.color6),
+            (COLOR_KEYS[7], self.color7),
+            (COLOR_KEYS[8], self.color8),
+            (COLOR_KEYS[9], self.color9),
+            (COLOR_KEYS[10], self.color10),
+            (COLOR_KEYS[11], self.color11),
+            (COLOR_KEYS[12], self.color12),
+            (COLOR_KEYS[13], self.color13),
+            (COLOR_KEYS[14], self.color14),
+            (COLOR_KEYS[15], self.color15),
+            (COLOR_KEYS[16], self.background),
+            (COLOR_KEYS[17], self.foreground),
+            (COLOR_KEYS[18], self.cursor),
+        ]
+    }
+
+    /// Returns the color with the given color settings file key, such as
+    /// "color4" or "background".
+    #[must_use]
+    pub fn slot(&self, key: &str) -> Option<Rgb> {
+        self.slots()
+            .into_iter()
+            .find_map(|(k, color)| (k == key).then_some(color))
+    }
+
+    /// Returns a mutable reference to the color with the given color settings
+    /// file key.
+    pub fn slot_mut(&mut self, key: &str) -> Option<&mut Rgb> {
+        let slot = match key {
+            "color0" => &mut self.color0,
+            "color1" => &mut self.color1,
+            "color2" => &mut self.color2,
+            "color3" => &mut self.color3,
+            "color4" => &mut self.color4,
+            "color5" => &mut self.color5,
+            "color6" => &mut self.color6,
+            "color7" => &mut self.color7,
+            "color8" => &mut self.color8,
+            "color9" => &mut self.color9,
+            "color10" => &mut self.color10,
+            "color11" => &mut self.color11,
+            "color12" => &mut self.color12,
+            "color13" => &mut self.color13,
+            "color14" => &mut self.color14,
+            "color15" => &mut self.color15,
+            "background" => &mut self.background,
+            "foreground" => &mut self.foreground,
+            "cursor" => &mut self.cursor,
+            _ => return None,
+        };
+
+        Some(slot)
+    }
+
+    /// Returns the `Theme` as a `String` in the settings file format.
+    #[must_use]
+    pub fn to_settings_string(&self) -> String {
+        format!(
+            "\
+#===============================================================
+# Color Theme: {}
+#
+# Credit: https://github.com/Gogh-Co/Gogh/graphs/contributors
+#===============================================================\n
+color0={}\ncolor1={}\ncolor2={}\ncolor3={}\ncolor4={}\ncolor5={}\ncolor6={}
+color7={}\ncolor8={}\ncolor9={}\ncolor10={}\ncolor11={}\ncolor12={}
+color13={}\ncolor14={}\ncolor15={}\nbackground={}\nforeground={}\ncursor={}\n",
+            self.name,
+            self.color0,
+            self.color1,
+            self.color2,
+            self.color3,
+            self.color4,
+            self.color5,
+            self.color6,
+            self.color7,
+            self.color8,
+            self.color9,
+            self.color10,
+            self.color11,
+            self.color12,
+            self.color13,
+            self.color14,
+            self.color15,
+            self.background,
+            self.foreground,
+            self.cursor
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestions(query: &str) -> Vec<&str> {
+        match Themes::init().resolve(query) {
+            Err(Unresolved::NotFound(themes)) => {
+                themes.iter().map(|theme| theme.name.as_ref()).collect()
+            }
+            res => panic!("\"{query}\" resolved to {res:?}"),
+        }
+    }
+
+    #[test]
+    fn resolve_matches_folded_names_and_prefixes() {
+        let themes = Themes::init();
+
+        assert_eq!(
+            themes.resolve("tokyo_night_storm").unwrap().name,
+            "Tokyo Night Storm"
+        );
+        assert_eq!(themes.resolve("NORD").unwrap().name, "Nord");
+        assert!(matches!(
+            themes.resolve("nor"),
+            Err(Unresolved::Ambiguous(_))
+        ));
+    }
+
+    #[test]
+    fn suggestions_are_limited_to_plausible_typos() {
+        assert_eq!(suggestions("drcula"), ["Dracula"]);
+        assert!(suggestions("qqqqqqqqqq").is_empty());
+        assert!(suggestions("").is_empty());
+    }
+}
@@ -1,22 +1,24 @@
-use std::process::{Command, ExitCode};
+use std::process::ExitCode;
 
 use dialoguer::{theme::ColorfulTheme, FuzzySelect};
+use themux::Themes;
 
+use crate::args::Args;
+use crate::cli::Cli;
 use crate::fail;
-use crate::style::Themes;
 
 /// A type containing methods for handling the theme selection TUI.
 pub struct Tui;
 
 impl Tui {
-    /// Runs the TUI theme selector.
-    pub fn get_selection() -> ExitCode {
-        let themes = Themes::init();
+    /// Runs the TUI theme selector, listing themes in the order given by
+    /// the `--sort` and `--reverse` options.
+    pub fn get_selection(args: &Args) -> ExitCode {
+        let themes = Cli::sorted_themes(args, Themes::init().iter().collect());
 
         let names = themes
-            .0
             .iter()
-            .map(|theme| theme.name)
+            .map(|theme| &*theme.name)
             .collect::<Vec<&str>>();
 
         let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
@@ -27,30 +29,11 @@ impl Tui {
             .items(&names[..])
             .interact_opt();
 
-        let name = match selection {
-            Ok(Some(idx)) => names[idx],
+        match selection {
+            Ok(Some(idx)) => Cli::apply_theme(themes[idx], args),
             // User pressed 'q' or 'ESC'.
-            Ok(None) => return ExitCode::SUCCESS,
+            Ok(None) => ExitCode::SUCCESS,
             Err(e) => fail!("{e}"),
-        };
-
-        if let Some(theme) = themes.get(name) {
-            if let Err(e) = theme.apply() {
-                fail!("{e}");
-            }
-
-            return Self::reload_termux_settings();
         }
-
-        fail!("Unable to apply the theme");
-    }
-
-    // Reset Termux setting using the `termux-reload-settings` command.
-    fn reload_termux_settings() -> ExitCode {
-        if Command::new("termux-reload-settings").status().is_err() {
-            fail!("Unable to reload Termux settings");
-        }
-
-        ExitCode::SUCCESS
     }
 }
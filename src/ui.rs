@@ -0,0 +1,94 @@
+/// The values a key accepts.
+#[derive(Debug)]
+enum Allowed {
+    /// One of a fixed set of words.
+    Choices(&'static [&'static str]),
+    /// An integer in an inclusive range.
+    Range(u32, u32),
+    /// 0 to turn the feature off, or an integer in an inclusive range.
+    OffOrRange(u32, u32),
+}
+
+/// An appearance key in `termux.properties`.
+#[derive(Debug)]
+pub struct Key {
+    /// The key's name.
+    pub name: &'static str,
+    /// The values the key accepts.
+    allowed: Allowed,
+}
+
+/// The `termux.properties` keys managed by `themux ui`.
+//
+// See: https://wiki.termux.com/wiki/Terminal_Settings
+pub static KEYS: &[Key] = &[
+    Key {
+        name: "extra-keys-style",
+        allowed: Allowed::Choices(&["default", "arrows-only", "arrows-all", "all", "none"]),
+    },
+    Key {
+        name: "terminal-cursor-blink-rate",
+        allowed: Allowed::OffOrRange(100, 2000),
+    },
+    Key {
+        name: "terminal-cursor-style",
+        allowed: Allowed::Choices(&["block", "underline", "bar"]),
+    },
+    Key {
+        name: "terminal-margin-horizontal",
+        allowed: Allowed::Range(0, 100),
+    },
+    Key {
+        name: "terminal-margin-vertical",
+        allowed: Allowed::Range(0, 100),
+    },
+    Key {
+        name: "use-black-ui",
+        allowed: Allowed::Choices(&["true", "false"]),
+    },
+];
+
+/// The key that switches Termux's drawers and dialogs to a dark style.
+pub const BLACK_UI: &str = "use-black-ui";
+
+impl Key {
+    /// Returns the `Key` with the given name, if themux manages it.
+    pub fn find(name: &str) -> Option<&'static Self> {
+        KEYS.iter().find(|key| key.name == name)
+    }
+
+    /// Returns the names of the managed keys.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        KEYS.iter().map(|key| key.name)
+    }
+
+    /// Checks that `value` is valid for this key.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        let is_valid = match self.allowed {
+            Allowed::Choices(choices) => choices.contains(&value),
+            Allowed::Range(min, max) => {
+                value.parse::<u32>().is_ok_and(|n| (min..=max).contains(&n))
+            }
+            Allowed::OffOrRange(min, max) => value
+                .parse::<u32>()
+                .is_ok_and(|n| n == 0 || (min..=max).contains(&n)),
+        };
+
+        if is_valid {
+            return Ok(());
+        }
+
+        let expected = match self.allowed {
+            Allowed::Choices(choices) => choices.join(", "),
+            Allowed::Range(min, max) => format!("a number from {min} to {max}"),
+            Allowed::OffOrRange(min, max) => {
+                format!("0 (off) or a number from {min} to {max}")
+            }
+        };
+
+        Err(format!(
+            "\"{value}\" is not a valid value for \"{}\" (expected {expected})",
+            self.name
+        ))
+    }
+}
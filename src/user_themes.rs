@@ -0,0 +1,135 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use themux::names::fold_name;
+use themux::{properties, Theme, Themes, Unresolved};
+
+use crate::termux::Paths;
+use crate::verbose;
+
+/// The extension of user theme files.
+const EXTENSION: &str = "properties";
+
+static THEMES: OnceLock<Vec<Theme>> = OnceLock::new();
+
+/// Returns the themes saved in the user theme directory, sorted by name.
+///
+/// Files that can't be read or parsed are skipped, which `--verbose`
+/// reports.
+pub fn all() -> &'static [Theme] {
+    THEMES.get_or_init(|| {
+        let dir = Paths::get().themes_dir();
+
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                if e.kind() != ErrorKind::NotFound {
+                    verbose!("unable to read {}: {e}", dir.display());
+                }
+
+                return Vec::new();
+            }
+        };
+
+        let mut themes = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == EXTENSION))
+            .filter_map(|path| read(&path))
+            .collect::<Vec<Theme>>();
+
+        themes.sort_by(|a, b| a.name.cmp(&b.name));
+        themes
+    })
+}
+
+/// Resolves a theme name among the user themes and the built-in ones, as
+/// `Themes::resolve` does. A user theme is preferred to a built-in theme with
+/// the same name.
+pub fn resolve(name: &str) -> Result<&'static Theme, Unresolved<'static>> {
+    Themes::init()
+        .resolve_with(name, all())
+        .inspect(|theme| verbose!("resolved \"{name}\" to \"{}\"", theme.name))
+}
+
+/// Saves a theme to the user theme directory, replacing any user theme with
+/// the same name, and returns the path of the file.
+pub fn save(theme: &Theme) -> Result<PathBuf, String> {
+    let folded = fold_name(&theme.name);
+
+    if folded.is_empty() {
+        return Err(String::from(
+            "A theme name needs at least one letter or digit",
+        ));
+    }
+
+    // User themes are found before built-in ones, so this would hide one.
+    if Themes::init().iter().any(|t| fold_name(&t.name) == folded) {
+        return Err(format!(
+            "There is already a built-in theme named \"{}\"",
+            theme.name
+        ));
+    }
+
+    let dir = Paths::get().themes_dir();
+    let path = existing_path(&dir, &folded)
+        .unwrap_or_else(|| dir.join(format!("{}.{EXTENSION}", file_stem(&theme.name))));
+
+    fs::create_dir_all(&dir)
+        .and_then(|()| fs::write(&path, file_content(theme)))
+        .map_err(|e| format!("Unable to write {}: {e}", path.display()))?;
+
+    Ok(path)
+}
+
+/// Returns a theme in the format user theme files are saved in.
+pub fn file_content(theme: &Theme) -> String {
+    let mut content = format!("# Color Theme: {}\n\n", theme.name);
+
+    for (key, color) in theme.slots() {
+        let _ = writeln!(content, "{key}={color}");
+    }
+
+    content
+}
+
+// Reads a user theme, named after its file if it doesn't record a name.
+fn read(path: &Path) -> Option<Theme> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| verbose!("unable to read {}: {e}", path.display()))
+        .ok()?;
+
+    let mut theme = properties::parse(&content)
+        .map_err(|e| verbose!("skipping {}: {e}", path.display()))
+        .ok()?;
+
+    if properties::theme_name(&content).is_none() {
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        theme.name = stem.to_string().into();
+    }
+
+    Some(theme)
+}
+
+// Returns the path of the user theme file whose theme has the given folded
+// name.
+fn existing_path(dir: &Path, folded: &str) -> Option<PathBuf> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| read(path).is_some_and(|theme| fold_name(&theme.name) == folded))
+}
+
+// Returns a file name for a theme name, such as `my-theme` for "My Theme".
+fn file_stem(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<String>>()
+        .join("-")
+}
@@ -1,61 +1,179 @@
-use std::env;
-use std::path::{Path, PathBuf};
+use std::borrow::Cow;
+use std::io;
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use themux::names::edit_distance;
+
+/// Exit status for runtime errors.
+pub const EXIT_FAILURE: u8 = 1;
+
+/// Exit status for invalid command-line usage.
+pub const EXIT_USAGE: u8 = 2;
+
+/// Exit status of `themux sync` when it changed something.
+pub const EXIT_CHANGED: u8 = 3;
+
+/// How often a child process run with a timeout is checked on.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
 
 /// Prints the provided message to stderr and exits with the value 1.
 #[macro_export]
 macro_rules! fail {
     ($($t:tt)*) => {{
-        use std::io::{self, IsTerminal, Write};
-        use std::process;
-        use $crate::style::{CLR, RED};
+        $crate::util::print_error(&format!($($t)*));
+        std::process::exit(i32::from($crate::util::EXIT_FAILURE));
+    }};
+}
 
-        let mut out = io::stderr().lock();
+/// Prints the provided message to stderr unless `--quiet` was given.
+#[macro_export]
+macro_rules! info {
+    ($($t:tt)*) => {{
+        if !$crate::util::is_quiet() {
+            eprintln!($($t)*);
+        }
+    }};
+}
 
-        let is_term = out.is_terminal();
+/// Prints the provided message to stderr if `--verbose` was given.
+#[macro_export]
+macro_rules! verbose {
+    ($($t:tt)*) => {{
+        if $crate::util::is_verbose() {
+            eprintln!("{}: {}", env!("CARGO_PKG_NAME"), format!($($t)*));
+        }
+    }};
+}
 
-        writeln!(
-            &mut out,
-            "{}Error: {}{}",
-            if is_term { RED } else { "" },
-            format!($($t)*),
-            if is_term { CLR } else { "" }
-        ).expect("Failed to write to stderr");
+/// The amount of messaging requested on the command line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Verbosity {
+    /// Only errors and requested output.
+    Quiet,
+    /// Errors, requested output and informational messages.
+    Normal,
+    /// Everything, including diagnostic messages.
+    Verbose,
+}
 
-        let _ = out.flush();
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
 
-        process::exit(1);
-    }};
+/// Sets the verbosity used by the `info!` and `verbose!` macros.
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
 }
 
-/// Returns the path to the color settings file path.
-pub fn get_settings_file_path() -> Result<PathBuf, &'static str> {
-    let Ok(home) = env::var("HOME") else {
-        fail!("'HOME' environment variable must be set");
-    };
+/// Returns true if `--quiet` was given.
+pub fn is_quiet() -> bool {
+    VERBOSITY.load(Ordering::Relaxed) == Verbosity::Quiet as u8
+}
 
-    let mut path = Path::new(&home).join(".termux");
-    path.push("colors.properties");
+/// Returns true if `--verbose` was given.
+pub fn is_verbose() -> bool {
+    VERBOSITY.load(Ordering::Relaxed) == Verbosity::Verbose as u8
+}
 
-    if matches!(path.try_exists(), Ok(true)) {
-        return Ok(path);
-    }
+/// Prints an error message to stderr, colored if appropriate.
+pub fn print_error(msg: &str) {
+    use crate::style::{use_color, CLR, RED};
+    use std::io::{self, IsTerminal, Write};
 
-    match path.parent() {
-        // Termux dir exists so we will create the file on write.
-        Some(termux_dir) if termux_dir.is_dir() => Ok(path),
-        Some(_) | None => fail!("Color settings file cannot be created"),
+    let mut out = io::stderr().lock();
+
+    let is_color = use_color(out.is_terminal());
+
+    writeln!(
+        &mut out,
+        "{}Error: {msg}{}",
+        if is_color { RED } else { "" },
+        if is_color { CLR } else { "" }
+    )
+    .expect("Failed to write to stderr");
+
+    let _ = out.flush();
+}
+
+/// Returns the candidate closest to `input`, if any is close enough to be a
+/// plausible typo.
+pub fn suggest<'a, I>(input: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let max_distance = (input.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Quotes a string for a POSIX shell, unless every character in it is safe
+/// to use unquoted.
+pub fn shell_quote(s: &str) -> Cow<'_, str> {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "-_./:,+@%=".contains(c);
+
+    if !s.is_empty() && s.chars().all(is_safe) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(format!("'{}'", s.replace('\'', "'\\''")))
     }
 }
 
-/// Checks the environment variables for an indication that we are in Termux.
-pub fn is_termux_env() -> bool {
-    for (var_name, _) in env::vars_os() {
-        if let Some(name) = var_name.as_os_str().to_str() {
-            if name.contains("TERMUX") {
-                return true;
-            }
+/// Waits for a child process to exit, killing it if it runs longer than
+/// `timeout`. Returns `None` if it was killed.
+///
+/// If the child leads its own process group, the whole group is killed.
+pub fn wait_timeout(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let start = Instant::now();
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
         }
+
+        if start.elapsed() >= timeout {
+            kill_group(child);
+            let _ = child.kill();
+            let _ = child.wait();
+
+            return Ok(None);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+// Kills the process group led by a child. A child that doesn't lead one has
+// no group with its ID, so nothing else is signalled.
+#[cfg(unix)]
+fn kill_group(child: &Child) {
+    if let Ok(pid) = libc::pid_t::try_from(child.id()) {
+        // SAFETY: kill takes no pointers, and a missing group is reported
+        // through the return value, which doesn't matter here.
+        unsafe { libc::kill(-pid, libc::SIGKILL) };
     }
+}
+
+// Process groups are only killed on Unix.
+#[cfg(not(unix))]
+const fn kill_group(_child: &Child) {}
 
-    false
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shell_quote_quotes_only_when_needed() {
+        assert_eq!(shell_quote("nord"), "nord");
+        assert_eq!(shell_quote("Tokyo Night"), "'Tokyo Night'");
+        assert_eq!(shell_quote("Generated #5E81AC"), "'Generated #5E81AC'");
+        assert_eq!(shell_quote("Rosé's"), "'Rosé'\\''s'");
+        assert_eq!(shell_quote(""), "''");
+    }
 }
@@ -0,0 +1,244 @@
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use themux::Theme;
+
+/// How long a file must go without changes before it is read, so that a
+/// save made of several writes is only seen once.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Watches a file for changes.
+pub struct Watcher(sys::Watcher);
+
+impl Watcher {
+    /// Starts watching a file, which doesn't need to exist yet.
+    pub fn new(path: &Path) -> io::Result<Self> {
+        sys::Watcher::new(path).map(Self)
+    }
+
+    /// Blocks until the file has changed and then stayed unchanged for a
+    /// moment.
+    pub fn wait(&self) -> io::Result<()> {
+        self.0.changed(None)?;
+
+        while self.0.changed(Some(DEBOUNCE))? {}
+
+        Ok(())
+    }
+}
+
+/// Returns the escape sequences that set the terminal's palette,
+/// foreground, background and cursor colors to a theme's.
+pub fn preview_sequences(theme: &Theme) -> String {
+    let mut out = String::new();
+
+    for (idx, (_, color)) in theme.slots().iter().take(16).enumerate() {
+        let _ = write!(out, "\x1b]4;{idx};{color}\x07");
+    }
+
+    let _ = write!(
+        out,
+        "\x1b]10;{}\x07\x1b]11;{}\x07\x1b]12;{}\x07",
+        theme.foreground, theme.background, theme.cursor
+    );
+
+    out
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod sys {
+    use std::ffi::{CString, OsStr, OsString};
+    use std::io::{self, ErrorKind};
+    use std::mem;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::ptr;
+    use std::time::{Duration, Instant};
+
+    /// The events that mean a file in the directory was written or replaced,
+    /// as editors that save to a temporary file and rename it do.
+    const MASK: u32 = libc::IN_MODIFY | libc::IN_CLOSE_WRITE | libc::IN_CREATE | libc::IN_MOVED_TO;
+
+    /// Watches the file's directory with inotify, since the file itself may
+    /// be replaced.
+    pub struct Watcher {
+        fd: OwnedFd,
+        name: OsString,
+    }
+
+    impl Watcher {
+        pub fn new(path: &Path) -> io::Result<Self> {
+            let name = path
+                .file_name()
+                .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "not a file name"))?
+                .to_os_string();
+
+            let dir = path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .unwrap_or_else(|| Path::new("."));
+
+            let dir = CString::new(dir.as_os_str().as_bytes())
+                .map_err(|e| io::Error::new(ErrorKind::InvalidInput, e))?;
+
+            // SAFETY: inotify_init1 takes no pointers, and failure is
+            // reported through the return value.
+            let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            // SAFETY: `fd` is a new descriptor that nothing else owns.
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+            // SAFETY: `dir` is a NUL-terminated string that outlives the call.
+            let wd = unsafe { libc::inotify_add_watch(fd.as_raw_fd(), dir.as_ptr(), MASK) };
+
+            if wd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            Ok(Self { fd, name })
+        }
+
+        /// Waits up to `timeout`, or forever if `None`, for the file to
+        /// change. Returns true if it did.
+        pub fn changed(&self, timeout: Option<Duration>) -> io::Result<bool> {
+            let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+            loop {
+                let ms = deadline.map_or(-1, |deadline| {
+                    let left = deadline.saturating_duration_since(Instant::now());
+                    i32::try_from(left.as_millis()).unwrap_or(i32::MAX)
+                });
+
+                let mut pfd = libc::pollfd {
+                    fd: self.fd.as_raw_fd(),
+                    events: libc::POLLIN,
+                    revents: 0,
+                };
+
+                // SAFETY: `pfd` is a single valid `pollfd` for the duration
+                // of the call.
+                let res = unsafe { libc::poll(ptr::addr_of_mut!(pfd), 1, ms) };
+
+                if res < 0 {
+                    let e = io::Error::last_os_error();
+
+                    if e.kind() == ErrorKind::Interrupted {
+                        continue;
+                    }
+
+                    return Err(e);
+                }
+
+                if res == 0 {
+                    return Ok(false);
+                }
+
+                if self.read_events()? {
+                    return Ok(true);
+                }
+            }
+        }
+
+        // Reads the pending events, returning true if any was for the file.
+        fn read_events(&self) -> io::Result<bool> {
+            const HEADER: usize = mem::size_of::<libc::inotify_event>();
+
+            let mut buf = [0_u8; 4096];
+
+            // SAFETY: `buf` is valid for writes of its length.
+            let len =
+                unsafe { libc::read(self.fd.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len()) };
+            let len = usize::try_from(len).map_err(|_| io::Error::last_os_error())?;
+
+            let mut found = false;
+            let mut pos = 0;
+
+            // Each event is a header, whose last field is the length of the
+            // NUL-padded name that follows it.
+            while pos + HEADER <= len {
+                let mut name_len = [0_u8; 4];
+                name_len.copy_from_slice(&buf[pos + HEADER - 4..pos + HEADER]);
+
+                let start = pos + HEADER;
+                let end = (start + u32::from_ne_bytes(name_len) as usize).min(len);
+                let name = buf[start..end]
+                    .split(|&b| b == 0)
+                    .next()
+                    .unwrap_or_default();
+
+                found |= OsStr::from_bytes(name) == self.name;
+                pos = end;
+            }
+
+            Ok(found)
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+mod sys {
+    use std::cell::Cell;
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::thread;
+    use std::time::{Duration, Instant, SystemTime};
+
+    /// How often the file is checked.
+    const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+    /// Watches a file by checking its modification time and size.
+    pub struct Watcher {
+        path: PathBuf,
+        last: Cell<Option<(SystemTime, u64)>>,
+    }
+
+    impl Watcher {
+        // Returns a `Result` to match the inotify watcher.
+        #[allow(clippy::unnecessary_wraps)]
+        pub fn new(path: &Path) -> io::Result<Self> {
+            Ok(Self {
+                path: path.to_path_buf(),
+                last: Cell::new(stamp(path)),
+            })
+        }
+
+        /// Waits up to `timeout`, or forever if `None`, for the file to
+        /// change. Returns true if it did.
+        pub fn changed(&self, timeout: Option<Duration>) -> io::Result<bool> {
+            let start = Instant::now();
+
+            loop {
+                let interval = timeout.map_or(POLL_INTERVAL, |timeout| {
+                    POLL_INTERVAL.min(timeout.saturating_sub(start.elapsed()))
+                });
+
+                thread::sleep(interval);
+
+                let stamp = stamp(&self.path);
+
+                if stamp != self.last.replace(stamp) {
+                    return Ok(true);
+                }
+
+                if timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+
+    // Returns the file's modification time and size, if it exists.
+    fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+        let meta = fs::metadata(path).ok()?;
+
+        Some((meta.modified().ok()?, meta.len()))
+    }
+}
@@ -0,0 +1,116 @@
+//! Snapshot tests for the generators in `themux::generate`.
+//!
+//! Each target is rendered for the same dark theme and compared with the
+//! file of the same name in `tests/snapshots`. The targets that adjust to the
+//! background are also rendered for a light theme, into a `-light` snapshot.
+//! After an intended change to a generator, run the tests with
+//! `UPDATE_SNAPSHOTS=1` to rewrite the snapshots, then review the difference.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use themux::generate::Target;
+use themux::Themes;
+
+fn check(target: Target) {
+    check_snapshot(target, "tokyo-night", target.name());
+}
+
+fn check_light(target: Target) {
+    check_snapshot(target, "ayu-light", &format!("{}-light", target.name()));
+}
+
+// Renders a target for a theme and compares it with the named snapshot.
+fn check_snapshot(target: Target, theme: &str, name: &str) {
+    let theme = Themes::init().resolve(theme).unwrap();
+    let actual = target.render(theme);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.snap"));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "{}: {e} (run with UPDATE_SNAPSHOTS=1 to create it)",
+            path.display()
+        )
+    });
+
+    assert_eq!(actual, expected, "{name} differs from its snapshot");
+}
+
+#[test]
+fn fzf() {
+    check(Target::Fzf);
+}
+
+#[test]
+fn ls_colors() {
+    check(Target::LsColors);
+}
+
+#[test]
+fn dircolors() {
+    check(Target::Dircolors);
+}
+
+#[test]
+fn tmux() {
+    check(Target::Tmux);
+}
+
+#[test]
+fn starship() {
+    check(Target::Starship);
+}
+
+#[test]
+fn less() {
+    check(Target::Less);
+}
+
+#[test]
+fn grep() {
+    check(Target::Grep);
+}
+
+#[test]
+fn neovim() {
+    check(Target::Neovim);
+}
+
+#[test]
+fn neovim_light() {
+    check_light(Target::Neovim);
+}
+
+#[test]
+fn vim() {
+    check(Target::Vim);
+}
+
+#[test]
+fn vim_light() {
+    check_light(Target::Vim);
+}
+
+#[test]
+fn helix() {
+    check(Target::Helix);
+}
+
+#[test]
+fn micro() {
+    check(Target::Micro);
+}
+
+#[test]
+fn nano() {
+    check(Target::Nano);
+}
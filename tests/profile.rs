@@ -0,0 +1,236 @@
+//! Tests that saved profiles can be shown and used again, that `sync`
+//! applies files in the same format, and that applying a theme writes,
+//! reloads and rolls back as it should.
+//!
+//! Each test runs the binary with `--root`, so every file it reads or writes
+//! is beneath a temporary directory.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output};
+use std::time::{Duration, Instant};
+
+/// A temporary directory that is removed when dropped.
+struct Sandbox(PathBuf);
+
+impl Sandbox {
+    fn new(name: &str) -> Self {
+        let root = env::temp_dir().join(format!("themux-{name}-{}", process::id()));
+        fs::create_dir_all(root.join("home/.termux")).unwrap();
+        Self(root)
+    }
+
+    // Runs themux in the sandbox, failing the test if it doesn't succeed.
+    fn run(&self, args: &[&str]) -> String {
+        let output = self.command(args);
+
+        assert!(
+            output.status.success(),
+            "themux {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn command(&self, args: &[&str]) -> Output {
+        self.command_reloading_with("true", args)
+    }
+
+    // Runs themux in the sandbox with another reload command.
+    fn command_reloading_with(&self, reload: &str, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_themux"))
+            .args(["--no-termux-check", "--root"])
+            .arg(&self.0)
+            .args(args)
+            .env("HOME", "/home")
+            .env_remove("XDG_CONFIG_HOME")
+            .env_remove("THEMUX_TERMUX_DIR")
+            .env("THEMUX_RELOAD_COMMAND", reload)
+            .output()
+            .unwrap()
+    }
+
+    fn termux_file(&self, name: &str) -> PathBuf {
+        self.0.join("home/.termux").join(name)
+    }
+
+    fn read(&self, path: &Path) -> String {
+        fs::read_to_string(path).unwrap()
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn save_show_and_use() {
+    let sandbox = Sandbox::new("profile");
+    let properties = sandbox.termux_file("termux.properties");
+
+    sandbox.run(&["apply", "nord"]);
+    fs::write(&properties, "terminal-margin-horizontal = 8\n").unwrap();
+
+    sandbox.run(&["profile", "save", "work"]);
+
+    assert_eq!(
+        sandbox.run(&["profile", "show", "work"]),
+        "theme = \"Nord\"\n\n[ui]\nterminal-margin-horizontal = \"8\"\n"
+    );
+    assert_eq!(sandbox.run(&["profile", "list"]), "work\n");
+
+    let nord = sandbox.read(&sandbox.termux_file("colors.properties"));
+
+    sandbox.run(&["apply", "dracula"]);
+    fs::write(&properties, "terminal-margin-horizontal = 0\n").unwrap();
+
+    sandbox.run(&["profile", "use", "work"]);
+
+    assert_eq!(
+        sandbox.read(&sandbox.termux_file("colors.properties")),
+        nord
+    );
+    assert!(sandbox
+        .read(&properties)
+        .contains("terminal-margin-horizontal = 8"));
+}
+
+#[test]
+fn changed_colors_are_saved_as_a_palette() {
+    let sandbox = Sandbox::new("profile-palette");
+    let colors = sandbox.termux_file("colors.properties");
+
+    sandbox.run(&["apply", "nord"]);
+
+    let changed = sandbox.read(&colors).replace("#2E3440", "#000000");
+    fs::write(&colors, &changed).unwrap();
+
+    sandbox.run(&["profile", "save", "dark"]);
+
+    let saved = sandbox.run(&["profile", "show", "dark"]);
+
+    assert!(saved.starts_with("[colors]\nname = \"Nord\"\n"), "{saved}");
+    assert!(saved.contains("background = \"#000000\""), "{saved}");
+
+    sandbox.run(&["apply", "dracula"]);
+    sandbox.run(&["profile", "use", "dark"]);
+
+    assert_eq!(sandbox.read(&colors), changed);
+}
+
+#[test]
+fn sync_resolves_user_themes() {
+    let sandbox = Sandbox::new("sync");
+    let config = sandbox.0.join("home/.config/themux");
+    let file = sandbox.0.join("sync.toml");
+
+    sandbox.run(&[
+        "generate-theme",
+        "--base",
+        "#5E81AC",
+        "--name",
+        "My Theme",
+        "--save",
+    ]);
+    fs::write(&file, "theme = \"my-theme\"\n").unwrap();
+
+    let sync = || sandbox.command(&["sync", "--file", file.to_str().unwrap()]);
+
+    assert_eq!(sync().status.code(), Some(3));
+    assert_eq!(sync().status.code(), Some(0));
+    assert_eq!(sandbox.run(&["current"]), "Current theme: My Theme\n");
+
+    // A failed post-apply hook is reported and fails the command, as it
+    // does for the others, but the theme was changed.
+    fs::write(
+        config.join("config.toml"),
+        "[hooks]\npost-apply = \"exit 4\"\n",
+    )
+    .unwrap();
+    fs::write(&file, "theme = \"Nord\"\n").unwrap();
+
+    let output = sync();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("`exit 4` failed"));
+    assert_eq!(sync().status.code(), Some(0));
+}
+
+#[test]
+fn user_themes_resolve_like_built_in_ones() {
+    let sandbox = Sandbox::new("resolve");
+
+    sandbox.run(&["generate-theme", "--base", "#5E81AC", "--save"]);
+
+    let generated = sandbox.run(&["show", "Generated #5E81AC"]);
+
+    assert_eq!(sandbox.run(&["show", "generated"]), generated);
+
+    let output = sandbox.command(&["show", "generatd"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("Generated #5E81AC"), "{stderr}");
+}
+
+#[test]
+fn termux_dir_is_created_beneath_the_root() {
+    let sandbox = Sandbox::new("fresh");
+    fs::remove_dir(sandbox.termux_file("")).unwrap();
+
+    let output = sandbox.command(&["current"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not found"));
+
+    assert!(sandbox
+        .run(&["apply", "--dry-run", "nord"])
+        .contains("Would write"));
+    assert!(!sandbox.termux_file("").exists());
+
+    sandbox.run(&["apply", "nord"]);
+
+    assert_eq!(sandbox.run(&["current"]), "Current theme: Nord\n");
+}
+
+#[test]
+fn failed_reload_rolls_back() {
+    let sandbox = Sandbox::new("rollback");
+    let colors = sandbox.termux_file("colors.properties");
+    let backup = sandbox.termux_file("colors.properties.bak");
+
+    sandbox.run(&["apply", "nord"]);
+    sandbox.run(&["apply", "dracula"]);
+
+    let (before, backup_before) = (sandbox.read(&colors), sandbox.read(&backup));
+
+    let output = sandbox.command_reloading_with("false", &["apply", "gruvbox-dark"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stderr.contains("previous settings were restored"),
+        "{stderr}"
+    );
+    assert_eq!(sandbox.read(&colors), before);
+    assert_eq!(sandbox.read(&backup), backup_before);
+
+    // A reload that hangs is stopped after the configured timeout.
+    let config = sandbox.0.join("home/.config/themux");
+    fs::create_dir_all(&config).unwrap();
+    fs::write(config.join("config.toml"), "[reload]\ntimeout = 1\n").unwrap();
+
+    let start = Instant::now();
+    let output = sandbox.command_reloading_with("sleep 30", &["apply", "gruvbox-dark"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("was stopped after 1s"), "{stderr}");
+    assert_eq!(sandbox.read(&colors), before);
+}